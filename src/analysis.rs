@@ -0,0 +1,199 @@
+use crate::{sample, Lerper};
+
+/// Differences in value smaller than this are considered flat.
+const EPSILON: f32 = 1.0e-6;
+/// Reversals shallower than this are considered noise, such as the wiggles
+/// left by [`Bezier`][0]'s solver, which is only accurate to 1/200.
+///
+/// [0]: ../struct.Bezier.html
+const TOLERANCE: f32 = 1.0e-2;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Direction a curve is moving over a [`MonotonicRange`][0].
+///
+/// [0]: struct.MonotonicRange.html
+pub enum Monotonicity {
+    /// The value is increasing.
+    Increasing,
+    /// The value is decreasing.
+    Decreasing,
+    /// The value is constant.
+    Constant,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Range of _t_ over which a curve moves in a single direction.
+pub struct MonotonicRange {
+    /// _t_ at the start of the range.
+    pub start: f32,
+    /// _t_ at the end of the range.
+    pub end: f32,
+    /// Direction of the curve over the range.
+    pub direction: Monotonicity,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Whether an [`Extremum`][0] is a peak or a trough.
+///
+/// [0]: struct.Extremum.html
+pub enum ExtremumKind {
+    /// Local minimum.
+    Minimum,
+    /// Local maximum.
+    Maximum,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Local extremum of a curve, inside of _(0, 1)_.
+pub struct Extremum {
+    /// _t_ of the extremum.
+    pub t: f32,
+    /// Value of the curve at the extremum.
+    pub value: f32,
+    /// Whether the extremum is a minimum or maximum.
+    pub kind: ExtremumKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Shape of a curve, as returned by [`analyze`][0].
+///
+/// [0]: fn.analyze.html
+pub struct Analysis {
+    /// Smallest sampled value.
+    pub min: f32,
+    /// Largest sampled value.
+    pub max: f32,
+    /// Ranges of _t_ over which the curve moves in a single direction, in
+    /// order.
+    pub ranges: Vec<MonotonicRange>,
+    /// Local extrema of the curve, in order.
+    pub extrema: Vec<Extremum>,
+}
+
+impl Analysis {
+    /// Whether the curve leaves _[0, 1]_.
+    pub fn overshoots(&self) -> bool {
+        self.max_overshoot() > 0.0
+    }
+
+    /// Furthest distance the curve goes outside of _[0, 1]_, or `0.0` if it
+    /// stays inside.
+    pub fn max_overshoot(&self) -> f32 {
+        (self.max - 1.0).max(-self.min).max(0.0)
+    }
+
+    /// Whether the curve never changes direction.
+    pub fn is_monotonic(&self) -> bool {
+        self.extrema.is_empty()
+    }
+}
+
+/// Analyze the shape of a lerper from `samples` evenly spaced samples, to
+/// validate a curve before it drives a property that can't leave _[0, 1]_.
+///
+/// A change of direction is only reported as an extremum once the curve has
+/// moved back by more than `0.01`, so shallower dips are treated as part of
+/// the surrounding range.
+///
+/// # Panics
+/// Panics if `samples` is less than two.
+///
+/// # Usage
+/// ```
+/// use soy::{analyze, Bezier, ExtremumKind};
+///
+/// let ease = analyze(soy::EASE_IN_OUT, 256);
+/// assert!(ease.is_monotonic());
+/// assert!(!ease.overshoots());
+///
+/// let back = analyze(Bezier::new(0.3, 0.0, 0.5, 1.5), 256);
+/// assert!(back.overshoots());
+/// assert_eq!(back.extrema.len(), 1);
+/// assert_eq!(back.extrema[0].kind, ExtremumKind::Maximum);
+/// ```
+pub fn analyze<L: Lerper>(lerper: L, samples: usize) -> Analysis {
+    assert!(samples >= 2, "an analysis requires at least two samples");
+
+    let points: Vec<_> = sample(lerper, samples).collect();
+    let min = points.iter().map(|p| p.1).fold(f32::INFINITY, f32::min);
+    let max = points.iter().map(|p| p.1).fold(f32::NEG_INFINITY, f32::max);
+
+    // Find the extrema first, only turning around once the curve has
+    // moved back more than `TOLERANCE` from the furthest point reached.
+    let mut extrema = Vec::new();
+    let mut first = None;
+    let mut heading = None;
+    let mut furthest = points[0];
+
+    for &point in &points[1..] {
+        let delta = point.1 - furthest.1;
+        match heading {
+            None if delta.abs() > TOLERANCE => {
+                let direction = if delta > 0.0 {
+                    Monotonicity::Increasing
+                } else {
+                    Monotonicity::Decreasing
+                };
+                first = Some(direction);
+                heading = Some(direction);
+                furthest = point;
+            }
+            None => {}
+            Some(Monotonicity::Increasing) if delta >= 0.0 => furthest = point,
+            Some(Monotonicity::Decreasing) if delta <= 0.0 => furthest = point,
+            Some(direction) if delta.abs() > TOLERANCE => {
+                let (kind, turned) = match direction {
+                    Monotonicity::Increasing => (ExtremumKind::Maximum, Monotonicity::Decreasing),
+                    _ => (ExtremumKind::Minimum, Monotonicity::Increasing),
+                };
+                extrema.push(Extremum {
+                    t: furthest.0,
+                    value: furthest.1,
+                    kind,
+                });
+                heading = Some(turned);
+                furthest = point;
+            }
+            Some(_) => {}
+        }
+    }
+
+    // Then label every moving step with the direction between the extrema
+    // around it, so noise doesn't split the ranges either.
+    let mut ranges: Vec<MonotonicRange> = Vec::new();
+    for pair in points.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        let moving = if b.1 > a.1 {
+            Monotonicity::Increasing
+        } else {
+            Monotonicity::Decreasing
+        };
+        let direction = if (b.1 - a.1).abs() <= EPSILON {
+            Monotonicity::Constant
+        } else {
+            let turns = extrema.iter().take_while(|e| e.t <= a.0).count();
+            match first {
+                Some(direction) if turns % 2 == 0 => direction,
+                Some(Monotonicity::Increasing) => Monotonicity::Decreasing,
+                Some(_) => Monotonicity::Increasing,
+                None => moving,
+            }
+        };
+
+        match ranges.last_mut() {
+            Some(range) if range.direction == direction => range.end = b.0,
+            _ => ranges.push(MonotonicRange {
+                start: a.0,
+                end: b.0,
+                direction,
+            }),
+        }
+    }
+
+    Analysis {
+        min,
+        max,
+        ranges,
+        extrema,
+    }
+}
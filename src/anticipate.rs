@@ -0,0 +1,233 @@
+use core::hash::{Hash, Hasher};
+
+use crate::hash::hash_f32;
+use crate::Lerper;
+
+/// Android's anticipate curve, which backs up before moving forward.
+fn anticipate(t: f32, tension: f32) -> f32 {
+    t * t * ((tension + 1.0) * t - tension)
+}
+
+/// Android's overshoot curve, without the final offset, for `t` in
+/// `[-1, 0]`.
+fn overshoot(t: f32, tension: f32) -> f32 {
+    t * t * ((tension + 1.0) * t + tension)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Lerper which starts backwards, then flings forward, matching Android's
+/// `AnticipateInterpolator`.
+pub struct Anticipate {
+    /// Amount of anticipation. `0.0` gives a plain cubic ease in.
+    pub tension: f32,
+}
+
+impl Anticipate {
+    /// Android's default tension.
+    pub const TENSION: f32 = 2.0;
+
+    /// Create a new anticipate lerper with the given tension.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Lerper;
+    ///
+    /// let wind_up = soy::Anticipate::new(soy::Anticipate::TENSION);
+    ///
+    /// assert!(wind_up.calculate(0.3) < 0.0);
+    /// assert_eq!(wind_up.calculate(1.0), 1.0);
+    /// ```
+    pub fn new(tension: f32) -> Anticipate {
+        Anticipate { tension }
+    }
+}
+
+impl Default for Anticipate {
+    fn default() -> Anticipate {
+        Anticipate::new(Anticipate::TENSION)
+    }
+}
+
+impl Hash for Anticipate {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f32(self.tension, state);
+    }
+}
+
+impl Lerper for Anticipate {
+    fn calculate(&self, t: f32) -> f32 {
+        anticipate(t, self.tension)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Lerper which flings forward past the end, then settles back, matching
+/// Android's `OvershootInterpolator`.
+pub struct Overshoot {
+    /// Amount of overshoot. `0.0` gives a plain cubic ease out.
+    pub tension: f32,
+}
+
+impl Overshoot {
+    /// Android's default tension.
+    pub const TENSION: f32 = 2.0;
+
+    /// Create a new overshoot lerper with the given tension.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Lerper;
+    ///
+    /// let pop = soy::Overshoot::new(soy::Overshoot::TENSION);
+    ///
+    /// assert_eq!(pop.calculate(0.0), 0.0);
+    /// assert!(pop.calculate(0.7) > 1.0);
+    /// assert_eq!(pop.calculate(1.0), 1.0);
+    /// ```
+    pub fn new(tension: f32) -> Overshoot {
+        Overshoot { tension }
+    }
+
+    /// Create a new overshoot lerper which travels `peak` past `1.0` before
+    /// settling back.
+    ///
+    /// Larger peaks are also reached earlier, leaving longer to settle back.
+    ///
+    /// # Panics
+    /// Panics if `peak` is negative.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Lerper;
+    ///
+    /// let pop = soy::Overshoot::with_peak(0.1);
+    /// assert!((pop.peak() - 0.1).abs() < 1.0e-5);
+    ///
+    /// let highest = (0..=1000)
+    ///     .map(|i| pop.calculate(i as f32 / 1000.0))
+    ///     .fold(0.0, f32::max);
+    /// assert!((highest - 1.1).abs() < 1.0e-3);
+    /// ```
+    pub fn with_peak(peak: f32) -> Overshoot {
+        assert!(peak >= 0.0, "an overshoot requires a non-negative peak");
+
+        // The peak only grows with the tension, so bracket the tension, then
+        // bisect.
+        let (mut low, mut high) = (0.0, 1.0);
+        while Overshoot::new(high).peak() < peak {
+            high *= 2.0;
+        }
+        for _ in 0..64 {
+            let mid = 0.5 * (low + high);
+            if Overshoot::new(mid).peak() < peak {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        Overshoot::new(0.5 * (low + high))
+    }
+
+    /// Distance travelled past `1.0` before settling back.
+    pub fn peak(&self) -> f32 {
+        let s = self.tension.max(0.0);
+
+        // The curve turns back at `t = 1 - 2s / 3(s + 1)`.
+        4.0 * s * s * s / (27.0 * (s + 1.0) * (s + 1.0))
+    }
+}
+
+impl Default for Overshoot {
+    fn default() -> Overshoot {
+        Overshoot::new(Overshoot::TENSION)
+    }
+}
+
+impl Hash for Overshoot {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f32(self.tension, state);
+    }
+}
+
+impl Lerper for Overshoot {
+    fn calculate(&self, t: f32) -> f32 {
+        overshoot(t - 1.0, self.tension) + 1.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Lerper which anticipates over the first half of _t_, and overshoots over
+/// the second, matching Android's `AnticipateOvershootInterpolator`.
+pub struct AnticipateOvershoot {
+    /// Amount of anticipation and overshoot.
+    pub tension: f32,
+}
+
+impl AnticipateOvershoot {
+    /// Android's default tension, which is [`Anticipate::TENSION`][0] scaled
+    /// by [`EXTRA_TENSION`][1].
+    ///
+    /// [0]: struct.Anticipate.html#associatedconstant.TENSION
+    /// [1]: #associatedconstant.EXTRA_TENSION
+    pub const TENSION: f32 = Anticipate::TENSION * AnticipateOvershoot::EXTRA_TENSION;
+
+    /// Android's default multiplier applied to the tension.
+    pub const EXTRA_TENSION: f32 = 1.5;
+
+    /// Create a new anticipate overshoot lerper, like Android's
+    /// `AnticipateOvershootInterpolator(tension)`, which scales the tension by
+    /// [`EXTRA_TENSION`][0].
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Lerper;
+    ///
+    /// let ease = soy::AnticipateOvershoot::new(2.0);
+    ///
+    /// assert_eq!(ease.tension, soy::AnticipateOvershoot::TENSION);
+    /// assert!(ease.calculate(0.15) < 0.0);
+    /// assert_eq!(ease.calculate(0.5), 0.5);
+    /// assert!(ease.calculate(0.85) > 1.0);
+    /// ```
+    ///
+    /// [0]: #associatedconstant.EXTRA_TENSION
+    pub fn new(tension: f32) -> AnticipateOvershoot {
+        AnticipateOvershoot::with_extra_tension(tension, AnticipateOvershoot::EXTRA_TENSION)
+    }
+
+    /// Create a new anticipate overshoot lerper, like Android's
+    /// `AnticipateOvershootInterpolator(tension, extraTension)`.
+    pub fn with_extra_tension(tension: f32, extra_tension: f32) -> AnticipateOvershoot {
+        AnticipateOvershoot {
+            tension: tension * extra_tension,
+        }
+    }
+}
+
+impl Default for AnticipateOvershoot {
+    fn default() -> AnticipateOvershoot {
+        AnticipateOvershoot {
+            tension: AnticipateOvershoot::TENSION,
+        }
+    }
+}
+
+impl Hash for AnticipateOvershoot {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f32(self.tension, state);
+    }
+}
+
+impl Lerper for AnticipateOvershoot {
+    fn calculate(&self, t: f32) -> f32 {
+        if t < 0.5 {
+            0.5 * anticipate(t * 2.0, self.tension)
+        } else {
+            0.5 * (overshoot(t * 2.0 - 2.0, self.tension) + 2.0)
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use core::f32::consts::FRAC_PI_2;
+
+use crate::Lerper;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Equal power fade: _f(t) = sin(t π / 2)_.
+///
+/// When crossfading uncorrelated material, such as two different songs, an
+/// equal power fade keeps the perceived loudness constant, where a linear
+/// fade dips by 3dB in the middle.
+pub struct EqualPower;
+
+impl Lerper for EqualPower {
+    fn calculate(&self, t: f32) -> f32 {
+        (t * FRAC_PI_2).sin()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Equal gain fade: _f(t) = t_.
+///
+/// The gains of the two sides of an equal gain crossfade always sum to one,
+/// which keeps correlated material, such as two takes of the same
+/// performance, at a constant level.
+pub struct EqualGain;
+
+impl Lerper for EqualGain {
+    fn calculate(&self, t: f32) -> f32 {
+        t
+    }
+}
+
+/// Gains of the outgoing and incoming sides of a crossfade at `t`, as
+/// `(out, in)`, using `lerper` as the fade in curve. _t_ is clamped to
+/// `[0, 1]`.
+///
+/// # Usage
+/// ```
+/// let (out_gain, in_gain) = soy::crossfade(soy::EqualPower, 0.5);
+///
+/// // Power, the square of the gain, is constant across the fade.
+/// assert!((out_gain * out_gain + in_gain * in_gain - 1.0).abs() < 1.0e-6);
+/// ```
+pub fn crossfade<L: Lerper>(lerper: L, t: f32) -> (f32, f32) {
+    let t = t.clamp(0.0, 1.0);
+    (lerper.calculate(1.0 - t), lerper.calculate(t))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Pan law, deciding the level of a centred signal in each channel.
+pub enum PanLaw {
+    /// Gains sum to one, so a centred signal is 6dB quieter in each channel.
+    Linear,
+    /// Powers sum to one, so a centred signal is 3dB quieter in each
+    /// channel.
+    ConstantPower,
+    /// Halfway between linear and constant power, 4.5dB quieter in each
+    /// channel.
+    Compromise,
+}
+
+/// Gains of the left and right channels, as `(left, right)`, for a signal
+/// panned to `position`, from `-1.0` (hard left) to `1.0` (hard right).
+///
+/// # Usage
+/// ```
+/// use soy::PanLaw;
+///
+/// assert_eq!(soy::pan(-1.0, PanLaw::ConstantPower), (1.0, 0.0));
+///
+/// let (left, right) = soy::pan(0.0, PanLaw::ConstantPower);
+/// assert_eq!(left, right);
+/// assert!((left - core::f32::consts::FRAC_1_SQRT_2).abs() < 1.0e-6);
+/// ```
+pub fn pan(position: f32, law: PanLaw) -> (f32, f32) {
+    let t = (position.clamp(-1.0, 1.0) + 1.0) / 2.0;
+
+    match law {
+        PanLaw::Linear => crossfade(EqualGain, t),
+        PanLaw::ConstantPower => crossfade(EqualPower, t),
+        PanLaw::Compromise => {
+            let (left, right) = crossfade(EqualGain, t);
+            let (power_left, power_right) = crossfade(EqualPower, t);
+
+            ((left * power_left).sqrt(), (right * power_right).sqrt())
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use core::ops::{Add, Mul, Sub};
+
+use crate::vector::Dot;
+
+/// Barycentric coordinates of `p` within the triangle `a`, `b`, `c`.
+///
+/// The coordinates are the weights of each vertex, summing to `1.0`. For
+/// three-dimensional triangles, `p` is projected onto the triangle's plane.
+/// Points outside of the triangle have at least one negative weight.
+///
+/// For degenerate triangles, whose vertices are in a line, `p` is projected
+/// onto the line from `a` to `b`, and `c` has no weight. If `a` and `b` are
+/// the same point, all of the weight goes to `a`.
+///
+/// # Usage
+/// ```
+/// # use core::ops::{Add, Mul, Sub};
+/// # #[derive(Debug, Clone, Copy, PartialEq)]
+/// # struct Vec2(f32, f32);
+/// # impl Sub for Vec2 { type Output = Vec2; fn sub(self, o: Vec2) -> Vec2 { Vec2(self.0 - o.0, self.1 - o.1) } }
+/// # impl soy::Dot for Vec2 { fn dot(self, o: Vec2) -> f32 { self.0 * o.0 + self.1 * o.1 } }
+/// let (a, b, c) = (Vec2(0.0, 0.0), Vec2(1.0, 0.0), Vec2(0.0, 1.0));
+/// let weights = soy::barycentric(Vec2(0.5, 0.5), a, b, c);
+///
+/// // Blend vertex colours.
+/// let shade = soy::interpolate_triangle(weights, 0.0, 10.0, 20.0);
+/// assert_eq!(shade, 15.0);
+/// ```
+pub fn barycentric<T>(p: T, a: T, b: T, c: T) -> (f32, f32, f32)
+where
+    T: Copy + Dot,
+    T: Sub<Output = T>,
+{
+    let (v0, v1, v2) = (b - a, c - a, p - a);
+    let (d00, d01, d11) = (v0.dot(v0), v0.dot(v1), v1.dot(v1));
+    let (d20, d21) = (v2.dot(v0), v2.dot(v1));
+
+    let denom = d00 * d11 - d01 * d01;
+    if denom == 0.0 {
+        // Fall back to the line from `a` to `b`, if there is one.
+        if d00 > 0.0 {
+            let v = d20 / d00;
+            return (1.0 - v, v, 0.0);
+        }
+        return (1.0, 0.0, 0.0);
+    }
+
+    let v = (d11 * d20 - d01 * d21) / denom;
+    let w = (d00 * d21 - d01 * d20) / denom;
+
+    (1.0 - v - w, v, w)
+}
+
+/// Blend the values at each vertex of a triangle, given the barycentric
+/// coordinates from [`barycentric`][0].
+///
+/// # Usage
+/// ```
+/// let weights = (0.25, 0.25, 0.5);
+/// assert_eq!(soy::interpolate_triangle(weights, 0.0, 10.0, 20.0), 12.5);
+/// ```
+///
+/// [0]: fn.barycentric.html
+pub fn interpolate_triangle<V>(weights: (f32, f32, f32), a: V, b: V, c: V) -> V
+where
+    V: Add<Output = V>,
+    V: Mul<f32, Output = V>,
+{
+    let (u, v, w) = weights;
+    a * u + b * v + c * w
+}
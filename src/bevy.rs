@@ -0,0 +1,170 @@
+//! [Bevy](https://bevyengine.org) integration, enabled with the `bevy`
+//! feature.
+//!
+//! Add an [`AnimatorPlugin`][0] for each animated type, then read
+//! [`Animator::value`][1] from your own systems.
+//!
+//! ```no_run
+//! use bevy_app::{App, Update};
+//! use bevy_ecs::prelude::*;
+//! use soy::bevy::{Animator, AnimatorPlugin};
+//!
+//! #[derive(Component)]
+//! struct Opacity(f32);
+//!
+//! fn apply(mut query: Query<(&Animator<f32>, &mut Opacity)>) {
+//!     for (animator, mut opacity) in query.iter_mut() {
+//!         opacity.0 = animator.value();
+//!     }
+//! }
+//!
+//! let mut app = App::new();
+//! app.add_plugins(AnimatorPlugin::<f32>::default())
+//!     .add_systems(Update, apply);
+//! app.world_mut()
+//!     .spawn((Animator::new(0.0, 1.0, 0.3, soy::EASE_OUT), Opacity(0.0)));
+//! ```
+//!
+//! [0]: struct.AnimatorPlugin.html
+//! [1]: struct.Animator.html#method.value
+use core::marker::PhantomData;
+use core::ops::{Add, Mul, Sub};
+use std::sync::Arc;
+
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::prelude::*;
+use bevy_time::Time;
+
+use crate::{lerp, DynLerper, Lerper};
+
+#[derive(Component)]
+/// Component tweening a value from `start` to `end` over `duration` seconds.
+pub struct Animator<T>
+where
+    T: Send + Sync + 'static,
+{
+    start: T,
+    end: T,
+    duration: f32,
+    elapsed: f32,
+    lerper: DynLerper,
+}
+
+impl<T> Animator<T>
+where
+    T: Send + Sync + 'static,
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    /// Create a new animator, tweening from `start` to `end` over `duration`
+    /// seconds with the given lerper.
+    ///
+    /// # Usage
+    /// ```
+    /// let mut animator = soy::bevy::Animator::new(0.0, 10.0, 2.0, soy::Linear);
+    /// animator.tick(1.0);
+    ///
+    /// assert_eq!(animator.value(), 5.0);
+    /// assert!(!animator.is_finished());
+    /// ```
+    pub fn new<L>(start: T, end: T, duration: f32, lerper: L) -> Animator<T>
+    where
+        L: Lerper + Send + Sync + 'static,
+    {
+        Animator {
+            start,
+            end,
+            duration,
+            elapsed: 0.0,
+            lerper: Arc::new(lerper),
+        }
+    }
+
+    /// Advance the animator by `dt` seconds.
+    pub fn tick(&mut self, dt: f32) {
+        self.elapsed = (self.elapsed + dt).min(self.duration);
+    }
+
+    /// Restart the animator from its start value.
+    pub fn restart(&mut self) {
+        self.elapsed = 0.0;
+    }
+
+    /// Start a new tween from the current value to `end`, over `duration`
+    /// seconds.
+    pub fn retarget(&mut self, end: T, duration: f32) {
+        self.start = self.value();
+        self.end = end;
+        self.duration = duration;
+        self.elapsed = 0.0;
+    }
+
+    /// Current value of the tween.
+    pub fn value(&self) -> T {
+        lerp(&self.lerper, self.start, self.end, self.progress())
+    }
+
+    /// Progress through the tween, from `0.0` to `1.0`.
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            1.0
+        } else {
+            self.elapsed / self.duration
+        }
+    }
+
+    /// Whether the tween has reached its end value.
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+/// System advancing every [`Animator<T>`][0] by Bevy's frame time.
+///
+/// [0]: struct.Animator.html
+pub fn advance_animators<T>(time: Res<Time>, mut query: Query<&mut Animator<T>>)
+where
+    T: Send + Sync + 'static,
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    let dt = time.delta_seconds();
+
+    for mut animator in query.iter_mut() {
+        if !animator.is_finished() {
+            animator.tick(dt);
+        }
+    }
+}
+
+/// Plugin running [`advance_animators`][0] for `T` every update.
+///
+/// [0]: fn.advance_animators.html
+pub struct AnimatorPlugin<T> {
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for AnimatorPlugin<T> {
+    fn default() -> AnimatorPlugin<T> {
+        AnimatorPlugin {
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Plugin for AnimatorPlugin<T>
+where
+    T: Send + Sync + 'static,
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, advance_animators::<T>);
+    }
+}
@@ -1,4 +1,7 @@
-use crate::Lerper;
+use core::hash::{Hash, Hasher};
+
+use crate::hash::hash_f32;
+use crate::{CachedBezier, Lerper, SolveCache};
 
 /// Wrapper around [`Bezier::new`][0].
 ///
@@ -13,17 +16,42 @@ pub fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> Bezier {
     Bezier::new(x1, y1, x2, y2)
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 /// Unit cubic bezier easing function.
+///
+/// Beziers can be compared and hashed, so they can be stored by value and
+/// used as cache keys. Hashing uses the bit pattern of each coefficient.
+///
+/// # Usage
+/// ```
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::{Hash, Hasher};
+///
+/// let hash = |bezier: &soy::Bezier| {
+///     let mut hasher = DefaultHasher::new();
+///     bezier.hash(&mut hasher);
+///     hasher.finish()
+/// };
+///
+/// let ease = soy::cubic_bezier(0.42, 0.0, 0.58, 1.0);
+///
+/// assert_eq!(ease, soy::EASE_IN_OUT);
+/// assert_eq!(hash(&ease), hash(&soy::EASE_IN_OUT));
+/// assert_ne!(ease, soy::EASE_IN);
+/// ```
 pub struct Bezier {
     /// _x_ coordinate co-efficients.
     pub(crate) x: (f32, f32, f32),
     /// _y_ coordinate co-efficients.
     pub(crate) y: (f32, f32, f32),
+    /// Whether to use the fixed cost approximate solve.
+    pub(crate) fast: bool,
 }
 
 impl Bezier {
     const NEWTON_ITERATIONS: usize = 8;
+    const FAST_BISECTIONS: usize = 4;
+    const FAST_NEWTON_ITERATIONS: usize = 3;
     // Assume duration of 1 second.
     const EPSILON: f32 = 1.0 / 200.0;
 
@@ -47,9 +75,102 @@ impl Bezier {
         Bezier {
             x: (ax, bx, cx),
             y: (ay, by, cy),
+            fast: false,
         }
     }
 
+    /// Create a new cubic bezier from its two control points, such as
+    /// `(f32, f32)` tuples, `[f32; 2]` arrays, or `mint::Point2<f32>`.
+    ///
+    /// # Usage
+    /// ```
+    /// let ease = soy::Bezier::from_points((0.42, 0.0), [0.58, 1.0]);
+    /// assert_eq!(ease, soy::EASE_IN_OUT);
+    /// ```
+    pub fn from_points<P1, P2>(p1: P1, p2: P2) -> Bezier
+    where
+        P1: Into<[f32; 2]>,
+        P2: Into<[f32; 2]>,
+    {
+        let [x1, y1] = p1.into();
+        let [x2, y2] = p2.into();
+
+        Bezier::new(x1, y1, x2, y2)
+    }
+
+    /// Create a new cubic bezier that trades precision for speed.
+    ///
+    /// Instead of iterating until the curve is solved precisely, a fast
+    /// bezier always takes four bisection steps followed by three Newton
+    /// steps. This keeps the cost of each calculation fixed and low.
+    ///
+    /// [`new`][0] also stops once _x_ is within 1/200, so for curves like
+    /// [`EASE_IN_OUT`][1] the two agree to around `0.01`. Steep curves, or
+    /// control points at _x = 0_ or _x = 1_, which stall the curve's _x_,
+    /// can differ by several times that.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{Bezier, Lerper};
+    ///
+    /// let exact = Bezier::new(0.42, 0.0, 0.58, 1.0);
+    /// let fast = Bezier::fast(0.42, 0.0, 0.58, 1.0);
+    ///
+    /// for i in 0..=100 {
+    ///     let t = i as f32 / 100.0;
+    ///     assert!((fast.calculate(t) - exact.calculate(t)).abs() < 0.01);
+    /// }
+    /// ```
+    ///
+    /// [0]: #method.new
+    /// [1]: constant.EASE_IN_OUT.html
+    pub fn fast(x1: f32, y1: f32, x2: f32, y2: f32) -> Bezier {
+        Bezier {
+            fast: true,
+            ..Bezier::new(x1, y1, x2, y2)
+        }
+    }
+
+    /// Whether this bezier was created with [`fast`][0].
+    ///
+    /// [0]: #method.fast
+    pub fn is_fast(&self) -> bool {
+        self.fast
+    }
+
+    /// Share a baked solve table with every other copy of this curve, from
+    /// the process-wide [`SolveCache`][0].
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Lerper;
+    ///
+    /// let ease = soy::EASE_OUT.cached();
+    /// assert!((ease.calculate(0.6) - soy::EASE_OUT.calculate(0.6)).abs() < 1.0e-4);
+    /// ```
+    ///
+    /// [0]: struct.SolveCache.html
+    pub fn cached(self) -> CachedBezier {
+        SolveCache::global().get(self)
+    }
+
+    /// Control points of the curve, as `(x1, y1, x2, y2)`.
+    ///
+    /// # Usage
+    /// ```
+    /// let (x1, y1, x2, y2) = soy::EASE_IN.points();
+    ///
+    /// assert!((x1 - 0.42).abs() < 1.0e-6 && y1 == 0.0);
+    /// assert!((x2 - 1.0).abs() < 1.0e-6 && (y2 - 1.0).abs() < 1.0e-6);
+    /// ```
+    pub fn points(&self) -> (f32, f32, f32, f32) {
+        let point = |(_, b, c): (f32, f32, f32)| (c / 3.0, (b + 2.0 * c) / 3.0);
+        let (x1, x2) = point(self.x);
+        let (y1, y2) = point(self.y);
+
+        (x1, y1, x2, y2)
+    }
+
     fn sample_x(&self, t: f32) -> f32 {
         let (a, b, c) = self.x;
 
@@ -57,7 +178,7 @@ impl Bezier {
         ((a * t + b) * t + c) * t
     }
 
-    fn sample_y(&self, t: f32) -> f32 {
+    pub(crate) fn sample_y(&self, t: f32) -> f32 {
         let (a, b, c) = self.y;
 
         ((a * t + b) * t + c) * t
@@ -69,7 +190,81 @@ impl Bezier {
         (3.0 * a * t + 2.0 * b) * t + c
     }
 
-    fn solve_x(&self, x: f32) -> f32 {
+    fn sample_derivative_y(&self, t: f32) -> f32 {
+        let (a, b, c) = self.y;
+
+        (3.0 * a * t + 2.0 * b) * t + c
+    }
+
+    fn sample_second_derivative_x(&self, t: f32) -> f32 {
+        let (a, b, _) = self.x;
+
+        6.0 * a * t + 2.0 * b
+    }
+
+    fn sample_second_derivative_y(&self, t: f32) -> f32 {
+        let (a, b, _) = self.y;
+
+        6.0 * a * t + 2.0 * b
+    }
+
+    /// Slope of the easing curve, _dy/dx_, at the given _x_.
+    ///
+    /// # Usage
+    /// ```
+    /// let linear = soy::cubic_bezier(0.25, 0.25, 0.75, 0.75);
+    /// assert!((linear.derivative(0.5) - 1.0).abs() < 1.0e-4);
+    ///
+    /// // CSS's "ease-in" starts flat.
+    /// assert!(soy::EASE_IN.derivative(0.0).abs() < 1.0e-4);
+    /// ```
+    pub fn derivative(&self, x: f32) -> f32 {
+        let t = self.solve_x(x);
+        let dx = self.sample_derivative_x(t);
+
+        if approx_eq(dx, 0.0, 1.0e-6) {
+            return 0.0;
+        }
+        self.sample_derivative_y(t) / dx
+    }
+
+    /// Second derivative of the easing curve, _d²y/dx²_, at the given _x_.
+    pub fn second_derivative(&self, x: f32) -> f32 {
+        let t = self.solve_x(x);
+        let dx = self.sample_derivative_x(t);
+
+        if approx_eq(dx, 0.0, 1.0e-6) {
+            return 0.0;
+        }
+
+        let dy = self.sample_derivative_y(t);
+        let ddx = self.sample_second_derivative_x(t);
+        let ddy = self.sample_second_derivative_y(t);
+
+        (ddy * dx - dy * ddx) / (dx * dx * dx)
+    }
+
+    /// Curvature of the easing curve's graph at the given _x_.
+    pub fn curvature(&self, x: f32) -> f32 {
+        let t = self.solve_x(x);
+        let (dx, dy) = (self.sample_derivative_x(t), self.sample_derivative_y(t));
+        let (ddx, ddy) = (
+            self.sample_second_derivative_x(t),
+            self.sample_second_derivative_y(t),
+        );
+
+        let speed_squared = dx * dx + dy * dy;
+        if speed_squared <= 0.0 {
+            return 0.0;
+        }
+        (dx * ddy - dy * ddx).abs() / (speed_squared * speed_squared.sqrt())
+    }
+
+    pub(crate) fn solve_x(&self, x: f32) -> f32 {
+        if self.fast {
+            return self.solve_x_fast(x);
+        }
+
         // Newton's method.
         let mut t = x;
 
@@ -113,6 +308,133 @@ impl Bezier {
         // Fallback on failure.
         t
     }
+
+    fn solve_x_fast(&self, x: f32) -> f32 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        if x >= 1.0 {
+            return 1.0;
+        }
+
+        // Bracket the solution first, so Newton's method starts close enough
+        // to converge even where the curve is flat.
+        let (mut low, mut high) = (0.0, 1.0);
+        for _ in 0..Self::FAST_BISECTIONS {
+            let mid = (low + high) / 2.0;
+            if self.sample_x(mid) < x {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        let mut t = (low + high) / 2.0;
+        for _ in 0..Self::FAST_NEWTON_ITERATIONS {
+            let dx = self.sample_derivative_x(t);
+            if approx_eq(dx, 0.0, 1.0e-6) {
+                break;
+            }
+
+            t = (t - (self.sample_x(t) - x) / dx).clamp(low, high);
+        }
+
+        t
+    }
+}
+
+impl Hash for Bezier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let (ax, bx, cx) = self.x;
+        let (ay, by, cy) = self.y;
+        for &coefficient in &[ax, bx, cx, ay, by, cy] {
+            hash_f32(coefficient, state);
+        }
+        self.fast.hash(state);
+    }
+}
+
+impl From<[f32; 4]> for Bezier {
+    /// Create a new cubic bezier from its control points, as
+    /// `[x1, y1, x2, y2]`.
+    ///
+    /// # Usage
+    /// ```
+    /// let ease = soy::Bezier::from([0.42, 0.0, 0.58, 1.0]);
+    /// assert_eq!(ease, soy::EASE_IN_OUT);
+    /// ```
+    fn from([x1, y1, x2, y2]: [f32; 4]) -> Bezier {
+        Bezier::new(x1, y1, x2, y2)
+    }
+}
+
+impl From<Bezier> for [f32; 4] {
+    /// Control points of the curve, as `[x1, y1, x2, y2]`, like
+    /// [`Bezier::points`][0].
+    ///
+    /// [0]: struct.Bezier.html#method.points
+    fn from(bezier: Bezier) -> [f32; 4] {
+        let (x1, y1, x2, y2) = bezier.points();
+        [x1, y1, x2, y2]
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Bezier {
+    /// Beziers are serialized as their control points, `[x1, y1, x2, y2]`,
+    /// followed by `true` for beziers created with [`fast`][0].
+    ///
+    /// [0]: #method.fast
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        let len = if self.fast { 5 } else { 4 };
+        let mut seq = serializer.serialize_seq(Some(len))?;
+        for point in &<[f32; 4]>::from(*self) {
+            seq.serialize_element(point)?;
+        }
+        if self.fast {
+            seq.serialize_element(&true)?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Bezier {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Bezier, D::Error> {
+        struct BezierVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for BezierVisitor {
+            type Value = Bezier;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("control points [x1, y1, x2, y2], optionally followed by a fast flag")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Bezier, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut points = [0.0; 4];
+                for (i, point) in points.iter_mut().enumerate() {
+                    *point = seq
+                        .next_element()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(i, &self))?;
+                }
+                let fast = seq.next_element()?.unwrap_or(false);
+
+                let [x1, y1, x2, y2] = points;
+                Ok(if fast {
+                    Bezier::fast(x1, y1, x2, y2)
+                } else {
+                    Bezier::new(x1, y1, x2, y2)
+                })
+            }
+        }
+
+        deserializer.deserialize_seq(BezierVisitor)
+    }
 }
 
 impl Lerper for Bezier {
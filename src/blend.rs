@@ -0,0 +1,260 @@
+use core::ops::{Add, Mul};
+
+use crate::Lerper;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Value placed in a [`BlendSpace1`][0] or [`BlendSpace2`][1].
+///
+/// [0]: struct.BlendSpace1.html
+/// [1]: struct.BlendSpace2.html
+pub struct BlendSample<T, L, P = f32> {
+    /// Position of the sample in the parameter space.
+    pub position: P,
+    /// Value blended in around `position`, such as an animation pose.
+    pub value: T,
+    /// Easing of the blend from this sample to the next in a `BlendSpace1`,
+    /// or of the sample's influence in a `BlendSpace2`.
+    pub easing: L,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// One-dimensional blend space, which blends between values placed along a
+/// parameter axis, such as idle, walk and run poses placed along walk speed.
+///
+/// At most two neighbouring samples have a weight at any parameter, and the
+/// weights always sum to one. The blend from each sample to the next is
+/// shaped by that sample's easing. Outside of the samples' range, the first
+/// or last sample is held.
+pub struct BlendSpace1<T, L> {
+    samples: Vec<BlendSample<T, L>>,
+}
+
+impl<T, L> BlendSpace1<T, L>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Mul<f32, Output = T>,
+    L: Lerper,
+{
+    /// Create a new blend space from the given samples.
+    ///
+    /// # Panics
+    /// Panics if `samples` is empty, or the samples are not sorted by
+    /// strictly increasing position.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{BlendSample, BlendSpace1};
+    ///
+    /// let gait = BlendSpace1::new(vec![
+    ///     BlendSample { position: 0.0, value: 0.0, easing: soy::Linear },
+    ///     BlendSample { position: 2.0, value: 10.0, easing: soy::Linear },
+    ///     BlendSample { position: 6.0, value: 30.0, easing: soy::Linear },
+    /// ]);
+    ///
+    /// assert_eq!(gait.evaluate(1.0), 5.0);
+    /// assert_eq!(gait.evaluate(4.0), 20.0);
+    /// assert_eq!(gait.evaluate(9.0), 30.0);
+    /// ```
+    pub fn new(samples: Vec<BlendSample<T, L>>) -> BlendSpace1<T, L> {
+        assert!(
+            !samples.is_empty(),
+            "a blend space requires at least one sample"
+        );
+        assert!(
+            samples.windows(2).all(|w| w[0].position < w[1].position),
+            "samples must be sorted by strictly increasing position"
+        );
+
+        BlendSpace1 { samples }
+    }
+
+    /// Samples of the blend space.
+    pub fn samples(&self) -> &[BlendSample<T, L>] {
+        &self.samples
+    }
+
+    /// Index of the first of the two samples blended at `parameter`, and the
+    /// weight of the second.
+    fn blend(&self, parameter: f32) -> (usize, f32) {
+        let last = self.samples.len() - 1;
+
+        if parameter <= self.samples[0].position {
+            return (0, 0.0);
+        }
+        if parameter >= self.samples[last].position {
+            return (last, 0.0);
+        }
+
+        let index = self
+            .samples
+            .partition_point(|s| s.position <= parameter)
+            .saturating_sub(1);
+        let (a, b) = (&self.samples[index], &self.samples[index + 1]);
+        let t = (parameter - a.position) / (b.position - a.position);
+
+        (index, a.easing.calculate(t))
+    }
+
+    /// Normalized weight of each sample at `parameter`, in the same order as
+    /// the samples.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{BlendSample, BlendSpace1};
+    ///
+    /// let space = BlendSpace1::new(vec![
+    ///     BlendSample { position: 0.0, value: 0.0, easing: soy::Linear },
+    ///     BlendSample { position: 1.0, value: 1.0, easing: soy::Linear },
+    ///     BlendSample { position: 2.0, value: 2.0, easing: soy::Linear },
+    /// ]);
+    ///
+    /// assert_eq!(space.weights(1.25), vec![0.0, 0.75, 0.25]);
+    /// ```
+    pub fn weights(&self, parameter: f32) -> Vec<f32> {
+        let mut weights = vec![0.0; self.samples.len()];
+        let (index, weight) = self.blend(parameter);
+
+        weights[index] = 1.0 - weight;
+        if let Some(next) = weights.get_mut(index + 1) {
+            *next += weight;
+        }
+        weights
+    }
+
+    /// Blend the samples' values at `parameter`.
+    pub fn evaluate(&self, parameter: f32) -> T {
+        let (index, weight) = self.blend(parameter);
+        let current = self.samples[index].value;
+
+        match self.samples.get(index + 1) {
+            Some(next) if weight != 0.0 => current * (1.0 - weight) + next.value * weight,
+            _ => current,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Two-dimensional blend space, which blends between values placed
+/// anywhere on a plane of parameters, such as locomotion poses placed by
+/// movement direction and speed.
+///
+/// Weights are found with gradient band interpolation: each sample's
+/// influence falls from `1.0` at its position to `0.0` at the band halfway
+/// across to each other sample, and is shaped by its easing. Influences are
+/// normalized so the weights always sum to one. At a sample's position, that
+/// sample has all of the weight.
+///
+/// # Usage
+/// ```
+/// use soy::{BlendSample, BlendSpace2};
+///
+/// let sample = |x, y, value| BlendSample { position: (x, y), value, easing: soy::Linear };
+/// let locomotion = BlendSpace2::new(vec![
+///     sample(0.0, 0.0, 0.0),
+///     sample(1.0, 0.0, 10.0),
+///     sample(0.0, 1.0, 20.0),
+///     sample(-1.0, 0.0, 30.0),
+/// ]);
+///
+/// assert_eq!(locomotion.evaluate((1.0, 0.0)), 10.0);
+/// assert_eq!(locomotion.weights((0.5, 0.0)), vec![0.5, 0.5, 0.0, 0.0]);
+///
+/// let total: f32 = locomotion.weights((0.3, 0.6)).iter().sum();
+/// assert!((total - 1.0).abs() < 1.0e-6);
+/// ```
+pub struct BlendSpace2<T, L> {
+    samples: Vec<BlendSample<T, L, (f32, f32)>>,
+}
+
+impl<T, L> BlendSpace2<T, L>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Mul<f32, Output = T>,
+    L: Lerper,
+{
+    /// Create a new blend space from the given samples.
+    ///
+    /// # Panics
+    /// Panics if `samples` is empty, or two samples share a position.
+    pub fn new(samples: Vec<BlendSample<T, L, (f32, f32)>>) -> BlendSpace2<T, L> {
+        assert!(
+            !samples.is_empty(),
+            "a blend space requires at least one sample"
+        );
+        for (i, a) in samples.iter().enumerate() {
+            assert!(
+                samples[i + 1..].iter().all(|b| a.position != b.position),
+                "samples must have distinct positions"
+            );
+        }
+
+        BlendSpace2 { samples }
+    }
+
+    /// Samples of the blend space.
+    pub fn samples(&self) -> &[BlendSample<T, L, (f32, f32)>] {
+        &self.samples
+    }
+
+    /// Normalized weight of each sample at `parameter`, in the same order as
+    /// the samples.
+    pub fn weights(&self, parameter: (f32, f32)) -> Vec<f32> {
+        let (px, py) = parameter;
+
+        let mut weights: Vec<f32> = self
+            .samples
+            .iter()
+            .map(|a| {
+                let (ax, ay) = a.position;
+                let (vx, vy) = (px - ax, py - ay);
+
+                let influence = self
+                    .samples
+                    .iter()
+                    .filter(|b| b.position != a.position)
+                    .map(|b| {
+                        let (dx, dy) = (b.position.0 - ax, b.position.1 - ay);
+                        1.0 - (vx * dx + vy * dy) / (dx * dx + dy * dy)
+                    })
+                    .fold(1.0_f32, f32::min)
+                    .max(0.0);
+
+                a.easing.calculate(influence).max(0.0)
+            })
+            .collect();
+
+        let total: f32 = weights.iter().sum();
+        if total > 0.0 {
+            for weight in &mut weights {
+                *weight /= total;
+            }
+        } else {
+            // Easings can flatten every influence, so fall back to the
+            // nearest sample.
+            let distance = |s: &BlendSample<T, L, (f32, f32)>| {
+                let (dx, dy) = (s.position.0 - px, s.position.1 - py);
+                dx * dx + dy * dy
+            };
+            let nearest = (0..self.samples.len())
+                .min_by(|&i, &j| distance(&self.samples[i]).total_cmp(&distance(&self.samples[j])))
+                .unwrap_or(0);
+            weights[nearest] = 1.0;
+        }
+
+        weights
+    }
+
+    /// Blend the samples' values at `parameter`.
+    pub fn evaluate(&self, parameter: (f32, f32)) -> T {
+        let weights = self.weights(parameter);
+        let first = self.samples[0].value * weights[0];
+
+        self.samples
+            .iter()
+            .zip(weights)
+            .skip(1)
+            .fold(first, |sum, (sample, weight)| sum + sample.value * weight)
+    }
+}
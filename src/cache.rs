@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+
+use crate::{Bezier, Lerper, Lut};
+
+/// Curve _x_ co-efficients, by bit pattern, which decide the solve table.
+type Key = [u32; 3];
+
+/// Cache of baked bezier solve tables, shared between every curve with the
+/// same control points.
+///
+/// Solving a bezier for _t_ takes several iterations, which adds up when
+/// hundreds of elements use the same easing. A cached bezier instead looks
+/// up _t_ in a table, baked once and shared by all of its copies. The table
+/// only depends on the _x_ control points, so curves which only differ in
+/// _y_ share one too.
+///
+/// While `x1` and `x2` are strictly between `0.0` and `1.0`, a cached bezier
+/// is within `1.0e-3` of the solved curve. Control points at _x = 0_ or
+/// _x = 1_ make the ends of the table steep, and the error can reach `0.02`.
+///
+/// Caches can be owned, or the process-wide [`global`][0] cache used.
+///
+/// # Usage
+/// ```
+/// use soy::{Lerper, SolveCache};
+///
+/// let cache = SolveCache::new();
+/// let buttons: Vec<_> = (0..100).map(|_| cache.get(soy::EASE_IN_OUT)).collect();
+///
+/// assert_eq!(cache.len(), 1);
+/// assert!((buttons[0].calculate(0.3) - soy::EASE_IN_OUT.calculate(0.3)).abs() < 1.0e-4);
+/// ```
+///
+/// [0]: #method.global
+pub struct SolveCache {
+    tables: Mutex<HashMap<Key, Arc<Lut>>>,
+}
+
+impl SolveCache {
+    /// Number of entries in each solve table.
+    pub const RESOLUTION: usize = 1024;
+
+    /// Create a new, empty, cache.
+    pub fn new() -> SolveCache {
+        SolveCache {
+            tables: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Process-wide cache, used by [`Bezier::cached`][0].
+    ///
+    /// [0]: struct.Bezier.html#method.cached
+    pub fn global() -> &'static SolveCache {
+        static GLOBAL: OnceLock<SolveCache> = OnceLock::new();
+        GLOBAL.get_or_init(SolveCache::new)
+    }
+
+    /// Look up the solve table for `bezier`, baking it if this cache hasn't
+    /// seen its control points before.
+    pub fn get(&self, bezier: Bezier) -> CachedBezier {
+        let (a, b, c) = bezier.x;
+        let key = [a.to_bits(), b.to_bits(), c.to_bits()];
+
+        let table = self
+            .lock()
+            .entry(key)
+            .or_insert_with(|| Arc::new(bake(&bezier)))
+            .clone();
+
+        CachedBezier { bezier, table }
+    }
+
+    /// Number of distinct tables in the cache.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether the cache contains no tables.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Remove every table from the cache. Cached beziers keep their tables.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<Key, Arc<Lut>>> {
+        // Tables are only inserted whole, so a panic can't leave the map
+        // in a broken state.
+        self.tables
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for SolveCache {
+    fn default() -> SolveCache {
+        SolveCache::new()
+    }
+}
+
+/// Bake the curve parameter _t_ at evenly spaced _x_ values.
+fn bake(bezier: &Bezier) -> Lut {
+    let last = (SolveCache::RESOLUTION - 1) as f32;
+    let values = (0..SolveCache::RESOLUTION)
+        .map(|i| bezier.solve_x(i as f32 / last))
+        .collect();
+
+    Lut::new(values)
+}
+
+#[derive(Debug, Clone)]
+/// Bezier which looks up its solution in a shared table.
+///
+/// This is created using [`SolveCache::get`][0] or [`Bezier::cached`][1].
+/// Outside of `[0, 1]`, the bezier is solved directly.
+///
+/// [0]: struct.SolveCache.html#method.get
+/// [1]: struct.Bezier.html#method.cached
+pub struct CachedBezier {
+    bezier: Bezier,
+    table: Arc<Lut>,
+}
+
+impl CachedBezier {
+    /// Bezier being solved.
+    pub fn bezier(&self) -> Bezier {
+        self.bezier
+    }
+}
+
+impl PartialEq for CachedBezier {
+    fn eq(&self, other: &CachedBezier) -> bool {
+        // Tables are decided by the bezier.
+        self.bezier == other.bezier
+    }
+}
+
+impl Lerper for CachedBezier {
+    fn calculate(&self, t: f32) -> f32 {
+        if (0.0..=1.0).contains(&t) {
+            self.bezier.sample_y(self.table.calculate(t))
+        } else {
+            self.bezier.calculate(t)
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::time::Instant;
+
+/// Source of time for driving animations.
+///
+/// Animations in this crate advance by a delta, in seconds. Driving them from
+/// a clock lets the same animation run in real time, in deterministic tests,
+/// or in fixed-step simulations.
+///
+/// # Usage
+/// ```
+/// use soy::{AnimationClock, FixedStepClock};
+///
+/// fn run<C: AnimationClock>(clock: &mut C, shake: &mut soy::Shake<soy::Linear>) {
+///     shake.update(clock.tick());
+/// }
+///
+/// let mut clock = FixedStepClock::new(1.0 / 60.0);
+/// let mut shake = soy::Shake::new(soy::Linear);
+/// shake.add_trauma(1.0);
+///
+/// for _ in 0..60 {
+///     run(&mut clock, &mut shake);
+/// }
+/// assert!((clock.elapsed() - 1.0).abs() < 1.0e-5);
+/// ```
+pub trait AnimationClock {
+    /// Advance the clock, returning the time since the last tick, in seconds.
+    fn tick(&mut self) -> f32;
+
+    /// Total time ticked so far, in seconds.
+    fn elapsed(&self) -> f32;
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+/// Clock which only moves when told to, for tests and externally timed
+/// loops.
+///
+/// # Usage
+/// ```
+/// use soy::{AnimationClock, ManualClock};
+///
+/// let mut clock = ManualClock::new();
+/// clock.advance(0.25);
+/// clock.advance(0.25);
+///
+/// assert_eq!(clock.tick(), 0.5);
+/// assert_eq!(clock.tick(), 0.0);
+/// assert_eq!(clock.elapsed(), 0.5);
+/// ```
+pub struct ManualClock {
+    elapsed: f32,
+    pending: f32,
+}
+
+impl ManualClock {
+    /// Create a new, stopped, clock.
+    pub fn new() -> ManualClock {
+        ManualClock::default()
+    }
+
+    /// Move the clock forward by `dt` seconds, returned by the next tick.
+    pub fn advance(&mut self, dt: f32) {
+        self.pending += dt;
+    }
+}
+
+impl AnimationClock for ManualClock {
+    fn tick(&mut self) -> f32 {
+        let dt = self.pending;
+
+        self.elapsed += dt;
+        self.pending = 0.0;
+        dt
+    }
+
+    fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Clock which advances by the same step every tick, for deterministic,
+/// fixed-step simulations.
+pub struct FixedStepClock {
+    /// Time added every tick, in seconds.
+    pub step: f32,
+    elapsed: f32,
+}
+
+impl FixedStepClock {
+    /// Create a new clock, advancing by `step` seconds every tick.
+    pub fn new(step: f32) -> FixedStepClock {
+        FixedStepClock { step, elapsed: 0.0 }
+    }
+}
+
+impl AnimationClock for FixedStepClock {
+    fn tick(&mut self) -> f32 {
+        self.elapsed += self.step;
+        self.step
+    }
+
+    fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+/// Clock following real, monotonic, time.
+///
+/// # Usage
+/// ```
+/// use soy::{AnimationClock, InstantClock};
+///
+/// let mut clock = InstantClock::new();
+/// let dt = clock.tick();
+///
+/// assert!(dt >= 0.0);
+/// assert_eq!(clock.elapsed(), dt);
+/// ```
+pub struct InstantClock {
+    start: Instant,
+    last: Instant,
+}
+
+impl InstantClock {
+    /// Create a new clock, starting now.
+    pub fn new() -> InstantClock {
+        let now = Instant::now();
+        InstantClock {
+            start: now,
+            last: now,
+        }
+    }
+}
+
+impl Default for InstantClock {
+    fn default() -> InstantClock {
+        InstantClock::new()
+    }
+}
+
+impl AnimationClock for InstantClock {
+    fn tick(&mut self) -> f32 {
+        let now = Instant::now();
+        let dt = now.duration_since(self.last).as_secs_f32();
+
+        self.last = now;
+        dt
+    }
+
+    fn elapsed(&self) -> f32 {
+        self.last.duration_since(self.start).as_secs_f32()
+    }
+}
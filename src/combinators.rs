@@ -0,0 +1,258 @@
+use core::hash::{Hash, Hasher};
+
+use crate::hash::hash_f32;
+use crate::{Lerper, Spline};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Lerper which plays one lerper, then another.
+///
+/// This is usually created using [`Lerper::then`][0].
+///
+/// [0]: trait.Lerper.html#method.then
+pub struct Then<A, B> {
+    pub(crate) first: A,
+    pub(crate) second: B,
+    pub(crate) split: f32,
+    /// Progress reached at `split`.
+    pub(crate) value: f32,
+}
+
+impl<A: Lerper, B: Lerper> Then<A, B> {
+    /// Play `first` until `split`, covering progress from `0.0` to `split`,
+    /// then `second`, covering the remaining progress.
+    pub fn new(first: A, second: B, split: f32) -> Then<A, B> {
+        let split = split.clamp(0.0, 1.0);
+
+        Then::with_knot(first, second, split, split)
+    }
+
+    /// Play `first` until `split`, covering progress from `0.0` to `value`,
+    /// then `second`, covering the remaining progress.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{Lerper, Linear, Then};
+    ///
+    /// let fast_start = Then::with_knot(Linear, Linear, 0.25, 0.75);
+    ///
+    /// assert_eq!(fast_start.calculate(0.125), 0.375);
+    /// assert_eq!(fast_start.calculate(0.25), 0.75);
+    /// assert_eq!(fast_start.calculate(1.0), 1.0);
+    /// ```
+    pub fn with_knot(first: A, second: B, split: f32, value: f32) -> Then<A, B> {
+        Then {
+            first,
+            second,
+            split: split.clamp(0.0, 1.0),
+            value,
+        }
+    }
+}
+
+impl<A: Hash, B: Hash> Hash for Then<A, B> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.first.hash(state);
+        self.second.hash(state);
+        hash_f32(self.split, state);
+        hash_f32(self.value, state);
+    }
+}
+
+impl<A: Lerper, B: Lerper> Lerper for Then<A, B> {
+    fn calculate(&self, t: f32) -> f32 {
+        let (split, value) = (self.split, self.value);
+
+        if t < split {
+            value * self.first.calculate(t / split)
+        } else if split < 1.0 {
+            value + (1.0 - value) * self.second.calculate((t - split) / (1.0 - split))
+        } else {
+            value * self.first.calculate(t)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Lerper which plays another lerper several times in a row.
+///
+/// This is usually created using [`Lerper::repeat`][0].
+///
+/// [0]: trait.Lerper.html#method.repeat
+pub struct Repeat<L> {
+    pub(crate) lerper: L,
+    pub(crate) count: u32,
+}
+
+impl<L: Lerper> Repeat<L> {
+    /// Play `lerper` `count` times over the range of _t_.
+    pub fn new(lerper: L, count: u32) -> Repeat<L> {
+        Repeat {
+            lerper,
+            count: count.max(1),
+        }
+    }
+}
+
+impl<L: Lerper> Lerper for Repeat<L> {
+    fn calculate(&self, t: f32) -> f32 {
+        if t >= 1.0 {
+            return self.lerper.calculate(t);
+        }
+
+        let scaled = t * self.count as f32;
+        self.lerper.calculate(scaled - scaled.floor())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Lerper which plays another lerper backwards, turning an ease in into an
+/// ease out.
+///
+/// This is usually created using [`Lerper::reverse`][0].
+///
+/// [0]: trait.Lerper.html#method.reverse
+pub struct Reverse<L> {
+    pub(crate) lerper: L,
+}
+
+impl<L: Lerper> Reverse<L> {
+    /// Play `lerper` backwards.
+    pub fn new(lerper: L) -> Reverse<L> {
+        Reverse { lerper }
+    }
+}
+
+impl<L: Lerper> Lerper for Reverse<L> {
+    fn calculate(&self, t: f32) -> f32 {
+        1.0 - self.lerper.calculate(1.0 - t)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Lerper which rescales another lerper to start at `0.0` and end at `1.0`.
+///
+/// Sums, products and noise can break the endpoints of a curve, which this
+/// restores. A curve which starts and ends at the same value can't be
+/// rescaled, so it's shifted by a linear ramp instead.
+///
+/// This is usually created using [`Lerper::normalized`][0].
+///
+/// [0]: trait.Lerper.html#method.normalized
+pub struct Normalized<L> {
+    pub(crate) lerper: L,
+    start: f32,
+    end: f32,
+}
+
+impl<L: Lerper> Normalized<L> {
+    /// Rescale `lerper` to start at `0.0` and end at `1.0`.
+    pub fn new(lerper: L) -> Normalized<L> {
+        let start = lerper.calculate(0.0);
+        let end = lerper.calculate(1.0);
+
+        Normalized { lerper, start, end }
+    }
+}
+
+impl<L: Hash> Hash for Normalized<L> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // The endpoints are decided by the lerper.
+        self.lerper.hash(state);
+    }
+}
+
+impl<L: Lerper> Lerper for Normalized<L> {
+    fn calculate(&self, t: f32) -> f32 {
+        let y = self.lerper.calculate(t) - self.start;
+        let range = self.end - self.start;
+
+        if range.abs() > f32::EPSILON {
+            y / range
+        } else {
+            y + t
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Lerper which saturates the output of another lerper to `[0, 1]`, so
+/// overshooting easings never interpolate past either end.
+///
+/// Useful for properties which are only valid within the range, like
+/// opacity.
+///
+/// This is usually created using [`Lerper::clamped`][0].
+///
+/// [0]: trait.Lerper.html#method.clamped
+pub struct Clamped<L> {
+    pub(crate) lerper: L,
+}
+
+impl<L: Lerper> Clamped<L> {
+    /// Saturate the output of `lerper` to `[0, 1]`.
+    pub fn new(lerper: L) -> Clamped<L> {
+        Clamped { lerper }
+    }
+}
+
+impl<L: Lerper> Lerper for Clamped<L> {
+    fn calculate(&self, t: f32) -> f32 {
+        self.lerper.calculate(t).clamp(0.0, 1.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Lerper or spline played through a time-remapping curve, which maps the
+/// outer _t_ to the _t_ the inner curve is evaluated at.
+///
+/// Remapping warps the playback of a track without re-authoring its keys,
+/// for speed ramps, slow-in and slow-out, and, with a flat stretch in the
+/// time curve, freeze frames.
+///
+/// # Usage
+/// ```
+/// use soy::{Hermite, Lerper, Linear, Remap, Spline, TangentMode, Then};
+///
+/// let track = Hermite::auto(vec![(0.0, 0.0), (2.0, 10.0)], TangentMode::CatmullRom);
+///
+/// // Play the first half of the track in the first quarter of the time.
+/// let ramp = Then::with_knot(Linear, Linear, 0.25, 0.5);
+/// let ramped = Remap::new(track, ramp);
+/// assert_eq!(ramped.sample(0.25), 5.0);
+///
+/// let eased = Remap::new(Linear, soy::EASE_IN);
+/// assert_eq!(eased.calculate(0.3), soy::EASE_IN.calculate(0.3));
+/// ```
+pub struct Remap<A, B> {
+    pub(crate) inner: A,
+    pub(crate) time: B,
+}
+
+impl<A, B: Lerper> Remap<A, B> {
+    /// Play `inner` through the time curve `time`.
+    pub fn new(inner: A, time: B) -> Remap<A, B> {
+        Remap { inner, time }
+    }
+
+    /// Remapped curve.
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    /// Time curve, from the outer _t_ to the inner _t_.
+    pub fn time(&self) -> &B {
+        &self.time
+    }
+}
+
+impl<A: Lerper, B: Lerper> Lerper for Remap<A, B> {
+    fn calculate(&self, t: f32) -> f32 {
+        self.inner.calculate(self.time.calculate(t))
+    }
+}
+
+impl<T, A: Spline<T>, B: Lerper> Spline<T> for Remap<A, B> {
+    fn sample(&self, t: f32) -> T {
+        self.inner.sample(self.time.calculate(t))
+    }
+}
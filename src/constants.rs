@@ -1,25 +1,297 @@
-use crate::Bezier;
+use crate::{Bezier, EasingFamily, EasingMode, Penner, Then};
 
 /// Ease function, same as CSS's "ease" timing-function.
 pub const EASE: Bezier = Bezier {
     x: (1.0, -0.75, 0.75),
     y: (-1.7, 2.4, 0.3),
+    fast: false,
 };
 
 /// Ease in function, same as CSS's "ease-in" timing-function.
 pub const EASE_IN: Bezier = Bezier {
     x: (-0.74, 0.48, 1.26),
     y: (-2.0, 3.0, 0.0),
+    fast: false,
 };
 
 /// Ease out function, same as CSS's "ease-out" timing-function.
 pub const EASE_OUT: Bezier = Bezier {
     x: (-0.74, 1.74, 0.0),
     y: (-2.0, 3.0, 0.0),
+    fast: false,
 };
 
 /// Ease in-out function, same as CSS's "ease-in-out" timing-function.
 pub const EASE_IN_OUT: Bezier = Bezier {
     x: (0.52, -0.78, 1.26),
     y: (-2.0, 3.0, 0.0),
+    fast: false,
+};
+
+/// Material Design 3's standard easing, `cubic-bezier(0.2, 0.0, 0.0, 1.0)`.
+pub const MATERIAL_STANDARD: Bezier = Bezier {
+    x: (1.6, -1.2, 0.6),
+    y: (-2.0, 3.0, 0.0),
+    fast: false,
+};
+
+/// Material Design 3's standard decelerate easing, `cubic-bezier(0.0, 0.0, 0.0, 1.0)`.
+pub const MATERIAL_STANDARD_DECELERATE: Bezier = Bezier {
+    x: (1.0, 0.0, 0.0),
+    y: (-2.0, 3.0, 0.0),
+    fast: false,
+};
+
+/// Material Design 3's standard accelerate easing, `cubic-bezier(0.3, 0.0, 1.0, 1.0)`.
+pub const MATERIAL_STANDARD_ACCELERATE: Bezier = Bezier {
+    x: (-1.1, 1.2, 0.9),
+    y: (-2.0, 3.0, 0.0),
+    fast: false,
+};
+
+/// Material Design 3's emphasized easing.
+///
+/// This curve is made of two cubic beziers, joined at _(0.166666, 0.4)_: the
+/// [emphasized accelerate][0] curve, then the [emphasized decelerate][1]
+/// curve.
+///
+/// # Usage
+/// ```
+/// use soy::Lerper;
+///
+/// let ease = soy::MATERIAL_EMPHASIZED;
+/// assert!((ease.calculate(0.166666) - 0.4).abs() < 1.0e-5);
+/// assert_eq!(ease.calculate(1.0), 1.0);
+/// ```
+///
+/// [0]: constant.MATERIAL_EMPHASIZED_ACCELERATE.html
+/// [1]: constant.MATERIAL_EMPHASIZED_DECELERATE.html
+pub const MATERIAL_EMPHASIZED: Then<Bezier, Bezier> = Then {
+    first: Bezier {
+        x: (-0.5, 0.6, 0.9),
+        y: (0.55, 0.45, 0.0),
+        fast: false,
+    },
+    second: Bezier {
+        x: (0.85, 0.0, 0.15),
+        y: (0.1, -1.2, 2.1),
+        fast: false,
+    },
+    split: 0.166666,
+    value: 0.4,
+};
+
+/// Material Design 3's emphasized decelerate easing, `cubic-bezier(0.05, 0.7, 0.1, 1.0)`.
+pub const MATERIAL_EMPHASIZED_DECELERATE: Bezier = Bezier {
+    x: (0.85, 0.0, 0.15),
+    y: (0.1, -1.2, 2.1),
+    fast: false,
+};
+
+/// Material Design 3's emphasized accelerate easing, `cubic-bezier(0.3, 0.0, 0.8, 0.15)`.
+pub const MATERIAL_EMPHASIZED_ACCELERATE: Bezier = Bezier {
+    x: (-0.5, 0.6, 0.9),
+    y: (0.55, 0.45, 0.0),
+    fast: false,
+};
+
+/// Material Design 3's legacy easing, `cubic-bezier(0.4, 0.0, 0.2, 1.0)`.
+pub const MATERIAL_LEGACY: Bezier = Bezier {
+    x: (1.6, -1.8, 1.2),
+    y: (-2.0, 3.0, 0.0),
+    fast: false,
+};
+
+/// Material Design 3's legacy decelerate easing, `cubic-bezier(0.0, 0.0, 0.2, 1.0)`.
+pub const MATERIAL_LEGACY_DECELERATE: Bezier = Bezier {
+    x: (0.4, 0.6, 0.0),
+    y: (-2.0, 3.0, 0.0),
+    fast: false,
+};
+
+/// Material Design 3's legacy accelerate easing, `cubic-bezier(0.4, 0.0, 1.0, 1.0)`.
+pub const MATERIAL_LEGACY_ACCELERATE: Bezier = Bezier {
+    x: (-0.8, 0.6, 1.2),
+    y: (-2.0, 3.0, 0.0),
+    fast: false,
+};
+
+/// Penner's sinusoidal ease in function, easings.net's "ease-in-sine".
+pub const EASE_IN_SINE: Penner = Penner {
+    family: EasingFamily::Sine,
+    mode: EasingMode::In,
+};
+
+/// Penner's quadratic ease in function, easings.net's "ease-in-quad".
+pub const EASE_IN_QUAD: Penner = Penner {
+    family: EasingFamily::Quad,
+    mode: EasingMode::In,
+};
+
+/// Penner's cubic ease in function, easings.net's "ease-in-cubic".
+pub const EASE_IN_CUBIC: Penner = Penner {
+    family: EasingFamily::Cubic,
+    mode: EasingMode::In,
+};
+
+/// Penner's quartic ease in function, easings.net's "ease-in-quart".
+pub const EASE_IN_QUART: Penner = Penner {
+    family: EasingFamily::Quart,
+    mode: EasingMode::In,
+};
+
+/// Penner's quintic ease in function, easings.net's "ease-in-quint".
+pub const EASE_IN_QUINT: Penner = Penner {
+    family: EasingFamily::Quint,
+    mode: EasingMode::In,
+};
+
+/// Penner's exponential ease in function, easings.net's "ease-in-expo".
+pub const EASE_IN_EXPO: Penner = Penner {
+    family: EasingFamily::Expo,
+    mode: EasingMode::In,
+};
+
+/// Penner's circular ease in function, easings.net's "ease-in-circ".
+pub const EASE_IN_CIRC: Penner = Penner {
+    family: EasingFamily::Circ,
+    mode: EasingMode::In,
+};
+
+/// Penner's back ease in function, easings.net's "ease-in-back".
+pub const EASE_IN_BACK: Penner = Penner {
+    family: EasingFamily::Back,
+    mode: EasingMode::In,
+};
+
+/// Penner's elastic ease in function, easings.net's "ease-in-elastic".
+pub const EASE_IN_ELASTIC: Penner = Penner {
+    family: EasingFamily::Elastic,
+    mode: EasingMode::In,
+};
+
+/// Penner's bounce ease in function, easings.net's "ease-in-bounce".
+pub const EASE_IN_BOUNCE: Penner = Penner {
+    family: EasingFamily::Bounce,
+    mode: EasingMode::In,
+};
+
+/// Penner's sinusoidal ease out function, easings.net's "ease-out-sine".
+pub const EASE_OUT_SINE: Penner = Penner {
+    family: EasingFamily::Sine,
+    mode: EasingMode::Out,
+};
+
+/// Penner's quadratic ease out function, easings.net's "ease-out-quad".
+pub const EASE_OUT_QUAD: Penner = Penner {
+    family: EasingFamily::Quad,
+    mode: EasingMode::Out,
+};
+
+/// Penner's cubic ease out function, easings.net's "ease-out-cubic".
+pub const EASE_OUT_CUBIC: Penner = Penner {
+    family: EasingFamily::Cubic,
+    mode: EasingMode::Out,
+};
+
+/// Penner's quartic ease out function, easings.net's "ease-out-quart".
+pub const EASE_OUT_QUART: Penner = Penner {
+    family: EasingFamily::Quart,
+    mode: EasingMode::Out,
+};
+
+/// Penner's quintic ease out function, easings.net's "ease-out-quint".
+pub const EASE_OUT_QUINT: Penner = Penner {
+    family: EasingFamily::Quint,
+    mode: EasingMode::Out,
+};
+
+/// Penner's exponential ease out function, easings.net's "ease-out-expo".
+pub const EASE_OUT_EXPO: Penner = Penner {
+    family: EasingFamily::Expo,
+    mode: EasingMode::Out,
+};
+
+/// Penner's circular ease out function, easings.net's "ease-out-circ".
+pub const EASE_OUT_CIRC: Penner = Penner {
+    family: EasingFamily::Circ,
+    mode: EasingMode::Out,
+};
+
+/// Penner's back ease out function, easings.net's "ease-out-back".
+pub const EASE_OUT_BACK: Penner = Penner {
+    family: EasingFamily::Back,
+    mode: EasingMode::Out,
+};
+
+/// Penner's elastic ease out function, easings.net's "ease-out-elastic".
+pub const EASE_OUT_ELASTIC: Penner = Penner {
+    family: EasingFamily::Elastic,
+    mode: EasingMode::Out,
+};
+
+/// Penner's bounce ease out function, easings.net's "ease-out-bounce".
+pub const EASE_OUT_BOUNCE: Penner = Penner {
+    family: EasingFamily::Bounce,
+    mode: EasingMode::Out,
+};
+
+/// Penner's sinusoidal ease in out function, easings.net's "ease-in-out-sine".
+pub const EASE_IN_OUT_SINE: Penner = Penner {
+    family: EasingFamily::Sine,
+    mode: EasingMode::InOut,
+};
+
+/// Penner's quadratic ease in out function, easings.net's "ease-in-out-quad".
+pub const EASE_IN_OUT_QUAD: Penner = Penner {
+    family: EasingFamily::Quad,
+    mode: EasingMode::InOut,
+};
+
+/// Penner's cubic ease in out function, easings.net's "ease-in-out-cubic".
+pub const EASE_IN_OUT_CUBIC: Penner = Penner {
+    family: EasingFamily::Cubic,
+    mode: EasingMode::InOut,
+};
+
+/// Penner's quartic ease in out function, easings.net's "ease-in-out-quart".
+pub const EASE_IN_OUT_QUART: Penner = Penner {
+    family: EasingFamily::Quart,
+    mode: EasingMode::InOut,
+};
+
+/// Penner's quintic ease in out function, easings.net's "ease-in-out-quint".
+pub const EASE_IN_OUT_QUINT: Penner = Penner {
+    family: EasingFamily::Quint,
+    mode: EasingMode::InOut,
+};
+
+/// Penner's exponential ease in out function, easings.net's "ease-in-out-expo".
+pub const EASE_IN_OUT_EXPO: Penner = Penner {
+    family: EasingFamily::Expo,
+    mode: EasingMode::InOut,
+};
+
+/// Penner's circular ease in out function, easings.net's "ease-in-out-circ".
+pub const EASE_IN_OUT_CIRC: Penner = Penner {
+    family: EasingFamily::Circ,
+    mode: EasingMode::InOut,
+};
+
+/// Penner's back ease in out function, easings.net's "ease-in-out-back".
+pub const EASE_IN_OUT_BACK: Penner = Penner {
+    family: EasingFamily::Back,
+    mode: EasingMode::InOut,
+};
+
+/// Penner's elastic ease in out function, easings.net's "ease-in-out-elastic".
+pub const EASE_IN_OUT_ELASTIC: Penner = Penner {
+    family: EasingFamily::Elastic,
+    mode: EasingMode::InOut,
+};
+
+/// Penner's bounce ease in out function, easings.net's "ease-in-out-bounce".
+pub const EASE_IN_OUT_BOUNCE: Penner = Penner {
+    family: EasingFamily::Bounce,
+    mode: EasingMode::InOut,
 };
@@ -0,0 +1,87 @@
+use crate::Lerper;
+
+#[derive(Debug)]
+/// Eased count from one integer to another, for scores and statistics.
+///
+/// The count only ever moves towards `to`, even if the lerper overshoots or
+/// changes direction, and lands exactly on `to` once _t_ reaches `1.0`.
+/// Currency can be counted in its smallest unit, such as cents.
+///
+/// # Usage
+/// ```
+/// let mut score = soy::CountUp::new(0, 1000, soy::EASE_OUT).with_step(10);
+///
+/// let mut last = 0;
+/// for i in 0..=60 {
+///     let value = score.value(i as f32 / 60.0);
+///
+///     assert!(value >= last);
+///     assert_eq!(value % 10, 0);
+///     last = value;
+/// }
+/// assert_eq!(last, 1000);
+/// ```
+pub struct CountUp<L> {
+    from: i64,
+    to: i64,
+    step: i64,
+    lerper: L,
+    shown: i64,
+}
+
+impl<L: Lerper> CountUp<L> {
+    /// Create a new count from `from` to `to`, eased with the given lerper.
+    pub fn new(from: i64, to: i64, lerper: L) -> CountUp<L> {
+        CountUp {
+            from,
+            to,
+            step: 1,
+            lerper,
+            shown: from,
+        }
+    }
+
+    /// Only show values which are a multiple of `step` away from `from`,
+    /// except for `to` itself.
+    ///
+    /// # Panics
+    /// Panics if `step` is not positive.
+    pub fn with_step(mut self, step: i64) -> CountUp<L> {
+        assert!(step > 0, "a count requires a positive step");
+
+        self.step = step;
+        self
+    }
+
+    /// Value of the count at `t`.
+    pub fn value(&mut self, t: f32) -> i64 {
+        let target = if t >= 1.0 {
+            self.to
+        } else {
+            let progress = f64::from(self.lerper.calculate(t).clamp(0.0, 1.0));
+            let distance = ((self.to - self.from) as f64 * progress) as i64;
+
+            // Truncating towards `from` means `to` is only shown at the end.
+            self.from + distance / self.step * self.step
+        };
+
+        self.shown = if self.to >= self.from {
+            self.shown.max(target)
+        } else {
+            self.shown.min(target)
+        };
+        self.shown
+    }
+
+    /// Last value returned by [`value`][0].
+    ///
+    /// [0]: #method.value
+    pub fn shown(&self) -> i64 {
+        self.shown
+    }
+
+    /// Restart the count from `from`.
+    pub fn reset(&mut self) {
+        self.shown = self.from;
+    }
+}
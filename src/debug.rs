@@ -0,0 +1,168 @@
+//! Curve previews as PNG images, enabled with the `debug` feature.
+//!
+//! Images are small grayscale PNGs, meant for embedding in bug reports and
+//! design docs. They're stored uncompressed, so no image crates are needed.
+use std::io;
+
+use crate::{sample, Lerper};
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// Largest amount of data in a single stored deflate block.
+const MAX_STORED: usize = 0xffff;
+
+const BACKGROUND: u8 = 0xff;
+const AXIS: u8 = 0xc0;
+const CURVE: u8 = 0x00;
+
+/// Render a lerper as a `width` by `height` PNG image.
+///
+/// Like [`to_svg`][0], the image grows to include any part of the curve that
+/// overshoots _[0, 1]_, with grey lines marking _y = 0_ and _y = 1_.
+///
+/// # Panics
+/// Panics if `width` or `height` is zero.
+///
+/// # Usage
+/// ```
+/// let png = soy::debug::to_png(soy::EASE_IN_OUT, 64, 64);
+/// assert_eq!(&png[1..4], b"PNG");
+/// ```
+///
+/// [0]: ../fn.to_svg.html
+pub fn to_png<L: Lerper>(lerper: L, width: u32, height: u32) -> Vec<u8> {
+    let mut png = Vec::new();
+    let _ = write_png(lerper, width, height, &mut png);
+    png
+}
+
+/// Render a lerper as a `width` by `height` PNG image, like
+/// [`to_png`][0], writing it to `writer`.
+///
+/// # Panics
+/// Panics if `width` or `height` is zero.
+///
+/// # Usage
+/// ```no_run
+/// let file = std::fs::File::create("ease.png").unwrap();
+/// soy::debug::write_png(soy::EASE, 128, 128, file).unwrap();
+/// ```
+///
+/// [0]: fn.to_png.html
+pub fn write_png<L, W>(lerper: L, width: u32, height: u32, mut writer: W) -> io::Result<()>
+where
+    L: Lerper,
+    W: io::Write,
+{
+    assert!(
+        width > 0 && height > 0,
+        "an image requires at least one pixel"
+    );
+
+    let pixels = rasterize(lerper, width as usize, height as usize);
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&width.to_be_bytes());
+    header.extend_from_slice(&height.to_be_bytes());
+    // 8 bit grayscale, default compression and filtering, no interlacing.
+    header.extend_from_slice(&[8, 0, 0, 0, 0]);
+
+    writer.write_all(&SIGNATURE)?;
+    write_chunk(&mut writer, b"IHDR", &header)?;
+    write_chunk(&mut writer, b"IDAT", &zlib_stored(&pixels))?;
+    write_chunk(&mut writer, b"IEND", &[])
+}
+
+/// Draw the curve into rows of grayscale pixels, each starting with PNG's
+/// "no filter" byte.
+fn rasterize<L: Lerper>(lerper: L, width: usize, height: usize) -> Vec<u8> {
+    let points: Vec<_> = sample(lerper, width).collect();
+    let low = points.iter().map(|p| p.1).fold(0.0_f32, f32::min);
+    let high = points.iter().map(|p| p.1).fold(1.0_f32, f32::max);
+
+    let row_of = |y: f32| {
+        let row = ((high - y) / (high - low) * (height - 1) as f32).round();
+        (row.max(0.0) as usize).min(height - 1)
+    };
+
+    let stride = width + 1;
+    let mut pixels = vec![BACKGROUND; stride * height];
+    for row in 0..height {
+        pixels[row * stride] = 0;
+    }
+
+    for &y in &[0.0, 1.0] {
+        let row = row_of(y);
+        for pixel in &mut pixels[row * stride + 1..(row + 1) * stride] {
+            *pixel = AXIS;
+        }
+    }
+
+    // Fill between neighbouring samples, so steep parts stay connected.
+    let mut previous = None;
+    for (column, &(_, y)) in points.iter().enumerate() {
+        let row = row_of(y);
+        let (top, bottom) = match previous {
+            Some(last) if last < row => (last + 1, row),
+            Some(last) if last > row => (row, last - 1),
+            _ => (row, row),
+        };
+
+        for r in top..=bottom {
+            pixels[r * stride + column + 1] = CURVE;
+        }
+        previous = Some(row);
+    }
+
+    pixels
+}
+
+/// Wrap `data` in a zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut stream = vec![0x78, 0x01];
+
+    let mut blocks = data.chunks(MAX_STORED).peekable();
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+
+        stream.push(last as u8);
+        stream.extend_from_slice(&len.to_le_bytes());
+        stream.extend_from_slice(&(!len).to_le_bytes());
+        stream.extend_from_slice(block);
+    }
+
+    stream.extend_from_slice(&adler32(data).to_be_bytes());
+    stream
+}
+
+fn write_chunk<W: io::Write>(writer: &mut W, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    writer.write_all(&(data.len() as u32).to_be_bytes())?;
+    writer.write_all(kind)?;
+    writer.write_all(data)?;
+
+    let crc = !crc32(crc32(!0, kind), data);
+    writer.write_all(&crc.to_be_bytes())
+}
+
+/// Continue a CRC-32 over `data`, as used by PNG chunks.
+fn crc32(mut crc: u32, data: &[u8]) -> u32 {
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    crc
+}
+
+/// Adler-32 checksum of `data`, as used by zlib streams.
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1_u32, 0_u32);
+    for &byte in data {
+        a = (a + u32::from(byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
@@ -0,0 +1,128 @@
+use core::f32::consts::PI;
+use core::ops::{Add, Mul, Sub};
+
+#[derive(Debug)]
+/// Second-order dynamics system, used to give an input value organic motion.
+///
+/// The system is tuned by three parameters:
+/// - `f`: Natural frequency, in hertz. Controls how fast the system responds
+///   to changes in the input.
+/// - `z`: Damping ratio (_ζ_). At `0.0` the system vibrates forever, below
+///   `1.0` it overshoots, and at or above `1.0` it settles without
+///   overshooting.
+/// - `r`: Initial response. At `0.0` the system takes time to start moving,
+///   above `1.0` it overshoots immediately, and below `0.0` it anticipates the
+///   motion.
+pub struct SecondOrder<T> {
+    k1: f32,
+    k2: f32,
+    k3: f32,
+    /// Previous input, used to estimate the input velocity.
+    xp: T,
+    /// Current output.
+    y: T,
+    /// Current output velocity.
+    yd: T,
+}
+
+impl<T> SecondOrder<T>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    /// Create a new second-order system, resting at `x0`.
+    ///
+    /// # Panics
+    /// Panics if `f` is not positive, since the system's constants divide by
+    /// it.
+    ///
+    /// # Usage
+    /// ```
+    /// let mut follow = soy::SecondOrder::new(2.0, 0.5, 1.0, 0.0);
+    ///
+    /// for _ in 0..600 {
+    ///     follow.update(1.0 / 60.0, 10.0);
+    /// }
+    ///
+    /// assert!((follow.value() - 10.0).abs() < 1.0e-3);
+    /// ```
+    pub fn new(f: f32, z: f32, r: f32, x0: T) -> SecondOrder<T> {
+        assert!(
+            f > 0.0,
+            "a second-order system requires a positive frequency"
+        );
+
+        let k1 = z / (PI * f);
+        let k2 = 1.0 / ((2.0 * PI * f) * (2.0 * PI * f));
+        let k3 = r * z / (2.0 * PI * f);
+
+        SecondOrder {
+            k1,
+            k2,
+            k3,
+            xp: x0,
+            y: x0,
+            yd: x0 * 0.0,
+        }
+    }
+
+    /// Advance the system by `dt` seconds towards the input `x`, returning the
+    /// new output.
+    ///
+    /// The input velocity is estimated from the previous input. When `dt` is
+    /// not positive, such as on a paused or repeated frame, the system doesn't
+    /// move, and the current output is returned.
+    ///
+    /// # Usage
+    /// ```
+    /// let mut follow = soy::SecondOrder::new(2.0, 0.5, 1.0, 0.0);
+    /// let moving = follow.update(1.0 / 60.0, 10.0);
+    ///
+    /// assert_eq!(follow.update(0.0, 20.0), moving);
+    /// assert!(follow.update(1.0 / 60.0, 20.0).is_finite());
+    /// ```
+    pub fn update(&mut self, dt: f32, x: T) -> T {
+        if dt <= 0.0 {
+            return self.y;
+        }
+
+        let xd = (x - self.xp) * (1.0 / dt);
+        self.update_with_velocity(dt, x, xd)
+    }
+
+    /// Advance the system by `dt` seconds towards the input `x`, moving with
+    /// velocity `xd`, returning the new output.
+    ///
+    /// When `dt` is not positive, the current output is returned.
+    pub fn update_with_velocity(&mut self, dt: f32, x: T, xd: T) -> T {
+        if dt <= 0.0 {
+            return self.y;
+        }
+
+        self.xp = x;
+
+        // Clamp k2 to keep the integration stable for large time steps.
+        let k2 = self
+            .k2
+            .max(dt * dt / 2.0 + dt * self.k1 / 2.0)
+            .max(dt * self.k1);
+
+        self.y = self.y + self.yd * dt;
+        let accel = x + xd * self.k3 - self.y - self.yd * self.k1;
+        self.yd = self.yd + accel * (dt / k2);
+
+        self.y
+    }
+
+    /// Current output of the system.
+    pub fn value(&self) -> T {
+        self.y
+    }
+
+    /// Current velocity of the output.
+    pub fn velocity(&self) -> T {
+        self.yd
+    }
+}
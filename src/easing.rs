@@ -0,0 +1,150 @@
+use crate::{
+    Anticipate, AnticipateOvershoot, Bezier, Bias, EqualGain, EqualPower, Flicker, Gain,
+    GradientNoise, Lerper, Lfo, Linear, Lut, Overshoot, Penner, Sigmoid, Smoothstep, Spring, Steps,
+};
+
+#[derive(Debug, Clone, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// Any one of the built-in easings, as plain data.
+///
+/// Unlike a [`DynLerper`][0], an easing function can be compared, hashed and,
+/// with the `serde` feature, serialized, so configs and network messages can
+/// carry an easing without trait objects. Beziers are serialized as their
+/// control points, followed by `true` if they're fast. For composite
+/// easings, see [`Pipeline`][1].
+///
+/// # Usage
+/// ```
+/// use soy::{EasingFunction, Lerper};
+///
+/// let easings = vec![
+///     EasingFunction::Linear,
+///     EasingFunction::from(soy::EASE_OUT),
+///     EasingFunction::from(soy::Steps::new(4, soy::StepPosition::JumpEnd)),
+///     EasingFunction::from(soy::Spring::with_response(0.5, 0.3)),
+///     EasingFunction::from(soy::EASE_IN_OUT_BOUNCE),
+/// ];
+///
+/// for easing in &easings {
+///     assert_eq!(easing.calculate(0.0), 0.0);
+///     assert_eq!(easing.calculate(1.0), 1.0);
+/// }
+/// ```
+///
+/// [0]: type.DynLerper.html
+/// [1]: enum.Pipeline.html
+pub enum EasingFunction {
+    /// [`Linear`](struct.Linear.html) interpolation.
+    Linear,
+    /// Unit cubic [`Bezier`](struct.Bezier.html), like CSS's
+    /// `cubic-bezier()`.
+    CubicBezier(Bezier),
+    /// [`Steps`](struct.Steps.html), like CSS's `steps()`.
+    Steps(Steps),
+    /// One of Robert [`Penner`](struct.Penner.html)'s easings.
+    Penner(Penner),
+    /// Damped [`Spring`](struct.Spring.html).
+    Spring(Spring),
+    /// Android's [`Anticipate`](struct.Anticipate.html) curve.
+    Anticipate(Anticipate),
+    /// Android's [`Overshoot`](struct.Overshoot.html) curve.
+    Overshoot(Overshoot),
+    /// Android's [`AnticipateOvershoot`](struct.AnticipateOvershoot.html)
+    /// curve.
+    AnticipateOvershoot(AnticipateOvershoot),
+    /// Schlick [`Bias`](struct.Bias.html).
+    Bias(Bias),
+    /// Schlick [`Gain`](struct.Gain.html).
+    Gain(Gain),
+    /// Logistic [`Sigmoid`](struct.Sigmoid.html).
+    Sigmoid(Sigmoid),
+    /// Generalized [`Smoothstep`](struct.Smoothstep.html).
+    Smoothstep(Smoothstep),
+    /// Lookup table, [`Lut`](struct.Lut.html).
+    Lut(Lut),
+    /// [`GradientNoise`](struct.GradientNoise.html).
+    GradientNoise(GradientNoise),
+    /// Seeded [`Flicker`](struct.Flicker.html).
+    Flicker(Flicker),
+    /// Low frequency oscillator, [`Lfo`](struct.Lfo.html).
+    Lfo(Lfo),
+    /// [`EqualPower`](struct.EqualPower.html) fade.
+    EqualPower,
+    /// [`EqualGain`](struct.EqualGain.html) fade.
+    EqualGain,
+}
+
+impl Lerper for EasingFunction {
+    fn calculate(&self, t: f32) -> f32 {
+        match self {
+            EasingFunction::Linear => Linear.calculate(t),
+            EasingFunction::CubicBezier(lerper) => lerper.calculate(t),
+            EasingFunction::Steps(lerper) => lerper.calculate(t),
+            EasingFunction::Penner(lerper) => lerper.calculate(t),
+            EasingFunction::Spring(lerper) => lerper.calculate(t),
+            EasingFunction::Anticipate(lerper) => lerper.calculate(t),
+            EasingFunction::Overshoot(lerper) => lerper.calculate(t),
+            EasingFunction::AnticipateOvershoot(lerper) => lerper.calculate(t),
+            EasingFunction::Bias(lerper) => lerper.calculate(t),
+            EasingFunction::Gain(lerper) => lerper.calculate(t),
+            EasingFunction::Sigmoid(lerper) => lerper.calculate(t),
+            EasingFunction::Smoothstep(lerper) => lerper.calculate(t),
+            EasingFunction::Lut(lerper) => lerper.calculate(t),
+            EasingFunction::GradientNoise(lerper) => lerper.calculate(t),
+            EasingFunction::Flicker(lerper) => lerper.calculate(t),
+            EasingFunction::Lfo(lerper) => lerper.calculate(t),
+            EasingFunction::EqualPower => EqualPower.calculate(t),
+            EasingFunction::EqualGain => EqualGain.calculate(t),
+        }
+    }
+}
+
+/// Convert built-in easings into the matching easing function variant.
+macro_rules! easing_from {
+    ($($variant:ident($ty:ty)),* $(,)?) => {
+        $(
+            impl From<$ty> for EasingFunction {
+                fn from(lerper: $ty) -> EasingFunction {
+                    EasingFunction::$variant(lerper)
+                }
+            }
+        )*
+    };
+}
+
+easing_from!(
+    CubicBezier(Bezier),
+    Steps(Steps),
+    Penner(Penner),
+    Spring(Spring),
+    Anticipate(Anticipate),
+    Overshoot(Overshoot),
+    AnticipateOvershoot(AnticipateOvershoot),
+    Bias(Bias),
+    Gain(Gain),
+    Sigmoid(Sigmoid),
+    Smoothstep(Smoothstep),
+    Lut(Lut),
+    GradientNoise(GradientNoise),
+    Flicker(Flicker),
+    Lfo(Lfo),
+);
+
+impl From<Linear> for EasingFunction {
+    fn from(_: Linear) -> EasingFunction {
+        EasingFunction::Linear
+    }
+}
+
+impl From<EqualPower> for EasingFunction {
+    fn from(_: EqualPower) -> EasingFunction {
+        EasingFunction::EqualPower
+    }
+}
+
+impl From<EqualGain> for EasingFunction {
+    fn from(_: EqualGain) -> EasingFunction {
+        EasingFunction::EqualGain
+    }
+}
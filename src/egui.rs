@@ -0,0 +1,99 @@
+//! [egui](https://github.com/emilk/egui) integration, enabled with the `egui`
+//! feature.
+use egui::{Context, Id};
+
+use crate::{lerp, Lerper};
+
+#[derive(Debug, Clone, Copy)]
+/// Tween stored in egui's memory for each animated id.
+struct Animation {
+    from: f32,
+    to: f32,
+    start_time: f64,
+}
+
+/// Animate a value towards `target` over `duration` seconds, eased with the
+/// given lerper, returning this frame's value.
+///
+/// Like egui's own `animate_value_with_time`, state is keyed by `id`. The
+/// first call for an id returns `target` immediately, and whenever `target`
+/// changes a new tween starts from the current value. A repaint is requested
+/// while the value is still moving.
+///
+/// # Usage
+/// ```
+/// use soy::egui::animate_value_with_easing;
+///
+/// let ctx = egui::Context::default();
+/// let id = egui::Id::new("panel width");
+/// let at = |time| egui::RawInput { time: Some(time), ..Default::default() };
+///
+/// let _ = ctx.run(at(0.0), |ctx| {
+///     assert_eq!(animate_value_with_easing(ctx, id, 100.0, 1.0, soy::Linear), 100.0);
+/// });
+/// let _ = ctx.run(at(1.0), |ctx| {
+///     assert_eq!(animate_value_with_easing(ctx, id, 200.0, 1.0, soy::Linear), 100.0);
+/// });
+/// let _ = ctx.run(at(1.5), |ctx| {
+///     assert_eq!(animate_value_with_easing(ctx, id, 200.0, 1.0, soy::Linear), 150.0);
+/// });
+/// ```
+pub fn animate_value_with_easing<L: Lerper>(
+    ctx: &Context,
+    id: Id,
+    target: f32,
+    duration: f32,
+    lerper: L,
+) -> f32 {
+    let now = ctx.input(|input| input.time);
+    let id = id.with("soy::animate_value_with_easing");
+
+    let previous = ctx.data_mut(|data| data.get_temp::<Animation>(id));
+    let animation = match previous {
+        Some(animation) if animation.to == target => animation,
+        Some(animation) => Animation {
+            from: value_at(&animation, &lerper, duration, now),
+            to: target,
+            start_time: now,
+        },
+        None => Animation {
+            from: target,
+            to: target,
+            start_time: now,
+        },
+    };
+    ctx.data_mut(|data| data.insert_temp(id, animation));
+
+    let value = value_at(&animation, &lerper, duration, now);
+    if value != target {
+        ctx.request_repaint();
+    }
+
+    value
+}
+
+/// Animate between `0.0` and `1.0` as `value` toggles, over `duration`
+/// seconds, eased with the given lerper.
+pub fn animate_bool_with_easing<L: Lerper>(
+    ctx: &Context,
+    id: Id,
+    value: bool,
+    duration: f32,
+    lerper: L,
+) -> f32 {
+    let target = if value { 1.0 } else { 0.0 };
+    animate_value_with_easing(ctx, id, target, duration, lerper)
+}
+
+fn value_at<L: Lerper>(animation: &Animation, lerper: &L, duration: f32, now: f64) -> f32 {
+    if duration <= 0.0 {
+        return animation.to;
+    }
+
+    let t = ((now - animation.start_time) as f32 / duration).clamp(0.0, 1.0);
+    if t >= 1.0 {
+        animation.to
+    } else {
+        lerp(lerper, animation.from, animation.to, t)
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{lerp_unclamped, DynLerper, Lerper, Linear};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// EnvelopeStage of an [`Adsr`][0] envelope.
+///
+/// [0]: struct.Adsr.html
+pub enum EnvelopeStage {
+    /// The gate has never been opened, or the release has finished.
+    Idle,
+    /// Rising to full level after the gate opened.
+    Attack,
+    /// Falling from full level to the sustain level.
+    Decay,
+    /// Holding the sustain level while the gate is open.
+    Sustain,
+    /// Falling to zero after the gate closed.
+    Release,
+}
+
+/// Attack, decay, sustain, release envelope.
+///
+/// Opening the gate rises to full level over `attack` seconds, then falls to
+/// the `sustain` level over `decay` seconds, holding it until the gate closes.
+/// Closing the gate falls to zero over `release` seconds. Each stage is
+/// shaped by its own lerper, which is linear by default.
+///
+/// # Usage
+/// ```
+/// use soy::{Adsr, EnvelopeStage};
+///
+/// let mut button = Adsr::new(0.1, 0.2, 0.5, 0.4).with_release_curve(soy::EASE_OUT);
+///
+/// button.gate_on();
+/// assert_eq!(button.sample(0.05), 0.5);
+/// assert_eq!(button.sample(0.05), 1.0);
+/// assert!((button.sample(0.5) - 0.5).abs() < 1.0e-6);
+/// assert_eq!(button.stage(), EnvelopeStage::Sustain);
+///
+/// button.gate_off();
+/// button.sample(0.4);
+/// assert_eq!(button.stage(), EnvelopeStage::Idle);
+/// ```
+pub struct Adsr {
+    /// Duration of the attack stage, in seconds.
+    pub attack: f32,
+    /// Duration of the decay stage, in seconds.
+    pub decay: f32,
+    /// Level held while the gate is open, after the decay stage.
+    pub sustain: f32,
+    /// Duration of the release stage, in seconds.
+    pub release: f32,
+    attack_curve: DynLerper,
+    decay_curve: DynLerper,
+    release_curve: DynLerper,
+    stage: EnvelopeStage,
+    /// Time spent in the current stage.
+    elapsed: f32,
+    /// Level at the start of the current stage.
+    from: f32,
+    level: f32,
+}
+
+impl Adsr {
+    /// Create a new, idle, envelope with linear stages.
+    pub fn new(attack: f32, decay: f32, sustain: f32, release: f32) -> Adsr {
+        Adsr {
+            attack,
+            decay,
+            sustain,
+            release,
+            attack_curve: Linear.into_dyn(),
+            decay_curve: Linear.into_dyn(),
+            release_curve: Linear.into_dyn(),
+            stage: EnvelopeStage::Idle,
+            elapsed: 0.0,
+            from: 0.0,
+            level: 0.0,
+        }
+    }
+
+    /// Shape the attack stage with the given lerper.
+    pub fn with_attack_curve<L>(mut self, lerper: L) -> Adsr
+    where
+        L: Lerper + Send + Sync + 'static,
+    {
+        self.attack_curve = lerper.into_dyn();
+        self
+    }
+
+    /// Shape the decay stage with the given lerper.
+    pub fn with_decay_curve<L>(mut self, lerper: L) -> Adsr
+    where
+        L: Lerper + Send + Sync + 'static,
+    {
+        self.decay_curve = lerper.into_dyn();
+        self
+    }
+
+    /// Shape the release stage with the given lerper.
+    pub fn with_release_curve<L>(mut self, lerper: L) -> Adsr
+    where
+        L: Lerper + Send + Sync + 'static,
+    {
+        self.release_curve = lerper.into_dyn();
+        self
+    }
+
+    /// Open the gate, starting the attack stage from the current level.
+    pub fn gate_on(&mut self) {
+        self.enter(EnvelopeStage::Attack);
+    }
+
+    /// Close the gate, starting the release stage from the current level.
+    pub fn gate_off(&mut self) {
+        if self.stage != EnvelopeStage::Idle {
+            self.enter(EnvelopeStage::Release);
+        }
+    }
+
+    /// Whether the gate is open.
+    pub fn is_gated(&self) -> bool {
+        match self.stage {
+            EnvelopeStage::Attack | EnvelopeStage::Decay | EnvelopeStage::Sustain => true,
+            EnvelopeStage::Idle | EnvelopeStage::Release => false,
+        }
+    }
+
+    /// Current stage of the envelope.
+    pub fn stage(&self) -> EnvelopeStage {
+        self.stage
+    }
+
+    /// Current level of the envelope.
+    pub fn level(&self) -> f32 {
+        self.level
+    }
+
+    /// Advance the envelope by `dt` seconds, returning the new level.
+    pub fn sample(&mut self, dt: f32) -> f32 {
+        self.elapsed += dt;
+
+        loop {
+            let (duration, to, curve) = match self.stage {
+                EnvelopeStage::Idle => {
+                    self.level = 0.0;
+                    break;
+                }
+                EnvelopeStage::Sustain => {
+                    self.level = self.sustain;
+                    break;
+                }
+                EnvelopeStage::Attack => (self.attack, 1.0, &self.attack_curve),
+                EnvelopeStage::Decay => (self.decay, self.sustain, &self.decay_curve),
+                EnvelopeStage::Release => (self.release, 0.0, &self.release_curve),
+            };
+
+            if self.elapsed < duration {
+                self.level = lerp_unclamped(curve, self.from, to, self.elapsed / duration);
+                break;
+            }
+
+            // The stage has finished, so carry the remaining time into the
+            // next one.
+            let remaining = self.elapsed - duration.max(0.0);
+            self.level = to;
+            self.enter(match self.stage {
+                EnvelopeStage::Attack => EnvelopeStage::Decay,
+                EnvelopeStage::Decay => EnvelopeStage::Sustain,
+                _ => EnvelopeStage::Idle,
+            });
+            self.elapsed = remaining;
+        }
+
+        self.level
+    }
+
+    fn enter(&mut self, stage: EnvelopeStage) {
+        self.stage = stage;
+        self.elapsed = 0.0;
+        self.from = self.level;
+    }
+}
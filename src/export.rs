@@ -0,0 +1,163 @@
+use core::fmt::Write;
+use std::io;
+
+use crate::{sample, Lerper};
+
+/// Render a lerper as SVG path data, for the `d` attribute of a `<path>`.
+///
+/// The curve is sampled `samples` times, with _t_ spanning `width` and _y_
+/// spanning `height`. SVG's _y_ axis points down, so _y = 0_ maps to `height`
+/// and _y = 1_ maps to `0`.
+///
+/// # Usage
+/// ```
+/// let path = soy::to_svg_path(soy::Linear, 3, 100.0, 100.0);
+/// assert_eq!(path, "M0 100 L50 50 L100 0");
+/// ```
+pub fn to_svg_path<L: Lerper>(lerper: L, samples: usize, width: f32, height: f32) -> String {
+    let mut path = String::new();
+
+    for (i, (t, y)) in sample(lerper, samples).enumerate() {
+        let command = if i == 0 { "M" } else { " L" };
+        let _ = write!(path, "{}{} {}", command, t * width, (1.0 - y) * height);
+    }
+
+    path
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Options for [`to_svg`][0].
+///
+/// [0]: fn.to_svg.html
+pub struct SvgOptions {
+    /// Width spanned by _t_.
+    pub width: f32,
+    /// Height spanned by _y_ from `0.0` to `1.0`.
+    pub height: f32,
+    /// Number of samples of the curve.
+    pub samples: usize,
+    /// Whether to draw the _t_ and _y_ axes, and the unit square.
+    pub axes: bool,
+    /// Stroke colour of the curve.
+    pub stroke: String,
+}
+
+impl Default for SvgOptions {
+    fn default() -> SvgOptions {
+        SvgOptions {
+            width: 200.0,
+            height: 200.0,
+            samples: 100,
+            axes: true,
+            stroke: String::from("black"),
+        }
+    }
+}
+
+/// Render a lerper as a standalone SVG document.
+///
+/// The view box grows to include any part of the curve that overshoots
+/// _[0, 1]_.
+///
+/// # Usage
+/// ```
+/// let svg = soy::to_svg(soy::EASE_IN_OUT, &soy::SvgOptions::default());
+///
+/// assert!(svg.starts_with("<svg"));
+/// assert!(svg.contains("<path"));
+/// ```
+pub fn to_svg<L: Lerper>(lerper: L, options: &SvgOptions) -> String {
+    let (width, height) = (options.width, options.height);
+    let points: Vec<_> = sample(&lerper, options.samples).collect();
+
+    let low = points.iter().map(|p| p.1).fold(0.0_f32, f32::min);
+    let high = points.iter().map(|p| p.1).fold(1.0_f32, f32::max);
+    let top = (1.0 - high) * height;
+    let span = (high - low) * height;
+
+    let mut svg = String::new();
+    let _ = write!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 {} {} {}">"#,
+        top, width, span
+    );
+
+    if options.axes {
+        let _ = write!(
+            svg,
+            r#"<rect x="0" y="0" width="{w}" height="{h}" fill="none" stroke="lightgrey"/><line x1="0" y1="{h}" x2="{w}" y2="{h}" stroke="grey"/><line x1="0" y1="{top}" x2="0" y2="{bottom}" stroke="grey"/>"#,
+            w = width,
+            h = height,
+            top = top,
+            bottom = top + span,
+        );
+    }
+
+    let _ = write!(
+        svg,
+        r#"<path d="{}" fill="none" stroke="{}"/></svg>"#,
+        to_svg_path(&lerper, options.samples, width, height),
+        options.stroke
+    );
+
+    svg
+}
+
+/// Write `samples` samples of a lerper as CSV, with a `t,y` header row.
+///
+/// # Usage
+/// ```
+/// let mut csv = Vec::new();
+/// soy::write_csv(soy::Linear, 3, &mut csv).unwrap();
+///
+/// assert_eq!(String::from_utf8(csv).unwrap(), "t,y\n0,0\n0.5,0.5\n1,1\n");
+/// ```
+pub fn write_csv<L, W>(lerper: L, samples: usize, mut writer: W) -> io::Result<()>
+where
+    L: Lerper,
+    W: io::Write,
+{
+    writeln!(writer, "t,y")?;
+
+    for (t, y) in sample(lerper, samples) {
+        writeln!(writer, "{},{}", t, y)?;
+    }
+
+    Ok(())
+}
+
+/// Render a lerper as ASCII art, `width` characters wide and `height` lines
+/// tall, with one `*` per column.
+///
+/// Like [`to_svg`][0], the plot grows to include any part of the curve that
+/// overshoots _[0, 1]_.
+///
+/// # Panics
+/// Panics if `height` is zero.
+///
+/// # Usage
+/// ```
+/// let plot = soy::debug_plot(soy::Linear, 3, 3);
+/// assert_eq!(plot, "  *\n * \n*  ");
+/// ```
+///
+/// [0]: fn.to_svg.html
+pub fn debug_plot<L: Lerper>(lerper: L, width: usize, height: usize) -> String {
+    assert!(height > 0, "a plot requires at least one line");
+
+    let points: Vec<_> = sample(lerper, width).collect();
+    let low = points.iter().map(|p| p.1).fold(0.0_f32, f32::min);
+    let high = points.iter().map(|p| p.1).fold(1.0_f32, f32::max);
+
+    let mut grid = vec![vec![' '; width]; height];
+    for (column, &(_, y)) in points.iter().enumerate() {
+        let row = ((high - y) / (high - low) * (height - 1) as f32).round() as usize;
+        grid[row.min(height - 1)][column] = '*';
+    }
+
+    let lines: Vec<String> = grid
+        .into_iter()
+        .map(|row| row.into_iter().collect())
+        .collect();
+    lines.join("\n")
+}
@@ -0,0 +1,295 @@
+//! C ABI, enabled with the `ffi` feature.
+//!
+//! Build a shared library for C and C++ with:
+//! ```text
+//! cargo rustc --release --features ffi --crate-type cdylib
+//! ```
+//!
+//! Or pass `--crate-type staticlib` for a static library.
+//!
+//! Easings, tweens and splines are exposed as opaque handles. Every `*_new`
+//! and constructor function returns an owned pointer, which must be released
+//! with the matching `*_free` function.
+use core::ops::{Add, Mul, Sub};
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::ptr;
+use std::slice;
+
+use crate::registry::builtin;
+use crate::{Bezier, CatmullRom, DynLerper, Lerper, Linear, Spline};
+
+/// Opaque easing function.
+pub struct SoyEasing {
+    lerper: DynLerper,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Two dimensional point, passed by value.
+pub struct SoyVec2 {
+    /// _x_ coordinate.
+    pub x: f32,
+    /// _y_ coordinate.
+    pub y: f32,
+}
+
+impl Add for SoyVec2 {
+    type Output = SoyVec2;
+
+    fn add(self, other: SoyVec2) -> SoyVec2 {
+        SoyVec2 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl Sub for SoyVec2 {
+    type Output = SoyVec2;
+
+    fn sub(self, other: SoyVec2) -> SoyVec2 {
+        SoyVec2 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl Mul<f32> for SoyVec2 {
+    type Output = SoyVec2;
+
+    fn mul(self, scale: f32) -> SoyVec2 {
+        SoyVec2 {
+            x: self.x * scale,
+            y: self.y * scale,
+        }
+    }
+}
+
+/// Opaque tween, easing between two values over a duration.
+pub struct SoyTween {
+    lerper: DynLerper,
+    from: f32,
+    to: f32,
+    duration: f32,
+}
+
+/// Opaque Catmull-Rom spline through [`SoyVec2`][0] points.
+///
+/// [0]: struct.SoyVec2.html
+pub struct SoySpline {
+    spline: CatmullRom<SoyVec2>,
+}
+
+fn easing<L>(lerper: L) -> *mut SoyEasing
+where
+    L: Lerper + Send + Sync + 'static,
+{
+    Box::into_raw(Box::new(SoyEasing {
+        lerper: lerper.into_dyn(),
+    }))
+}
+
+/// Create a linear easing.
+#[no_mangle]
+pub extern "C" fn soy_easing_linear() -> *mut SoyEasing {
+    easing(Linear)
+}
+
+/// Create a cubic bezier easing, like CSS' `cubic-bezier(x1, y1, x2, y2)`.
+#[no_mangle]
+pub extern "C" fn soy_easing_cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> *mut SoyEasing {
+    easing(Bezier::new(x1, y1, x2, y2))
+}
+
+/// Create the easing for a CSS keyword, such as `"ease-in-out"`, returning
+/// null if the keyword is unknown.
+///
+/// # Safety
+/// `name` must be a valid, nul terminated, string.
+///
+/// # Usage
+/// ```
+/// use soy::ffi::*;
+///
+/// unsafe {
+///     let ease = soy_easing_keyword(b"ease-in-out\0".as_ptr() as *const _);
+///     assert!(!ease.is_null());
+///     assert_eq!(soy_easing_calculate(ease, 1.0), 1.0);
+///     soy_easing_free(ease);
+///
+///     assert!(soy_easing_keyword(b"wobble\0".as_ptr() as *const _).is_null());
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn soy_easing_keyword(name: *const c_char) -> *mut SoyEasing {
+    let name = match CStr::from_ptr(name).to_str() {
+        Ok(name) => name,
+        Err(_) => return ptr::null_mut(),
+    };
+
+    match builtin(name) {
+        Some(lerper) => easing(lerper),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Calculate the eased progression at `t`.
+///
+/// # Safety
+/// `easing` must be a live pointer returned by a `soy_easing_*` function.
+#[no_mangle]
+pub unsafe extern "C" fn soy_easing_calculate(easing: *const SoyEasing, t: f32) -> f32 {
+    (*easing).lerper.calculate(t)
+}
+
+/// Free an easing. Null pointers are ignored.
+///
+/// # Safety
+/// `easing` must be null, or a live pointer returned by a `soy_easing_*`
+/// function, which is not used again.
+#[no_mangle]
+pub unsafe extern "C" fn soy_easing_free(easing: *mut SoyEasing) {
+    if !easing.is_null() {
+        drop(Box::from_raw(easing));
+    }
+}
+
+/// Create a tween from `from` to `to` over `duration` seconds, eased by
+/// `easing`, returning null if `duration` is not positive.
+///
+/// The tween shares the easing, so `easing` may be freed after this call.
+///
+/// # Safety
+/// `easing` must be a live pointer returned by a `soy_easing_*` function.
+///
+/// # Usage
+/// ```
+/// use soy::ffi::*;
+///
+/// unsafe {
+///     let linear = soy_easing_linear();
+///     let tween = soy_tween_new(linear, 10.0, 20.0, 2.0);
+///     soy_easing_free(linear);
+///
+///     assert_eq!(soy_tween_value(tween, 1.0), 15.0);
+///     assert_eq!(soy_tween_value(tween, 3.0), 20.0);
+///     assert!(soy_tween_finished(tween, 2.0));
+///     soy_tween_free(tween);
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn soy_tween_new(
+    easing: *const SoyEasing,
+    from: f32,
+    to: f32,
+    duration: f32,
+) -> *mut SoyTween {
+    if duration <= 0.0 || duration.is_nan() {
+        return ptr::null_mut();
+    }
+
+    Box::into_raw(Box::new(SoyTween {
+        lerper: (*easing).lerper.clone(),
+        from,
+        to,
+        duration,
+    }))
+}
+
+/// Calculate the value of the tween `time` seconds after it started,
+/// holding the start and end values outside of its duration.
+///
+/// # Safety
+/// `tween` must be a live pointer returned by `soy_tween_new`.
+#[no_mangle]
+pub unsafe extern "C" fn soy_tween_value(tween: *const SoyTween, time: f32) -> f32 {
+    let tween = &*tween;
+    let t = (time / tween.duration).clamp(0.0, 1.0);
+
+    crate::lerp(&tween.lerper, tween.from, tween.to, t)
+}
+
+/// Whether the tween has finished `time` seconds after it started.
+///
+/// # Safety
+/// `tween` must be a live pointer returned by `soy_tween_new`.
+#[no_mangle]
+pub unsafe extern "C" fn soy_tween_finished(tween: *const SoyTween, time: f32) -> bool {
+    time >= (*tween).duration
+}
+
+/// Free a tween. Null pointers are ignored.
+///
+/// # Safety
+/// `tween` must be null, or a live pointer returned by `soy_tween_new`,
+/// which is not used again.
+#[no_mangle]
+pub unsafe extern "C" fn soy_tween_free(tween: *mut SoyTween) {
+    if !tween.is_null() {
+        drop(Box::from_raw(tween));
+    }
+}
+
+/// Create a Catmull-Rom spline through `count` points, returning null if
+/// `count` is zero.
+///
+/// The points are copied, so `points` may be freed after this call.
+///
+/// # Safety
+/// `points` must point to `count` consecutive points.
+///
+/// # Usage
+/// ```
+/// use soy::ffi::*;
+///
+/// let points = [SoyVec2 { x: 0.0, y: 0.0 }, SoyVec2 { x: 10.0, y: 4.0 }];
+///
+/// unsafe {
+///     let spline = soy_spline_new(points.as_ptr(), points.len());
+///     assert_eq!(soy_spline_sample(spline, 1.0), points[1]);
+///     soy_spline_free(spline);
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn soy_spline_new(points: *const SoyVec2, count: usize) -> *mut SoySpline {
+    if count == 0 {
+        return ptr::null_mut();
+    }
+
+    let points = slice::from_raw_parts(points, count).to_vec();
+    Box::into_raw(Box::new(SoySpline {
+        spline: CatmullRom::new(points),
+    }))
+}
+
+/// Calculate the point on the spline at `t`.
+///
+/// # Safety
+/// `spline` must be a live pointer returned by `soy_spline_new`.
+#[no_mangle]
+pub unsafe extern "C" fn soy_spline_sample(spline: *const SoySpline, t: f32) -> SoyVec2 {
+    (*spline).spline.sample(t)
+}
+
+/// Calculate the rate of change of the spline at `t`.
+///
+/// # Safety
+/// `spline` must be a live pointer returned by `soy_spline_new`.
+#[no_mangle]
+pub unsafe extern "C" fn soy_spline_derivative(spline: *const SoySpline, t: f32) -> SoyVec2 {
+    (*spline).spline.derivative(t)
+}
+
+/// Free a spline. Null pointers are ignored.
+///
+/// # Safety
+/// `spline` must be null, or a live pointer returned by `soy_spline_new`,
+/// which is not used again.
+#[no_mangle]
+pub unsafe extern "C" fn soy_spline_free(spline: *mut SoySpline) {
+    if !spline.is_null() {
+        drop(Box::from_raw(spline));
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{Bezier, Lerper};
+
+#[derive(Debug)]
+/// Result of fitting a curve to samples.
+pub struct BezierFit {
+    /// Best fitting curve.
+    pub bezier: Bezier,
+    /// Root mean square error of the curve against the samples.
+    pub error: f32,
+}
+
+/// Root mean square error of the bezier with the given control points
+/// against `samples`.
+fn rms_error(points: [f32; 4], samples: &[(f32, f32)]) -> f32 {
+    let [x1, y1, x2, y2] = points;
+    let bezier = Bezier::new(x1.clamp(0.0, 1.0), y1, x2.clamp(0.0, 1.0), y2);
+
+    let total: f32 = samples
+        .iter()
+        .map(|&(t, y)| (bezier.calculate(t) - y).powi(2))
+        .sum();
+    (total / samples.len() as f32).sqrt()
+}
+
+/// Minimize `f` over four parameters with the Nelder-Mead simplex method,
+/// returning the best parameters and their value.
+fn nelder_mead<F>(f: F, start: [f32; 4], step: f32, iterations: usize) -> ([f32; 4], f32)
+where
+    F: Fn([f32; 4]) -> f32,
+{
+    let mut simplex: Vec<([f32; 4], f32)> = (0..5)
+        .map(|i| {
+            let mut point = start;
+            if i > 0 {
+                point[i - 1] += step;
+            }
+            (point, f(point))
+        })
+        .collect();
+
+    // Move `from` towards, or past, `to` by `scale`.
+    let towards = |from: [f32; 4], to: [f32; 4], scale: f32| {
+        let mut point = from;
+        for i in 0..4 {
+            point[i] += (to[i] - from[i]) * scale;
+        }
+        point
+    };
+
+    for _ in 0..iterations {
+        simplex.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(core::cmp::Ordering::Equal));
+
+        let (best, worst) = (simplex[0].1, simplex[4]);
+        if simplex[4].1 - best < 1.0e-9 {
+            break;
+        }
+
+        let mut centroid = [0.0; 4];
+        for (point, _) in &simplex[..4] {
+            for i in 0..4 {
+                centroid[i] += point[i] / 4.0;
+            }
+        }
+
+        let reflected = towards(worst.0, centroid, 2.0);
+        let reflected_value = f(reflected);
+
+        if reflected_value < best {
+            let expanded = towards(worst.0, centroid, 3.0);
+            let expanded_value = f(expanded);
+
+            simplex[4] = if expanded_value < reflected_value {
+                (expanded, expanded_value)
+            } else {
+                (reflected, reflected_value)
+            };
+        } else if reflected_value < simplex[3].1 {
+            simplex[4] = (reflected, reflected_value);
+        } else {
+            let contracted = towards(worst.0, centroid, 0.5);
+            let contracted_value = f(contracted);
+
+            if contracted_value < worst.1 {
+                simplex[4] = (contracted, contracted_value);
+            } else {
+                // Shrink everything towards the best point.
+                let anchor = simplex[0].0;
+                for vertex in &mut simplex[1..] {
+                    let point = towards(anchor, vertex.0, 0.5);
+                    *vertex = (point, f(point));
+                }
+            }
+        }
+    }
+
+    simplex
+        .into_iter()
+        .fold(([0.0; 4], f32::INFINITY), |best, vertex| {
+            if vertex.1 < best.1 {
+                vertex
+            } else {
+                best
+            }
+        })
+}
+
+/// Fit a unit cubic bezier to _(t, y)_ samples, such as those recorded from
+/// a physical simulation or gesture, by least squares.
+///
+/// The fit starts from several common easings, refining each, and returns
+/// the best. The _x_ coordinates of the fitted control points are kept
+/// within `[0, 1]`, so the curve is always a valid easing.
+///
+/// # Panics
+/// Panics if `samples` is empty.
+///
+/// # Usage
+/// ```
+/// use soy::Lerper;
+///
+/// let samples: Vec<(f32, f32)> = soy::sample(soy::EASE_OUT, 50).collect();
+/// let fit = soy::fit_bezier(&samples);
+///
+/// assert!(fit.error < 1.0e-3);
+/// assert!((fit.bezier.calculate(0.3) - soy::EASE_OUT.calculate(0.3)).abs() < 1.0e-2);
+/// ```
+pub fn fit_bezier(samples: &[(f32, f32)]) -> BezierFit {
+    const STARTS: [[f32; 4]; 5] = [
+        [1.0 / 3.0, 1.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0],
+        [0.42, 0.0, 1.0, 1.0],
+        [0.0, 0.0, 0.58, 1.0],
+        [0.42, 0.0, 0.58, 1.0],
+        [0.25, 0.1, 0.25, 1.0],
+    ];
+    const ITERATIONS: usize = 400;
+
+    assert!(!samples.is_empty(), "fitting requires at least one sample");
+
+    let objective = |points: [f32; 4]| rms_error(points, samples);
+
+    let (points, error) = STARTS
+        .iter()
+        .map(|&start| nelder_mead(objective, start, 0.1, ITERATIONS))
+        .fold(([0.0; 4], f32::INFINITY), |best, fit| {
+            if fit.1 < best.1 {
+                fit
+            } else {
+                best
+            }
+        });
+
+    let [x1, y1, x2, y2] = points;
+    BezierFit {
+        bezier: Bezier::new(x1.clamp(0.0, 1.0), y1, x2.clamp(0.0, 1.0), y2),
+        error,
+    }
+}
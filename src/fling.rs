@@ -0,0 +1,146 @@
+#[derive(Debug)]
+/// Decay animation for flung objects, such as scroll views.
+///
+/// Starting with an initial velocity, the object decelerates exponentially
+/// until its velocity falls below a rest threshold. Optionally, the motion
+/// stops early when a boundary is hit.
+pub struct Fling {
+    origin: f32,
+    velocity: f32,
+    friction: f32,
+    rest_velocity: f32,
+    bounds: Option<(f32, f32)>,
+}
+
+impl Fling {
+    /// Default velocity, in units per second, below which the fling is
+    /// considered at rest.
+    pub const REST_VELOCITY: f32 = 1.0;
+
+    /// Create a new fling starting at `origin` moving with `velocity` units
+    /// per second.
+    ///
+    /// `friction` is the rate of decay per second; higher values stop the
+    /// fling sooner.
+    ///
+    /// # Panics
+    /// Panics if `friction` is not positive. Without friction, the fling
+    /// never comes to rest.
+    ///
+    /// # Usage
+    /// ```
+    /// let fling = soy::Fling::new(0.0, 1000.0, 4.0);
+    ///
+    /// assert!(fling.position(0.5) > 0.0);
+    /// assert!(fling.position(0.5) < fling.rest_position());
+    /// assert_eq!(fling.position(10.0), fling.rest_position());
+    /// ```
+    pub fn new(origin: f32, velocity: f32, friction: f32) -> Fling {
+        assert!(friction > 0.0, "a fling requires positive friction");
+
+        Fling {
+            origin,
+            velocity,
+            friction,
+            rest_velocity: Self::REST_VELOCITY,
+            bounds: None,
+        }
+    }
+
+    /// Set the velocity, in units per second, below which the fling is
+    /// considered at rest.
+    pub fn with_rest_velocity(mut self, rest_velocity: f32) -> Fling {
+        self.rest_velocity = rest_velocity.abs();
+        self
+    }
+
+    /// Stop the fling when it reaches either `min` or `max`.
+    ///
+    /// # Usage
+    /// ```
+    /// let fling = soy::Fling::new(0.0, 1000.0, 4.0).with_bounds(0.0, 100.0);
+    ///
+    /// assert_eq!(fling.rest_position(), 100.0);
+    /// assert_eq!(fling.velocity(fling.duration()), 0.0);
+    /// ```
+    pub fn with_bounds(mut self, min: f32, max: f32) -> Fling {
+        self.bounds = Some((min, max));
+        self
+    }
+
+    /// Time, in seconds, until the fling comes to rest.
+    pub fn duration(&self) -> f32 {
+        let speed = self.velocity.abs();
+        let rest = if speed > self.rest_velocity {
+            (speed / self.rest_velocity).ln() / self.friction
+        } else {
+            0.0
+        };
+
+        match self.boundary_time() {
+            Some(hit) => hit.min(rest),
+            None => rest,
+        }
+    }
+
+    /// Position after `t` seconds.
+    pub fn position(&self, t: f32) -> f32 {
+        if let Some(hit) = self.boundary_time() {
+            if t >= hit && hit <= self.duration() {
+                return self.boundary();
+            }
+        }
+
+        let t = t.max(0.0).min(self.duration());
+        self.unbounded_position(t)
+    }
+
+    /// Velocity after `t` seconds, which is zero once at rest.
+    pub fn velocity(&self, t: f32) -> f32 {
+        if t >= self.duration() {
+            return 0.0;
+        }
+
+        self.velocity * (-self.friction * t.max(0.0)).exp()
+    }
+
+    /// Position where the fling comes to rest.
+    pub fn rest_position(&self) -> f32 {
+        self.position(self.duration())
+    }
+
+    /// Whether the fling is at rest after `t` seconds.
+    pub fn is_finished(&self, t: f32) -> bool {
+        t >= self.duration()
+    }
+
+    fn unbounded_position(&self, t: f32) -> f32 {
+        let decay = 1.0 - (-self.friction * t).exp();
+        self.origin + self.velocity / self.friction * decay
+    }
+
+    fn boundary(&self) -> f32 {
+        match self.bounds {
+            Some((_, max)) if self.velocity > 0.0 => max,
+            Some((min, _)) => min,
+            None => self.origin,
+        }
+    }
+
+    fn boundary_time(&self) -> Option<f32> {
+        if self.bounds.is_none() || self.velocity == 0.0 {
+            return None;
+        }
+        let boundary = self.boundary();
+
+        // Solve `unbounded_position(t) == boundary` for `t`.
+        let decay = (boundary - self.origin) * self.friction / self.velocity;
+        if decay <= 0.0 {
+            Some(0.0)
+        } else if decay < 1.0 {
+            Some(-(1.0 - decay).ln() / self.friction)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use crate::Lerper;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// What happens to frame playback once _t_ goes past `1.0`.
+pub enum FrameMode {
+    /// Play once, then hold the last frame.
+    HoldLast,
+    /// Loop back to the first frame after each cycle.
+    Wrap,
+    /// Play forwards, then backwards, and so on.
+    PingPong,
+}
+
+/// Index of the frame to show at `t`, out of `count` frames, with playback
+/// shaped by `lerper`.
+///
+/// Each unit of _t_ plays through every frame once, so each frame is shown
+/// for an equal share of the eased output. Before _t = 0_, the first frame is
+/// held, except when wrapping.
+///
+/// # Panics
+/// Panics if `count` is zero.
+///
+/// # Usage
+/// ```
+/// use soy::FrameMode;
+///
+/// assert_eq!(soy::frame_index(soy::Linear, 0.5, 8, FrameMode::HoldLast), 4);
+/// assert_eq!(soy::frame_index(soy::Linear, 3.0, 8, FrameMode::HoldLast), 7);
+/// assert_eq!(soy::frame_index(soy::Linear, 1.25, 8, FrameMode::Wrap), 2);
+/// assert_eq!(soy::frame_index(soy::Linear, 1.25, 8, FrameMode::PingPong), 6);
+///
+/// // Easing in lingers on the first frames.
+/// assert_eq!(soy::frame_index(soy::EASE_IN, 0.25, 8, FrameMode::HoldLast), 0);
+/// ```
+pub fn frame_index<L: Lerper>(lerper: L, t: f32, count: usize, mode: FrameMode) -> usize {
+    assert!(count > 0, "frame playback requires at least one frame");
+
+    let y = match mode {
+        FrameMode::HoldLast => lerper.calculate(t.clamp(0.0, 1.0)),
+        FrameMode::Wrap => lerper.calculate(t.rem_euclid(1.0)),
+        FrameMode::PingPong => {
+            let phase = t.max(0.0) % 2.0;
+
+            if phase <= 1.0 {
+                lerper.calculate(phase)
+            } else {
+                lerper.calculate(2.0 - phase)
+            }
+        }
+    };
+
+    // Overshooting lerpers would otherwise run off either end of the sheet.
+    let frame = (y * count as f32).floor().max(0.0) as usize;
+    frame.min(count - 1)
+}
+
+#[derive(Debug, Clone)]
+/// Sprite sheet playback, with its timing shaped by a lerper.
+///
+/// # Usage
+/// ```
+/// use soy::{FrameAnimation, FrameMode};
+///
+/// let walk = FrameAnimation::new(8, 12.0, soy::Linear, FrameMode::Wrap);
+///
+/// assert_eq!(walk.duration(), 8.0 / 12.0);
+/// assert_eq!(walk.current_frame(0.0), 0);
+/// assert_eq!(walk.current_frame(0.25), 3);
+/// assert_eq!(walk.current_frame(walk.duration() + 0.25), 3);
+/// ```
+pub struct FrameAnimation<L> {
+    /// Number of frames in the animation.
+    pub count: usize,
+    /// Frames per second, on average, over a cycle.
+    pub fps: f32,
+    /// Lerper shaping the playback of each cycle.
+    pub lerper: L,
+    /// What happens after the first cycle.
+    pub mode: FrameMode,
+}
+
+impl<L: Lerper> FrameAnimation<L> {
+    /// Create a new frame animation.
+    ///
+    /// # Panics
+    /// Panics if `count` is zero, or `fps` isn't positive.
+    pub fn new(count: usize, fps: f32, lerper: L, mode: FrameMode) -> FrameAnimation<L> {
+        assert!(count > 0, "frame playback requires at least one frame");
+        assert!(fps > 0.0, "frame playback requires a positive frame rate");
+
+        FrameAnimation {
+            count,
+            fps,
+            lerper,
+            mode,
+        }
+    }
+
+    /// Duration of one cycle through the frames, in seconds.
+    pub fn duration(&self) -> f32 {
+        self.count as f32 / self.fps
+    }
+
+    /// Index of the frame to show `elapsed` seconds into the animation.
+    pub fn current_frame(&self, elapsed: f32) -> usize {
+        let t = elapsed / self.duration();
+        frame_index(&self.lerper, t, self.count, self.mode)
+    }
+
+    /// Whether a [`HoldLast`][0] animation has played through, holding its
+    /// last frame. Looping animations never finish.
+    ///
+    /// [0]: enum.FrameMode.html#variant.HoldLast
+    pub fn is_finished(&self, elapsed: f32) -> bool {
+        self.mode == FrameMode::HoldLast && elapsed >= self.duration()
+    }
+}
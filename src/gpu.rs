@@ -0,0 +1,110 @@
+use crate::{sample, Bezier, Hermite, Lerper};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// GLSL buffer layout to pack arrays of scalars for.
+pub enum GpuLayout {
+    /// `std140`, where every array element is padded to 16 bytes.
+    Std140,
+    /// `std430`, where array elements are tightly packed.
+    Std430,
+}
+
+impl GpuLayout {
+    /// Distance, in bytes, between consecutive `float`s of an array.
+    pub fn float_stride(self) -> usize {
+        match self {
+            GpuLayout::Std140 => 16,
+            GpuLayout::Std430 => 4,
+        }
+    }
+}
+
+/// Curves which can be packed into bytes, for evaluation in shaders.
+///
+/// Values are little endian `f32`s, aligned and padded so the same bytes are
+/// valid in both `std140` and `std430` buffers.
+pub trait ToGpu {
+    /// Pack the curve into bytes, ready to upload to a buffer.
+    fn to_gpu(&self) -> Vec<u8>;
+}
+
+impl ToGpu for Bezier {
+    /// Pack the curve as a single `vec4(x1, y1, x2, y2)`, 16 bytes long.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::ToGpu;
+    ///
+    /// let bytes = soy::cubic_bezier(0.25, 0.1, 0.25, 1.0).to_gpu();
+    ///
+    /// assert_eq!(bytes.len(), 16);
+    /// assert_eq!(bytes[12..16], 1.0_f32.to_le_bytes());
+    /// ```
+    fn to_gpu(&self) -> Vec<u8> {
+        let (x1, y1, x2, y2) = self.points();
+        pack(&[x1, y1, x2, y2])
+    }
+}
+
+impl ToGpu for Hermite<f32> {
+    /// Pack the keys as an array of `vec4(time, value, tangent, 0.0)`, 16
+    /// bytes per key.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{Hermite, HermiteKey, ToGpu};
+    ///
+    /// let spline = Hermite::new(vec![
+    ///     HermiteKey { time: 0.0, value: 0.0, tangent: 0.0 },
+    ///     HermiteKey { time: 2.0, value: 10.0, tangent: 0.0 },
+    /// ]);
+    /// let bytes = spline.to_gpu();
+    ///
+    /// assert_eq!(bytes.len(), 32);
+    /// assert_eq!(bytes[20..24], 10.0_f32.to_le_bytes());
+    /// ```
+    fn to_gpu(&self) -> Vec<u8> {
+        let values: Vec<f32> = self
+            .keys()
+            .iter()
+            .flat_map(|key| [key.time, key.value, key.tangent, 0.0])
+            .collect();
+
+        pack(&values)
+    }
+}
+
+/// Bake `count` evenly spaced samples of a lerper into a `float[]` lookup
+/// table with the given layout.
+///
+/// Sample _i_ is at _t = i / (count - 1)_, so a shader can linearly
+/// interpolate between neighbouring samples.
+///
+/// # Usage
+/// ```
+/// use soy::GpuLayout;
+///
+/// let std430 = soy::lut_to_gpu(soy::EASE, 64, GpuLayout::Std430);
+/// let std140 = soy::lut_to_gpu(soy::EASE, 64, GpuLayout::Std140);
+///
+/// assert_eq!(std430.len(), 64 * 4);
+/// assert_eq!(std140.len(), 64 * 16);
+/// assert_eq!(std430[4..8], std140[16..20]);
+/// ```
+pub fn lut_to_gpu<L: Lerper>(lerper: L, count: usize, layout: GpuLayout) -> Vec<u8> {
+    let stride = layout.float_stride();
+    let mut bytes = vec![0; count * stride];
+
+    for (i, (_, y)) in sample(lerper, count).enumerate() {
+        bytes[i * stride..i * stride + 4].copy_from_slice(&y.to_le_bytes());
+    }
+
+    bytes
+}
+
+fn pack(values: &[f32]) -> Vec<u8> {
+    values
+        .iter()
+        .flat_map(|value| value.to_le_bytes())
+        .collect()
+}
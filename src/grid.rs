@@ -0,0 +1,141 @@
+use core::ops::{Add, Mul, Sub};
+
+use crate::spline::catmull_rom::uniform_segment;
+use crate::{lerp_unclamped, Linear};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Two-dimensional grid of samples, such as a heightmap, which can be
+/// interpolated at fractional coordinates.
+pub struct Grid2<T> {
+    width: usize,
+    height: usize,
+    data: Vec<T>,
+}
+
+impl<T> Grid2<T>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    /// Create a new grid from row-major `data`.
+    ///
+    /// # Panics
+    /// Panics if the grid is empty, or `data` doesn't contain exactly
+    /// `width * height` samples.
+    ///
+    /// # Usage
+    /// ```
+    /// let grid = soy::Grid2::new(2, 2, vec![0.0, 1.0, 2.0, 3.0]);
+    ///
+    /// assert_eq!(grid.get(1, 0), 1.0);
+    /// assert_eq!(grid.bilinear(0.5, 0.5), 1.5);
+    /// ```
+    pub fn new(width: usize, height: usize, data: Vec<T>) -> Grid2<T> {
+        assert!(
+            width > 0 && height > 0,
+            "a grid requires at least one sample"
+        );
+        assert_eq!(
+            data.len(),
+            width * height,
+            "a grid requires `width * height` samples"
+        );
+
+        Grid2 {
+            width,
+            height,
+            data,
+        }
+    }
+
+    /// Create a new grid, calling `f` with the coordinates of each sample.
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Grid2<T>
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let data = (0..width * height)
+            .map(|i| f(i % width, i / width))
+            .collect();
+
+        Grid2::new(width, height, data)
+    }
+
+    /// Number of samples along the _x_ axis.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of samples along the _y_ axis.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Row-major samples of the grid.
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Sample at the given coordinates, clamped to the edges of the grid.
+    pub fn get(&self, x: usize, y: usize) -> T {
+        let x = x.min(self.width - 1);
+        let y = y.min(self.height - 1);
+
+        self.data[y * self.width + x]
+    }
+
+    /// Sample at signed coordinates, clamped to the edges of the grid.
+    fn get_clamped(&self, x: isize, y: isize) -> T {
+        self.get(x.max(0) as usize, y.max(0) as usize)
+    }
+
+    /// Bilinearly interpolate the grid at fractional coordinates, clamped to
+    /// the edges of the grid.
+    pub fn bilinear(&self, x: f32, y: f32) -> T {
+        let (x0, tx) = split(x, self.width);
+        let (y0, ty) = split(y, self.height);
+
+        let top = lerp_unclamped(Linear, self.get(x0, y0), self.get(x0 + 1, y0), tx);
+        let bottom = lerp_unclamped(Linear, self.get(x0, y0 + 1), self.get(x0 + 1, y0 + 1), tx);
+
+        lerp_unclamped(Linear, top, bottom, ty)
+    }
+
+    /// Bicubically interpolate the grid at fractional coordinates, clamped to
+    /// the edges of the grid, using Catmull-Rom splines along each axis.
+    ///
+    /// # Usage
+    /// ```
+    /// let terrain = soy::Grid2::from_fn(4, 4, |x, y| (x * y) as f32);
+    ///
+    /// assert_eq!(terrain.bicubic(2.0, 1.0), 2.0);
+    /// assert!((terrain.bicubic(1.5, 1.5) - 2.25).abs() < 1.0e-5);
+    /// ```
+    pub fn bicubic(&self, x: f32, y: f32) -> T {
+        let (x0, tx) = split(x, self.width);
+        let (y0, ty) = split(y, self.height);
+        let (x0, y0) = (x0 as isize, y0 as isize);
+
+        let row = |y: isize| {
+            uniform_segment(
+                self.get_clamped(x0 - 1, y),
+                self.get_clamped(x0, y),
+                self.get_clamped(x0 + 1, y),
+                self.get_clamped(x0 + 2, y),
+                tx,
+            )
+        };
+
+        uniform_segment(row(y0 - 1), row(y0), row(y0 + 1), row(y0 + 2), ty)
+    }
+}
+
+/// Split a fractional coordinate into its integer cell and the fraction
+/// within the cell, clamped to `len` samples.
+fn split(x: f32, len: usize) -> (usize, f32) {
+    let x = x.max(0.0).min((len - 1) as f32);
+    let cell = (x as usize).min(len.saturating_sub(2));
+
+    (cell, x - cell as f32)
+}
@@ -0,0 +1,96 @@
+//! Half precision interpolation over [half](https://docs.rs/half)'s `f16`
+//! and `bf16`, enabled with the `half` feature.
+//!
+//! Values are interpolated in single precision, then rounded back, so
+//! animation data can stay in half precision on its way to the GPU.
+use half::{bf16, f16};
+
+use crate::{lerp, Lerper};
+
+/// Interpolate between two `f16` values, like [`lerp`][0].
+///
+/// # Usage
+/// ```
+/// use half::f16;
+///
+/// let start = f16::from_f32(0.0);
+/// let end = f16::from_f32(2.0);
+///
+/// assert_eq!(soy::half::lerp_f16(soy::Linear, start, end, 0.25), f16::from_f32(0.5));
+/// ```
+///
+/// [0]: ../fn.lerp.html
+pub fn lerp_f16<L: Lerper>(lerper: L, start: f16, end: f16, t: f32) -> f16 {
+    f16::from_f32(lerp(lerper, start.to_f32(), end.to_f32(), t))
+}
+
+/// Interpolate between two `bf16` values, like [`lerp`][0].
+///
+/// # Usage
+/// ```
+/// use half::bf16;
+///
+/// let start = bf16::from_f32(0.0);
+/// let end = bf16::from_f32(2.0);
+///
+/// assert_eq!(soy::half::lerp_bf16(soy::Linear, start, end, 0.25), bf16::from_f32(0.5));
+/// ```
+///
+/// [0]: ../fn.lerp.html
+pub fn lerp_bf16<L: Lerper>(lerper: L, start: bf16, end: bf16, t: f32) -> bf16 {
+    bf16::from_f32(lerp(lerper, start.to_f32(), end.to_f32(), t))
+}
+
+/// Interpolate element-wise between two equal length `f16` buffers into
+/// `buffer`, calculating the lerper only once.
+///
+/// # Panics
+/// Panics if `from`, `to` and `buffer` don't all have the same length.
+///
+/// # Usage
+/// ```
+/// use half::f16;
+///
+/// let from = [f16::from_f32(0.0), f16::from_f32(1.0)];
+/// let to = [f16::from_f32(1.0), f16::from_f32(3.0)];
+/// let mut buffer = [f16::ZERO; 2];
+///
+/// soy::half::lerp_f16_into(soy::Linear, &from, &to, 0.5, &mut buffer);
+/// assert_eq!(buffer, [f16::from_f32(0.5), f16::from_f32(2.0)]);
+/// ```
+pub fn lerp_f16_into<L: Lerper>(lerper: L, from: &[f16], to: &[f16], t: f32, buffer: &mut [f16]) {
+    assert!(
+        from.len() == to.len() && to.len() == buffer.len(),
+        "element-wise interpolation requires equal length slices"
+    );
+
+    let k = lerper.calculate(t.clamp(0.0, 1.0));
+    for ((slot, a), b) in buffer.iter_mut().zip(from).zip(to) {
+        let (a, b) = (a.to_f32(), b.to_f32());
+        *slot = f16::from_f32(a + (b - a) * k);
+    }
+}
+
+/// Interpolate element-wise between two equal length `bf16` buffers into
+/// `buffer`, calculating the lerper only once.
+///
+/// # Panics
+/// Panics if `from`, `to` and `buffer` don't all have the same length.
+pub fn lerp_bf16_into<L: Lerper>(
+    lerper: L,
+    from: &[bf16],
+    to: &[bf16],
+    t: f32,
+    buffer: &mut [bf16],
+) {
+    assert!(
+        from.len() == to.len() && to.len() == buffer.len(),
+        "element-wise interpolation requires equal length slices"
+    );
+
+    let k = lerper.calculate(t.clamp(0.0, 1.0));
+    for ((slot, a), b) in buffer.iter_mut().zip(from).zip(to) {
+        let (a, b) = (a.to_f32(), b.to_f32());
+        *slot = bf16::from_f32(a + (b - a) * k);
+    }
+}
@@ -0,0 +1,9 @@
+use core::hash::{Hash, Hasher};
+
+/// Hash `value` by its bit pattern, so lerpers with floating point
+/// parameters can be hashed.
+///
+/// `-0.0` is hashed as `0.0`, since the two compare equal.
+pub(crate) fn hash_f32<H: Hasher>(value: f32, state: &mut H) {
+    (value + 0.0).to_bits().hash(state);
+}
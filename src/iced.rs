@@ -0,0 +1,105 @@
+//! [iced](https://iced.rs) integration, enabled with the `iced` feature.
+use core::ops::{Add, Mul, Sub};
+use std::sync::Arc;
+
+use iced::time::{Duration, Instant};
+use iced::{window, Subscription};
+
+use crate::{lerp, DynLerper, Lerper};
+
+/// Eased transition between two values, driven by the timestamps of iced's
+/// frame events.
+///
+/// Store an `Animation` in your application state, subscribe to
+/// [`subscription`][0] and read [`value`][1] in `view`.
+///
+/// [0]: #method.subscription
+/// [1]: #method.value
+pub struct Animation<T> {
+    from: T,
+    to: T,
+    duration: Duration,
+    started: Option<Instant>,
+    lerper: DynLerper,
+}
+
+impl<T> Animation<T>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    /// Create a new animation resting at `value`, which eases to new values
+    /// over `duration` with the given lerper.
+    ///
+    /// # Usage
+    /// ```
+    /// use iced::time::{Duration, Instant};
+    /// use soy::iced::Animation;
+    ///
+    /// let mut width = Animation::new(100.0, Duration::from_secs(1), soy::Linear);
+    /// let now = Instant::now();
+    ///
+    /// width.go_to(200.0, now);
+    /// assert_eq!(width.value(now), 100.0);
+    /// assert_eq!(width.value(now + Duration::from_millis(500)), 150.0);
+    /// assert!(!width.is_animating(now + Duration::from_secs(1)));
+    /// ```
+    pub fn new<L>(value: T, duration: Duration, lerper: L) -> Animation<T>
+    where
+        L: Lerper + Send + Sync + 'static,
+    {
+        Animation {
+            from: value,
+            to: value,
+            duration,
+            started: None,
+            lerper: Arc::new(lerper),
+        }
+    }
+
+    /// Start easing from the current value to `to`, at `now`.
+    pub fn go_to(&mut self, to: T, now: Instant) {
+        self.from = self.value(now);
+        self.to = to;
+        self.started = Some(now);
+    }
+
+    /// Value the animation is easing towards.
+    pub fn target(&self) -> T {
+        self.to
+    }
+
+    /// Value of the animation at `now`.
+    pub fn value(&self, now: Instant) -> T {
+        lerp(&self.lerper, self.from, self.to, self.progress(now))
+    }
+
+    /// Progress through the current transition at `now`, from `0.0` to `1.0`.
+    pub fn progress(&self, now: Instant) -> f32 {
+        match self.started {
+            Some(_) if self.duration.is_zero() => 1.0,
+            Some(started) => {
+                let elapsed = now.saturating_duration_since(started);
+                (elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+            }
+            None => 1.0,
+        }
+    }
+
+    /// Whether the animation is still moving at `now`.
+    pub fn is_animating(&self, now: Instant) -> bool {
+        self.progress(now) < 1.0
+    }
+
+    /// Subscription emitting the timestamp of every frame while the
+    /// animation is moving, and nothing once it has finished.
+    pub fn subscription(&self, now: Instant) -> Subscription<Instant> {
+        if self.is_animating(now) {
+            window::frames()
+        } else {
+            Subscription::none()
+        }
+    }
+}
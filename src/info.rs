@@ -0,0 +1,485 @@
+use crate::{
+    Anticipate, AnticipateOvershoot, Bezier, Bias, Clamped, EqualGain, EqualPower, Flicker, Gain,
+    GradientNoise, Jittered, Lfo, Linear, Lut, Normalized, Overshoot, Penner, Repeat, Reverse,
+    Sigmoid, Smoothstep, Spring, StepPosition, Steps, Then,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Named, tweakable, parameter of a curve.
+pub struct Parameter {
+    /// Name of the parameter.
+    pub name: &'static str,
+    /// Current value of the parameter.
+    pub value: f32,
+}
+
+impl Parameter {
+    /// Create a new parameter.
+    pub fn new(name: &'static str, value: f32) -> Parameter {
+        Parameter { name, value }
+    }
+}
+
+/// Introspection of a curve, for displaying and tweaking curves generically in
+/// editor tooling.
+///
+/// # Usage
+/// ```
+/// use soy::CurveInfo;
+///
+/// let mut ease = soy::cubic_bezier(0.42, 0.0, 0.58, 1.0);
+/// assert_eq!(ease.name(), "cubic-bezier");
+/// assert_eq!(ease.parameters()[0].name, "x1");
+///
+/// assert!(ease.set_parameter("y1", 0.5));
+/// assert!((ease.control_points()[1].1 - 0.5).abs() < 1.0e-6);
+/// ```
+pub trait CurveInfo {
+    /// Name of the curve.
+    fn name(&self) -> &'static str;
+
+    /// Parameters of the curve.
+    fn parameters(&self) -> Vec<Parameter> {
+        Vec::new()
+    }
+
+    /// Set the parameter called `name`, returning whether the curve has such
+    /// a parameter.
+    fn set_parameter(&mut self, name: &str, value: f32) -> bool {
+        let _ = (name, value);
+        false
+    }
+
+    /// Control points of the curve, in _(t, y)_ space, for curves that have
+    /// them.
+    fn control_points(&self) -> Vec<(f32, f32)> {
+        Vec::new()
+    }
+}
+
+impl CurveInfo for Linear {
+    fn name(&self) -> &'static str {
+        "linear"
+    }
+
+    fn control_points(&self) -> Vec<(f32, f32)> {
+        vec![(0.0, 0.0), (1.0, 1.0)]
+    }
+}
+
+impl CurveInfo for Lut {
+    fn name(&self) -> &'static str {
+        "lut"
+    }
+
+    fn control_points(&self) -> Vec<(f32, f32)> {
+        crate::sample(self, self.values().len()).collect()
+    }
+}
+
+impl CurveInfo for Penner {
+    fn name(&self) -> &'static str {
+        Penner::name(self)
+    }
+}
+
+impl CurveInfo for Anticipate {
+    fn name(&self) -> &'static str {
+        "anticipate"
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter::new("tension", self.tension)]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f32) -> bool {
+        match name {
+            "tension" => self.tension = value,
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl CurveInfo for Overshoot {
+    fn name(&self) -> &'static str {
+        "overshoot"
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter::new("tension", self.tension)]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f32) -> bool {
+        match name {
+            "tension" => self.tension = value,
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl CurveInfo for AnticipateOvershoot {
+    fn name(&self) -> &'static str {
+        "anticipate-overshoot"
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter::new("tension", self.tension)]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f32) -> bool {
+        match name {
+            "tension" => self.tension = value,
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl CurveInfo for Bias {
+    fn name(&self) -> &'static str {
+        "bias"
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter::new("bias", self.0)]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f32) -> bool {
+        match name {
+            "bias" => self.0 = value.clamp(f32::EPSILON, 1.0 - f32::EPSILON),
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl CurveInfo for Gain {
+    fn name(&self) -> &'static str {
+        "gain"
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter::new("gain", self.0)]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f32) -> bool {
+        match name {
+            "gain" => self.0 = value.clamp(f32::EPSILON, 1.0 - f32::EPSILON),
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl CurveInfo for Sigmoid {
+    fn name(&self) -> &'static str {
+        "sigmoid"
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter::new("steepness", self.steepness)]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f32) -> bool {
+        match name {
+            "steepness" => self.steepness = value,
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl CurveInfo for Smoothstep {
+    fn name(&self) -> &'static str {
+        "smoothstep"
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter::new("order", self.order() as f32)]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f32) -> bool {
+        match name {
+            "order" => {
+                let order = value.clamp(0.0, Smoothstep::MAX_ORDER as f32);
+                *self = Smoothstep::new(order as u32);
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl CurveInfo for Steps {
+    fn name(&self) -> &'static str {
+        "steps"
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter::new("count", self.count() as f32)]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f32) -> bool {
+        match name {
+            "count" => {
+                let position = self.position();
+                let min = if position == StepPosition::JumpNone {
+                    2.0
+                } else {
+                    1.0
+                };
+                *self = Steps::new(value.max(min) as u32, position);
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl CurveInfo for Spring {
+    fn name(&self) -> &'static str {
+        "spring"
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::new("mass", self.mass),
+            Parameter::new("stiffness", self.stiffness),
+            Parameter::new("damping", self.damping),
+            Parameter::new("velocity", self.velocity),
+            Parameter::new("duration", self.duration),
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f32) -> bool {
+        match name {
+            "mass" => self.mass = value.max(f32::EPSILON),
+            "stiffness" => self.stiffness = value.max(f32::EPSILON),
+            "damping" => self.damping = value.max(f32::EPSILON),
+            "velocity" => self.velocity = value,
+            "duration" => self.duration = value,
+            _ => return false,
+        }
+        // Changing the spring's physics changes when it settles.
+        if name != "duration" {
+            self.duration = self.settling_duration();
+        }
+        true
+    }
+}
+
+impl CurveInfo for EqualPower {
+    fn name(&self) -> &'static str {
+        "equal-power"
+    }
+}
+
+impl CurveInfo for EqualGain {
+    fn name(&self) -> &'static str {
+        "equal-gain"
+    }
+
+    fn control_points(&self) -> Vec<(f32, f32)> {
+        vec![(0.0, 0.0), (1.0, 1.0)]
+    }
+}
+
+impl CurveInfo for Bezier {
+    fn name(&self) -> &'static str {
+        "cubic-bezier"
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        let (x1, y1, x2, y2) = self.points();
+
+        vec![
+            Parameter::new("x1", x1),
+            Parameter::new("y1", y1),
+            Parameter::new("x2", x2),
+            Parameter::new("y2", y2),
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f32) -> bool {
+        let (mut x1, mut y1, mut x2, mut y2) = self.points();
+
+        match name {
+            "x1" => x1 = value,
+            "y1" => y1 = value,
+            "x2" => x2 = value,
+            "y2" => y2 = value,
+            _ => return false,
+        }
+
+        *self = Bezier {
+            fast: self.fast,
+            ..Bezier::new(x1, y1, x2, y2)
+        };
+        true
+    }
+
+    fn control_points(&self) -> Vec<(f32, f32)> {
+        let (x1, y1, x2, y2) = self.points();
+
+        vec![(0.0, 0.0), (x1, y1), (x2, y2), (1.0, 1.0)]
+    }
+}
+
+impl<L> CurveInfo for Jittered<L> {
+    fn name(&self) -> &'static str {
+        "jittered"
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::new("amplitude", self.amplitude),
+            Parameter::new("frequency", self.frequency),
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f32) -> bool {
+        match name {
+            "amplitude" => self.amplitude = value,
+            "frequency" => self.frequency = value,
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl CurveInfo for GradientNoise {
+    fn name(&self) -> &'static str {
+        "gradient-noise"
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::new("frequency", self.frequency),
+            Parameter::new("octaves", self.octaves as f32),
+            Parameter::new("persistence", self.persistence),
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f32) -> bool {
+        match name {
+            "frequency" => self.frequency = value,
+            "octaves" => self.octaves = value.max(1.0) as u32,
+            "persistence" => self.persistence = value,
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl CurveInfo for Flicker {
+    fn name(&self) -> &'static str {
+        "flicker"
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::new("frequency", self.frequency),
+            Parameter::new("duty", self.duty),
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f32) -> bool {
+        match name {
+            "frequency" => self.frequency = value,
+            "duty" => self.duty = value,
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl CurveInfo for Lfo {
+    fn name(&self) -> &'static str {
+        "lfo"
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::new("frequency", self.frequency),
+            Parameter::new("phase", self.phase),
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f32) -> bool {
+        match name {
+            "frequency" => self.frequency = value,
+            "phase" => self.phase = value,
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl<A, B> CurveInfo for Then<A, B> {
+    fn name(&self) -> &'static str {
+        "then"
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::new("split", self.split),
+            Parameter::new("value", self.value),
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f32) -> bool {
+        match name {
+            "split" => {
+                let split = value.clamp(0.0, 1.0);
+
+                // Knots created by `Then::new` sit on the diagonal, so keep
+                // them there.
+                if self.value == self.split {
+                    self.value = split;
+                }
+                self.split = split;
+            }
+            "value" => self.value = value,
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl<L> CurveInfo for Repeat<L> {
+    fn name(&self) -> &'static str {
+        "repeat"
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter::new("count", self.count as f32)]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f32) -> bool {
+        match name {
+            "count" => self.count = value.max(1.0) as u32,
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl<L> CurveInfo for Reverse<L> {
+    fn name(&self) -> &'static str {
+        "reverse"
+    }
+}
+
+impl<L> CurveInfo for Normalized<L> {
+    fn name(&self) -> &'static str {
+        "normalized"
+    }
+}
+
+impl<L> CurveInfo for Clamped<L> {
+    fn name(&self) -> &'static str {
+        "clamped"
+    }
+}
@@ -0,0 +1,112 @@
+use crate::Lerper;
+
+/// Remove a deadzone of `radius` around zero from an input in `[-1, 1]`,
+/// rescaling the rest of the range so the output still reaches `±1`.
+///
+/// # Usage
+/// ```
+/// assert_eq!(soy::deadzone(0.25, 0.5), 0.0);
+/// assert_eq!(soy::deadzone(-0.75, 0.5), -0.5);
+/// assert_eq!(soy::deadzone(1.0, 0.5), 1.0);
+/// ```
+pub fn deadzone(value: f32, radius: f32) -> f32 {
+    let magnitude = value.abs();
+
+    if magnitude <= radius || radius >= 1.0 {
+        0.0
+    } else {
+        value.signum() * ((magnitude - radius) / (1.0 - radius)).min(1.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Hysteresis band, which only follows an input once it has moved more than
+/// the band away, so small jitter in a noisy input is ignored.
+///
+/// # Usage
+/// ```
+/// let mut steady = soy::Hysteresis::new(0.25);
+///
+/// assert_eq!(steady.update(0.75), 0.5);
+/// // Jitter within the band doesn't move the output.
+/// assert_eq!(steady.update(0.625), 0.5);
+/// assert_eq!(steady.update(0.375), 0.5);
+/// // Moving further drags the output along.
+/// assert_eq!(steady.update(0.125), 0.375);
+/// ```
+pub struct Hysteresis {
+    /// Distance the input can move before the output follows.
+    pub band: f32,
+    value: f32,
+}
+
+impl Hysteresis {
+    /// Create a new hysteresis band, with the output starting at zero.
+    pub fn new(band: f32) -> Hysteresis {
+        Hysteresis { band, value: 0.0 }
+    }
+
+    /// Follow `input`, returning the new output.
+    pub fn update(&mut self, input: f32) -> f32 {
+        if input > self.value + self.band {
+            self.value = input - self.band;
+        } else if input < self.value - self.band {
+            self.value = input + self.band;
+        }
+        self.value
+    }
+
+    /// Current output.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Response curve for a noisy input in `[-1, 1]`, such as a thumbstick
+/// axis or sensor.
+///
+/// Each input passes through a [`Hysteresis`][0] band, then has a
+/// [`deadzone`][1] removed, and the magnitude of what's left is shaped by a
+/// lerper. Full deflection still gives an output of `±1`.
+///
+/// # Usage
+/// ```
+/// let mut stick = soy::InputCurve::new(soy::EASE_IN, 0.1, 0.01);
+///
+/// assert_eq!(stick.update(0.05), 0.0);
+/// assert!(stick.update(-0.5) < 0.0);
+/// assert_eq!(stick.update(-1.0), -1.0);
+/// ```
+///
+/// [0]: struct.Hysteresis.html
+/// [1]: fn.deadzone.html
+pub struct InputCurve<L> {
+    lerper: L,
+    /// Radius of the deadzone around zero.
+    pub deadzone: f32,
+    hysteresis: Hysteresis,
+}
+
+impl<L: Lerper> InputCurve<L> {
+    /// Create a new response curve, shaped by `lerper`, with the given
+    /// deadzone radius and hysteresis band.
+    pub fn new(lerper: L, deadzone: f32, band: f32) -> InputCurve<L> {
+        InputCurve {
+            lerper,
+            deadzone,
+            hysteresis: Hysteresis::new(band),
+        }
+    }
+
+    /// Filter and shape `input`, returning the new output.
+    pub fn update(&mut self, input: f32) -> f32 {
+        // The band keeps the output short of the input, so rescale it to
+        // still reach the ends of the range.
+        let band = self.hysteresis.band;
+        let steady = (self.hysteresis.update(input) / (1.0 - band)).clamp(-1.0, 1.0);
+        let value = deadzone(steady, self.deadzone);
+
+        value.signum() * self.lerper.calculate(value.abs())
+    }
+}
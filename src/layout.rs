@@ -0,0 +1,223 @@
+use core::ops::{Add, Mul, Sub};
+
+use crate::{lerp, Lerper};
+
+/// Implement the operators required by `lerp`, component-wise.
+macro_rules! component_ops {
+    ($name:ident { $($field:ident),* }) => {
+        impl Add for $name {
+            type Output = $name;
+
+            fn add(self, other: $name) -> $name {
+                $name { $($field: self.$field + other.$field),* }
+            }
+        }
+
+        impl Sub for $name {
+            type Output = $name;
+
+            fn sub(self, other: $name) -> $name {
+                $name { $($field: self.$field - other.$field),* }
+            }
+        }
+
+        impl Mul<f32> for $name {
+            type Output = $name;
+
+            fn mul(self, scale: f32) -> $name {
+                $name { $($field: self.$field * scale),* }
+            }
+        }
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+/// Width and height of a layout element.
+pub struct Size {
+    /// Width.
+    pub width: f32,
+    /// Height.
+    pub height: f32,
+}
+
+impl Size {
+    /// Create a new size.
+    pub fn new(width: f32, height: f32) -> Size {
+        Size { width, height }
+    }
+
+    /// Ratio of width to height.
+    pub fn aspect(self) -> f32 {
+        self.width / self.height
+    }
+}
+
+component_ops!(Size { width, height });
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+/// Axis aligned rectangle, from its top left corner.
+pub struct Rect {
+    /// _x_ coordinate of the left edge.
+    pub x: f32,
+    /// _y_ coordinate of the top edge.
+    pub y: f32,
+    /// Width.
+    pub width: f32,
+    /// Height.
+    pub height: f32,
+}
+
+impl Rect {
+    /// Create a new rectangle.
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Rect {
+        Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Create a new rectangle of the given size, centred on `(x, y)`.
+    pub fn from_center(x: f32, y: f32, size: Size) -> Rect {
+        Rect::new(
+            x - size.width / 2.0,
+            y - size.height / 2.0,
+            size.width,
+            size.height,
+        )
+    }
+
+    /// Centre of the rectangle, as `(x, y)`.
+    pub fn center(self) -> (f32, f32) {
+        (self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+
+    /// Size of the rectangle.
+    pub fn size(self) -> Size {
+        Size::new(self.width, self.height)
+    }
+
+    /// Largest rectangle with the given aspect ratio that fits inside this
+    /// one, sharing its centre.
+    pub fn fit(self, aspect: f32) -> Rect {
+        let (x, y) = self.center();
+        let size = if self.size().aspect() > aspect {
+            Size::new(self.height * aspect, self.height)
+        } else {
+            Size::new(self.width, self.width / aspect)
+        };
+
+        Rect::from_center(x, y, size)
+    }
+
+    /// Smallest rectangle with the given aspect ratio that covers this one,
+    /// sharing its centre.
+    pub fn fill(self, aspect: f32) -> Rect {
+        let (x, y) = self.center();
+        let size = if self.size().aspect() > aspect {
+            Size::new(self.width, self.width / aspect)
+        } else {
+            Size::new(self.height * aspect, self.height)
+        };
+
+        Rect::from_center(x, y, size)
+    }
+
+    /// Shrink the rectangle by the given insets.
+    pub fn inset(self, insets: Insets) -> Rect {
+        Rect::new(
+            self.x + insets.left,
+            self.y + insets.top,
+            self.width - insets.left - insets.right,
+            self.height - insets.top - insets.bottom,
+        )
+    }
+}
+
+component_ops!(Rect {
+    x,
+    y,
+    width,
+    height
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+/// Distances inwards from each edge of a rectangle, such as padding or safe
+/// areas.
+pub struct Insets {
+    /// Distance from the top edge.
+    pub top: f32,
+    /// Distance from the right edge.
+    pub right: f32,
+    /// Distance from the bottom edge.
+    pub bottom: f32,
+    /// Distance from the left edge.
+    pub left: f32,
+}
+
+impl Insets {
+    /// Create new insets.
+    pub fn new(top: f32, right: f32, bottom: f32, left: f32) -> Insets {
+        Insets {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    /// Create new insets, the same distance from every edge.
+    pub fn uniform(inset: f32) -> Insets {
+        Insets::new(inset, inset, inset, inset)
+    }
+}
+
+component_ops!(Insets {
+    top,
+    right,
+    bottom,
+    left
+});
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// How the content of a rectangle keeps its shape in [`lerp_rect`][0].
+///
+/// [0]: fn.lerp_rect.html
+pub enum AspectMode {
+    /// Interpolate each edge independently, stretching the content.
+    Stretch,
+    /// Keep the content at the given aspect ratio, fitted inside the
+    /// interpolated rectangle.
+    Fit(f32),
+    /// Keep the content at the given aspect ratio, covering the interpolated
+    /// rectangle.
+    Fill(f32),
+}
+
+/// Interpolate between two rectangles, such as a card expanding to full
+/// screen, keeping content at its aspect ratio with the given mode.
+///
+/// Like [`lerp`](fn.lerp.html), _t_ is clamped to `[0, 1]`.
+///
+/// # Usage
+/// ```
+/// use soy::{AspectMode, Rect};
+///
+/// let card = Rect::new(20.0, 40.0, 160.0, 90.0);
+/// let screen = Rect::new(0.0, 0.0, 400.0, 800.0);
+///
+/// let photo = soy::lerp_rect(soy::EASE_IN_OUT, card, screen, 0.5, AspectMode::Fit(16.0 / 9.0));
+/// assert!((photo.size().aspect() - 16.0 / 9.0).abs() < 1.0e-5);
+///
+/// assert_eq!(soy::lerp_rect(soy::Linear, card, screen, 1.0, AspectMode::Stretch), screen);
+/// ```
+pub fn lerp_rect<L: Lerper>(lerper: L, from: Rect, to: Rect, t: f32, mode: AspectMode) -> Rect {
+    let frame = lerp(lerper, from, to, t);
+
+    match mode {
+        AspectMode::Stretch => frame,
+        AspectMode::Fit(aspect) => frame.fit(aspect),
+        AspectMode::Fill(aspect) => frame.fill(aspect),
+    }
+}
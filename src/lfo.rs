@@ -0,0 +1,141 @@
+use core::f32::consts::PI;
+
+use core::hash::{Hash, Hasher};
+
+use crate::hash::hash_f32;
+use crate::noise::random;
+use crate::Lerper;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Shape of each cycle of an [`Lfo`][0].
+///
+/// [0]: struct.Lfo.html
+pub enum Waveform {
+    /// Smooth sine wave.
+    Sine,
+    /// Linear rise and fall.
+    Triangle,
+    /// Alternates between the high and low value.
+    Square,
+    /// Linear rise, then an immediate drop.
+    Saw,
+    /// Random value, held for each cycle.
+    SampleAndHold,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Low frequency oscillator, driven by time in seconds rather than progress.
+///
+/// As a lerper, the oscillator ranges from `0.0` to `1.0`, so `lerp` maps it
+/// onto any range. [`bipolar`][0] ranges from `-1.0` to `1.0` instead.
+///
+/// The sine, triangle and square waves all start each cycle at their middle
+/// or high value and rise, like a sine wave.
+///
+/// # Usage
+/// ```
+/// use soy::{Lfo, Waveform};
+///
+/// let bob = Lfo::new(Waveform::Sine, 0.5);
+///
+/// // Bob between 10 and 14 pixels, with a period of 2 seconds. Time runs past
+/// // 1, so the interpolation mustn't be clamped.
+/// assert!((soy::lerp_unclamped(&bob, 10.0, 14.0, 0.5) - 14.0).abs() < 1.0e-5);
+/// assert!((soy::lerp_unclamped(&bob, 10.0, 14.0, 1.5) - 10.0).abs() < 1.0e-5);
+/// ```
+///
+/// [0]: #method.bipolar
+pub struct Lfo {
+    /// Shape of each cycle.
+    pub waveform: Waveform,
+    /// Cycles per second.
+    pub frequency: f32,
+    /// Offset into the cycle at time zero, as a fraction of a cycle.
+    pub phase: f32,
+    /// Seed of the [`SampleAndHold`][0] waveform.
+    ///
+    /// [0]: enum.Waveform.html#variant.SampleAndHold
+    pub seed: u32,
+}
+
+impl Lfo {
+    /// Create a new oscillator with the given waveform and frequency, in
+    /// cycles per second.
+    pub fn new(waveform: Waveform, frequency: f32) -> Lfo {
+        Lfo {
+            waveform,
+            frequency,
+            phase: 0.0,
+            seed: 0,
+        }
+    }
+
+    /// Set the offset into the cycle at time zero, as a fraction of a cycle.
+    pub fn with_phase(mut self, phase: f32) -> Lfo {
+        self.phase = phase;
+        self
+    }
+
+    /// Set the seed of the [`SampleAndHold`][0] waveform.
+    ///
+    /// [0]: enum.Waveform.html#variant.SampleAndHold
+    pub fn with_seed(mut self, seed: u32) -> Lfo {
+        self.seed = seed;
+        self
+    }
+
+    /// Value of the oscillator at `time` seconds, from `-1.0` to `1.0`.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{Lfo, Waveform};
+    ///
+    /// let triangle = Lfo::new(Waveform::Triangle, 1.0);
+    /// assert_eq!(triangle.bipolar(0.0), 0.0);
+    /// assert_eq!(triangle.bipolar(0.25), 1.0);
+    /// assert_eq!(triangle.bipolar(0.75), -1.0);
+    ///
+    /// let square = Lfo::new(Waveform::Square, 1.0);
+    /// assert_eq!(square.bipolar(0.2), 1.0);
+    /// assert_eq!(square.bipolar(0.7), -1.0);
+    /// ```
+    pub fn bipolar(&self, time: f32) -> f32 {
+        let position = time * self.frequency + self.phase;
+        let p = position.rem_euclid(1.0);
+
+        match self.waveform {
+            Waveform::Sine => (2.0 * PI * p).sin(),
+            Waveform::Triangle => 4.0 * ((p + 0.75).rem_euclid(1.0) - 0.5).abs() - 1.0,
+            Waveform::Square => {
+                if p < 0.5 {
+                    1.0
+                } else {
+                    -1.0
+                }
+            }
+            Waveform::Saw => 2.0 * p - 1.0,
+            Waveform::SampleAndHold => {
+                let cycle = position.floor() as i32;
+                2.0 * random(self.seed, cycle) - 1.0
+            }
+        }
+    }
+}
+
+impl Hash for Lfo {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.waveform.hash(state);
+        hash_f32(self.frequency, state);
+        hash_f32(self.phase, state);
+        self.seed.hash(state);
+    }
+}
+
+impl Lerper for Lfo {
+    /// Value of the oscillator at `t` seconds, from `0.0` to `1.0`.
+    fn calculate(&self, t: f32) -> f32 {
+        0.5 + 0.5 * self.bipolar(t)
+    }
+}
@@ -12,7 +12,7 @@
 //! struct Linear;
 //!
 //! impl soy::Lerper for Linear {
-//!     fn calculate(&self, t: f32) -> {
+//!     fn calculate(&self, t: f32) -> f32 {
 //!         t
 //!     }
 //! }
@@ -21,16 +21,147 @@
 //! [0]: trait.Lerper.html
 #![deny(missing_docs)]
 
+mod analysis;
+mod anticipate;
+mod audio;
+mod barycentric;
+#[cfg(feature = "bevy")]
+pub mod bevy;
 mod bezier;
+mod blend;
+mod cache;
+mod clock;
+mod combinators;
 mod constants;
+mod count;
+#[cfg(feature = "debug")]
+pub mod debug;
+mod dynamics;
+mod easing;
+#[cfg(feature = "egui")]
+pub mod egui;
+mod envelope;
+mod export;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod fit;
+mod fling;
+mod frames;
+mod gpu;
+mod grid;
+#[cfg(feature = "half")]
+pub mod half;
+mod hash;
+#[cfg(feature = "iced")]
+pub mod iced;
+mod info;
+mod input;
+mod layout;
+mod lfo;
+mod lut;
+mod macros;
+mod marker;
+mod morph;
+mod noise;
+mod ops;
+mod penner;
+mod pipeline;
+#[cfg(feature = "python")]
+pub mod python;
+mod registry;
+mod resample;
+mod rubber_band;
+mod sample;
+mod schlick;
+mod series;
+mod shake;
+mod sigmoid;
+mod simplify;
+mod smooth;
+mod smoothstep;
+mod spline;
+mod spring;
+mod steps;
+#[cfg(feature = "async")]
+pub mod stream;
+mod svg;
+mod text;
+mod timestep;
+#[cfg(feature = "tokio")]
+pub mod tokio;
+mod transform;
+mod vector;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+mod zip;
 
 use core::ops::{Add, Mul, Sub};
+use std::sync::Arc;
 
+pub use analysis::{analyze, Analysis, Extremum, ExtremumKind, MonotonicRange, Monotonicity};
+pub use anticipate::{Anticipate, AnticipateOvershoot, Overshoot};
+pub use audio::{crossfade, pan, EqualGain, EqualPower, PanLaw};
+pub use barycentric::{barycentric, interpolate_triangle};
 pub use bezier::{cubic_bezier, Bezier};
+pub use blend::{BlendSample, BlendSpace1, BlendSpace2};
+pub use cache::{CachedBezier, SolveCache};
+pub use clock::{AnimationClock, FixedStepClock, InstantClock, ManualClock};
+pub use combinators::{Clamped, Normalized, Remap, Repeat, Reverse, Then};
 pub use constants::*;
+pub use count::CountUp;
+pub use dynamics::SecondOrder;
+pub use easing::EasingFunction;
+pub use envelope::{Adsr, EnvelopeStage};
+pub use export::{debug_plot, to_svg, to_svg_path, write_csv, SvgOptions};
+pub use fit::{fit_bezier, BezierFit};
+pub use fling::Fling;
+pub use frames::{frame_index, FrameAnimation, FrameMode};
+pub use gpu::{lut_to_gpu, GpuLayout, ToGpu};
+pub use grid::Grid2;
+pub use info::{CurveInfo, Parameter};
+pub use input::{deadzone, Hysteresis, InputCurve};
+pub use layout::{lerp_rect, AspectMode, Insets, Rect, Size};
+pub use lfo::{Lfo, Waveform};
+pub use lut::Lut;
+pub use marker::{Marker, MarkerTrack};
+pub use morph::{resample_polygon, Morph};
+pub use noise::{Flicker, GradientNoise, Jittered};
+pub use ops::{Difference, Product, Scaled, Sum};
+pub use penner::{EasingFamily, EasingMode, Penner};
+pub use pipeline::{Pipeline, PipelineError};
+pub use registry::{all_easings, BoxedLerper, Registry};
+pub use resample::{resample, resample_into, Kernel};
+pub use rubber_band::{
+    rubber_band, rubber_band_clamp, rubber_band_inverse, RUBBER_BAND_COEFFICIENT,
+};
+pub use sample::{lerp_iter, sample, EasedSteps};
+pub use schlick::{Bias, Gain};
+pub use series::{Akima, MonotoneCubic, Pchip, SmoothingSpline};
+pub use shake::Shake;
+pub use sigmoid::Sigmoid;
+pub use simplify::{simplify, simplify_lerper};
+pub use smooth::{damp, exp_smooth, ExpSmoother};
+pub use smoothstep::{smoothstep_n, Smoothstep};
+pub use spline::{
+    ArcLength, BSpline, BakedTrack, BezierPath, BezierSegment, CatmullRom, Continuity,
+    FixedHermite, Frame, Hermite, HermiteCursor, HermiteKey, KeyError, Nurbs, Parameterization,
+    Spline, TangentMode,
+};
+pub use spring::Spring;
+pub use steps::{StepPosition, Steps};
+pub use svg::{PathMorph, SvgPath, SvgPathError};
+pub use text::{reveal, reveal_count};
+pub use timestep::{lerp_states, FixedTimestep};
+pub use transform::{slerp, Transform, TransformTrack};
+pub use vector::{Cross, Dot, Perp};
+pub use zip::{lerp_slice, lerp_slice_into, lerp_zip};
 
 /// Interpolate between two values given an interpolation method.
 ///
+/// _t_ is clamped to `[0, 1]`, so out of range timing holds at either end
+/// rather than extrapolating. Lerpers which overshoot still do so within the
+/// range of _t_. Use [`lerp_unclamped`][0] to extrapolate deliberately.
+///
 /// # Arguments:
 /// - `lerper`: Interpolation method to use.
 /// - `start`: Initial data point.
@@ -39,18 +170,39 @@ pub use constants::*;
 ///
 /// # Usage
 /// ```
-/// fn main() {
-///     let start = 5.0;
-///     let end = 10.0;
+/// let start = 5.0;
+/// let end = 10.0;
 ///
-///     let quarter = soy::lerp(soy::Linear, start, end, 0.25);
-///     assert_eq!(quarter, 6.25);
+/// let quarter = soy::lerp(soy::Linear, start, end, 0.25);
+/// assert_eq!(quarter, 6.25);
 ///
-///     let half_way = soy::lerp(soy::Linear, start, end, 0.5);
-///     assert_eq!(half_way, 7.5);
-/// }
+/// let half_way = soy::lerp(soy::Linear, start, end, 0.5);
+/// assert_eq!(half_way, 7.5);
+///
+/// assert_eq!(soy::lerp(soy::Linear, start, end, 2.0), 10.0);
 /// ```
+///
+/// [0]: fn.lerp_unclamped.html
 pub fn lerp<T, D>(lerper: T, start: D, end: D, t: f32) -> D
+where
+    T: Lerper,
+    D: Copy,
+    D: Add<Output = D>,
+    D: Sub<Output = D>,
+    D: Mul<f32, Output = D>,
+{
+    lerp_unclamped(lerper, start, end, t.clamp(0.0, 1.0))
+}
+
+/// Interpolate between two values given an interpolation method, without
+/// clamping _t_, so values outside of `[0, 1]` extrapolate.
+///
+/// # Usage
+/// ```
+/// assert_eq!(soy::lerp_unclamped(soy::Linear, 5.0, 10.0, 2.0), 15.0);
+/// assert_eq!(soy::lerp_unclamped(soy::Linear, 5.0, 10.0, -1.0), 0.0);
+/// ```
+pub fn lerp_unclamped<T, D>(lerper: T, start: D, end: D, t: f32) -> D
 where
     T: Lerper,
     D: Copy,
@@ -61,13 +213,256 @@ where
     start + (end - start) * lerper.calculate(t)
 }
 
+/// Interpolate between two values given an interpolation method, with the
+/// formula _(1 - k) start + k end_.
+///
+/// Unlike [`lerp`][0], the result is exactly `end` when the lerper reaches
+/// `1.0`, even when rounding would make _start + (end - start)_ miss it. In
+/// exchange, the result may not change monotonically with _k_. Like `lerp`,
+/// _t_ is clamped to `[0, 1]`.
+///
+/// # Usage
+/// ```
+/// let (start, end) = (1.0e8_f32, 0.1_f32);
+///
+/// assert_ne!(soy::lerp(soy::Linear, start, end, 1.0), end);
+/// assert_eq!(soy::lerp_precise(soy::Linear, start, end, 1.0), end);
+/// assert_eq!(soy::lerp_precise(soy::Linear, start, end, 0.0), start);
+/// ```
+///
+/// [0]: fn.lerp.html
+pub fn lerp_precise<T, D>(lerper: T, start: D, end: D, t: f32) -> D
+where
+    T: Lerper,
+    D: Copy,
+    D: Add<Output = D>,
+    D: Mul<f32, Output = D>,
+{
+    let k = lerper.calculate(t.clamp(0.0, 1.0));
+    start * (1.0 - k) + end * k
+}
+
+/// Interpolate between two values by reference, for types which are `Clone`
+/// but not `Copy`, such as heap allocated matrices or big numbers.
+///
+/// Only references to the values are used, so `start` and `end` are never
+/// cloned. Like [`lerp`][0], _t_ is clamped to `[0, 1]`.
+///
+/// # Usage
+/// ```
+/// use std::ops::{Add, Mul, Sub};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Pose(Vec<f32>);
+///
+/// impl Sub for &Pose {
+///     type Output = Pose;
+///     fn sub(self, other: &Pose) -> Pose {
+///         Pose(self.0.iter().zip(&other.0).map(|(a, b)| a - b).collect())
+///     }
+/// }
+///
+/// impl Add<Pose> for &Pose {
+///     type Output = Pose;
+///     fn add(self, other: Pose) -> Pose {
+///         Pose(self.0.iter().zip(&other.0).map(|(a, b)| a + b).collect())
+///     }
+/// }
+///
+/// impl Mul<f32> for Pose {
+///     type Output = Pose;
+///     fn mul(self, k: f32) -> Pose {
+///         Pose(self.0.into_iter().map(|a| a * k).collect())
+///     }
+/// }
+///
+/// let rest = Pose(vec![0.0, 0.0]);
+/// let wave = Pose(vec![1.0, 4.0]);
+///
+/// assert_eq!(soy::lerp_ref(soy::Linear, &rest, &wave, 0.5), Pose(vec![0.5, 2.0]));
+/// ```
+///
+/// [0]: fn.lerp.html
+pub fn lerp_ref<T, D>(lerper: T, start: &D, end: &D, t: f32) -> D
+where
+    T: Lerper,
+    for<'a> &'a D: Add<D, Output = D>,
+    for<'a> &'a D: Sub<&'a D, Output = D>,
+    D: Mul<f32, Output = D>,
+{
+    start + (end - start) * lerper.calculate(t.clamp(0.0, 1.0))
+}
+
 /// Trait implemented by all interpolating methods.
 pub trait Lerper {
     /// Given a timing function _y = f(t)_, this method calculates the _y_ value
     /// at the given _t_.
     fn calculate(&self, t: f32) -> f32;
+
+    /// Play this lerper until `split`, then `next`.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Lerper;
+    ///
+    /// let twice = soy::Linear.then(soy::EASE_OUT, 0.5);
+    ///
+    /// assert_eq!(twice.calculate(0.25), 0.25);
+    /// assert_eq!(twice.calculate(1.0), 1.0);
+    /// ```
+    fn then<B: Lerper>(self, next: B, split: f32) -> Then<Self, B>
+    where
+        Self: Sized,
+    {
+        Then::new(self, next, split)
+    }
+
+    /// Play this lerper `count` times over the range of _t_.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Lerper;
+    ///
+    /// let pulses = soy::Linear.repeat(4);
+    /// assert!((pulses.calculate(0.3) - 0.2).abs() < 1.0e-6);
+    /// assert_eq!(pulses.calculate(0.5), 0.0);
+    /// assert_eq!(pulses.calculate(1.0), 1.0);
+    /// ```
+    fn repeat(self, count: u32) -> Repeat<Self>
+    where
+        Self: Sized,
+    {
+        Repeat::new(self, count)
+    }
+
+    /// Play this lerper backwards, turning an ease in into an ease out.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Lerper;
+    ///
+    /// let ease_out = soy::EASE_IN.reverse();
+    /// assert!(ease_out.calculate(0.25) > 0.25);
+    /// ```
+    fn reverse(self) -> Reverse<Self>
+    where
+        Self: Sized,
+    {
+        Reverse::new(self)
+    }
+
+    /// Saturate the output of this lerper to `[0, 1]`, so interpolated values
+    /// stay between the start and end.
+    ///
+    /// Lerpers can be clamped for a single call by clamping a reference.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Lerper;
+    ///
+    /// let pop = soy::EASE_OUT_BACK;
+    /// assert!(soy::lerp(&pop, 0.0, 1.0, 0.7) > 1.0);
+    ///
+    /// let opacity = soy::lerp((&pop).clamped(), 0.0, 1.0, 0.7);
+    /// assert_eq!(opacity, 1.0);
+    /// ```
+    fn clamped(self) -> Clamped<Self>
+    where
+        Self: Sized,
+    {
+        Clamped::new(self)
+    }
+
+    /// Rescale this lerper to start at `0.0` and end at `1.0`.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Lerper;
+    ///
+    /// let wobbly = (soy::EASE_OUT + soy::Linear * 0.5).normalized();
+    ///
+    /// assert_eq!(wobbly.calculate(0.0), 0.0);
+    /// assert_eq!(wobbly.calculate(1.0), 1.0);
+    /// ```
+    fn normalized(self) -> Normalized<Self>
+    where
+        Self: Sized,
+    {
+        Normalized::new(self)
+    }
+
+    /// Sample this lerper `count` times, evenly spaced from _t = 0_ to _t = 1_
+    /// inclusive.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Lerper;
+    ///
+    /// let steps: Vec<f32> = soy::Linear.eased_steps(5).collect();
+    /// assert_eq!(steps, vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// ```
+    fn eased_steps(self, count: usize) -> EasedSteps<Self>
+    where
+        Self: Sized,
+    {
+        EasedSteps::new(self, count)
+    }
+
+    /// Convert this lerper into a shared, thread-safe, trait object.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{DynLerper, Lerper};
+    ///
+    /// let shared: DynLerper = soy::EASE_IN_OUT.into_dyn();
+    /// let clone = shared.clone();
+    ///
+    /// std::thread::spawn(move || soy::lerp(clone, 0.0, 1.0, 0.5))
+    ///     .join()
+    ///     .unwrap();
+    /// assert_eq!(soy::lerp(&shared, 0.0, 10.0, 1.0), 10.0);
+    ///
+    /// let boxed: soy::BoxedLerper = Box::new(soy::Linear);
+    /// let from_box = DynLerper::from(boxed);
+    /// assert_eq!(soy::lerp(from_box, 0.0, 10.0, 0.5), 5.0);
+    /// ```
+    fn into_dyn(self) -> DynLerper
+    where
+        Self: Sized + Send + Sync + 'static,
+    {
+        Arc::new(self)
+    }
+}
+
+/// Shared, thread-safe, lerper trait object, for storing easings in
+/// components and sharing them across threads.
+///
+/// Boxed lerpers, such as those from a [`Registry`][0], convert with
+/// `DynLerper::from`.
+///
+/// [0]: struct.Registry.html
+pub type DynLerper = Arc<dyn Lerper + Send + Sync>;
+
+impl<L: Lerper + ?Sized> Lerper for &L {
+    fn calculate(&self, t: f32) -> f32 {
+        (**self).calculate(t)
+    }
+}
+
+impl<L: Lerper + ?Sized> Lerper for Box<L> {
+    fn calculate(&self, t: f32) -> f32 {
+        (**self).calculate(t)
+    }
+}
+
+impl<L: Lerper + ?Sized> Lerper for Arc<L> {
+    fn calculate(&self, t: f32) -> f32 {
+        (**self).calculate(t)
+    }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Linear interpolator: _f(t) = t_.
 pub struct Linear;
 
@@ -0,0 +1,167 @@
+use core::hash::{Hash, Hasher};
+
+use crate::hash::hash_f32;
+use crate::{sample, Lerper, SecondOrder};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Lerper reading from a table of evenly spaced values, from _t = 0_ to
+/// _t = 1_ inclusive, linearly interpolating between entries.
+///
+/// Baking expensive or stateful curves into a table gives a cheap, stateless
+/// lerper which can be used anywhere an easing is accepted. _t_ is clamped to
+/// `[0, 1]`.
+pub struct Lut {
+    values: Vec<f32>,
+}
+
+impl Lut {
+    /// Time step used to simulate second-order systems, in seconds.
+    const SIMULATION_STEP: f32 = 1.0 / 1000.0;
+    /// Longest time a second-order system is simulated for, in seconds.
+    const SIMULATION_LIMIT: f32 = 60.0;
+    /// Distance from the target, and speed, at which a system is at rest.
+    const REST_THRESHOLD: f32 = 1.0e-3;
+
+    /// Create a new table from the given values.
+    ///
+    /// # Panics
+    /// Panics if `values` is empty.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Lerper;
+    ///
+    /// let table = soy::Lut::new(vec![0.0, 0.8, 1.0]);
+    ///
+    /// assert_eq!(table.calculate(0.25), 0.4);
+    /// assert_eq!(table.calculate(0.5), 0.8);
+    /// ```
+    pub fn new(values: Vec<f32>) -> Lut {
+        assert!(!values.is_empty(), "a table requires at least one value");
+        Lut { values }
+    }
+
+    /// Bake `count` samples of a lerper into a table.
+    ///
+    /// # Panics
+    /// Panics if `count` is zero.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Lerper;
+    ///
+    /// let spring = soy::Spring::with_response(0.5, 0.4);
+    /// let baked = soy::Lut::from_lerper(spring, 256);
+    ///
+    /// assert!((baked.calculate(0.3) - spring.calculate(0.3)).abs() < 1.0e-2);
+    /// ```
+    pub fn from_lerper<L: Lerper>(lerper: L, count: usize) -> Lut {
+        Lut::new(sample(lerper, count).map(|(_, y)| y).collect())
+    }
+
+    /// Simulate the step response of a [`SecondOrder`][0] system, from `0.0`
+    /// to `1.0`, until it comes to rest, and bake it into a table of `count`
+    /// values.
+    ///
+    /// Returns the table, along with the time the system took to come to
+    /// rest, in seconds, which corresponds to _t = 1_. Systems which never
+    /// rest, such as an undamped system, are simulated for a minute.
+    ///
+    /// # Panics
+    /// Panics if `count` is zero, or `f` is not positive.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Lerper;
+    ///
+    /// let (bouncy, duration) = soy::Lut::from_second_order(2.0, 0.3, 0.0, 256);
+    ///
+    /// assert!(duration > 0.5 && duration < 5.0);
+    /// assert_eq!(bouncy.calculate(0.0), 0.0);
+    /// assert!(bouncy.calculate(0.2) > 1.0);
+    /// assert_eq!(bouncy.calculate(1.0), 1.0);
+    /// ```
+    ///
+    /// [0]: struct.SecondOrder.html
+    pub fn from_second_order(f: f32, z: f32, r: f32, count: usize) -> (Lut, f32) {
+        assert!(count > 0, "a table requires at least one value");
+
+        let dt = Lut::SIMULATION_STEP;
+        let steps = (Lut::SIMULATION_LIMIT / dt) as usize;
+
+        let mut system = SecondOrder::new(f, z, r, 0.0);
+        let mut positions = vec![0.0];
+
+        for _ in 0..steps {
+            let y = system.update(dt, 1.0);
+            positions.push(y);
+
+            let at_rest = (y - 1.0).abs() < Lut::REST_THRESHOLD
+                && system.velocity().abs() < Lut::REST_THRESHOLD;
+            if at_rest {
+                break;
+            }
+        }
+
+        // The system is only within the threshold of the target, so snap the
+        // end of the table to it.
+        let last = positions.len() - 1;
+        positions[last] = 1.0;
+
+        let simulated = Lut::new(positions);
+        let duration = last as f32 * dt;
+
+        (Lut::from_lerper(&simulated, count), duration)
+    }
+
+    /// Values in the table.
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+}
+
+impl Hash for Lut {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.values.len().hash(state);
+        for &value in &self.values {
+            hash_f32(value, state);
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Lut {
+    /// Tables are serialized as their values.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.values, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Lut {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Lut, D::Error> {
+        let values = <Vec<f32> as serde::Deserialize>::deserialize(deserializer)?;
+        if values.is_empty() {
+            return Err(serde::de::Error::custom(
+                "a table requires at least one value",
+            ));
+        }
+
+        Ok(Lut { values })
+    }
+}
+
+impl Lerper for Lut {
+    fn calculate(&self, t: f32) -> f32 {
+        let last = self.values.len() - 1;
+        if last == 0 {
+            return self.values[0];
+        }
+
+        let position = t.clamp(0.0, 1.0) * last as f32;
+        let i = (position.floor() as usize).min(last - 1);
+        let u = position - i as f32;
+
+        self.values[i] + (self.values[i + 1] - self.values[i]) * u
+    }
+}
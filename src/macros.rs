@@ -0,0 +1,147 @@
+/// Compose lerpers at compile time.
+///
+/// Built-in easings are referred to by their snake case names, such as
+/// `ease_in` or `ease_out_bounce`, and are combined with the combinator methods on
+/// [`Lerper`][0], such as `then` and `repeat`. Penner's easings can also be
+/// named family first, such as `bounce_out`. Any other identifier is used
+/// as is, so custom lerpers in scope can be composed too, and arguments can
+/// be any expression.
+///
+/// The expression expands directly to the combinator types, with no runtime
+/// parsing.
+///
+/// # Usage
+/// ```
+/// use soy::Lerper;
+///
+/// let custom = soy::cubic_bezier(0.2, 0.0, 0.0, 1.0);
+/// let composed = soy::easing!(ease_in.then(custom.reverse(), 0.6).repeat(2));
+///
+/// assert_eq!(composed.calculate(0.0), 0.0);
+/// assert_eq!(composed.calculate(1.0), 1.0);
+///
+/// let bouncy = soy::easing!(ease_in.then(bounce_out, 0.6).repeat(2));
+/// assert_eq!(bouncy.calculate(1.0), 1.0);
+///
+/// let wobbly = soy::easing!(linear.then(soy::cubic_bezier(0.3, -0.1, 0.7, 1.1), -0.1 + 0.5));
+/// assert_eq!(wobbly.calculate(0.2), 0.2);
+/// ```
+///
+/// [0]: trait.Lerper.html
+#[macro_export]
+macro_rules! easing {
+    (@name linear) => { $crate::Linear };
+    (@name ease) => { $crate::EASE };
+    (@name ease_in) => { $crate::EASE_IN };
+    (@name ease_out) => { $crate::EASE_OUT };
+    (@name ease_in_out) => { $crate::EASE_IN_OUT };
+    (@name ease_in_sine) => { $crate::EASE_IN_SINE };
+    (@name ease_in_quad) => { $crate::EASE_IN_QUAD };
+    (@name ease_in_cubic) => { $crate::EASE_IN_CUBIC };
+    (@name ease_in_quart) => { $crate::EASE_IN_QUART };
+    (@name ease_in_quint) => { $crate::EASE_IN_QUINT };
+    (@name ease_in_expo) => { $crate::EASE_IN_EXPO };
+    (@name ease_in_circ) => { $crate::EASE_IN_CIRC };
+    (@name ease_in_back) => { $crate::EASE_IN_BACK };
+    (@name ease_in_elastic) => { $crate::EASE_IN_ELASTIC };
+    (@name ease_in_bounce) => { $crate::EASE_IN_BOUNCE };
+    (@name ease_out_sine) => { $crate::EASE_OUT_SINE };
+    (@name ease_out_quad) => { $crate::EASE_OUT_QUAD };
+    (@name ease_out_cubic) => { $crate::EASE_OUT_CUBIC };
+    (@name ease_out_quart) => { $crate::EASE_OUT_QUART };
+    (@name ease_out_quint) => { $crate::EASE_OUT_QUINT };
+    (@name ease_out_expo) => { $crate::EASE_OUT_EXPO };
+    (@name ease_out_circ) => { $crate::EASE_OUT_CIRC };
+    (@name ease_out_back) => { $crate::EASE_OUT_BACK };
+    (@name ease_out_elastic) => { $crate::EASE_OUT_ELASTIC };
+    (@name ease_out_bounce) => { $crate::EASE_OUT_BOUNCE };
+    (@name ease_in_out_sine) => { $crate::EASE_IN_OUT_SINE };
+    (@name ease_in_out_quad) => { $crate::EASE_IN_OUT_QUAD };
+    (@name ease_in_out_cubic) => { $crate::EASE_IN_OUT_CUBIC };
+    (@name ease_in_out_quart) => { $crate::EASE_IN_OUT_QUART };
+    (@name ease_in_out_quint) => { $crate::EASE_IN_OUT_QUINT };
+    (@name ease_in_out_expo) => { $crate::EASE_IN_OUT_EXPO };
+    (@name ease_in_out_circ) => { $crate::EASE_IN_OUT_CIRC };
+    (@name ease_in_out_back) => { $crate::EASE_IN_OUT_BACK };
+    (@name ease_in_out_elastic) => { $crate::EASE_IN_OUT_ELASTIC };
+    (@name ease_in_out_bounce) => { $crate::EASE_IN_OUT_BOUNCE };
+    (@name material_standard) => { $crate::MATERIAL_STANDARD };
+    (@name material_standard_decelerate) => { $crate::MATERIAL_STANDARD_DECELERATE };
+    (@name material_standard_accelerate) => { $crate::MATERIAL_STANDARD_ACCELERATE };
+    (@name material_emphasized) => { $crate::MATERIAL_EMPHASIZED };
+    (@name material_emphasized_decelerate) => { $crate::MATERIAL_EMPHASIZED_DECELERATE };
+    (@name material_emphasized_accelerate) => { $crate::MATERIAL_EMPHASIZED_ACCELERATE };
+    (@name material_legacy) => { $crate::MATERIAL_LEGACY };
+    (@name material_legacy_decelerate) => { $crate::MATERIAL_LEGACY_DECELERATE };
+    (@name material_legacy_accelerate) => { $crate::MATERIAL_LEGACY_ACCELERATE };
+    (@name sine_in) => { $crate::EASE_IN_SINE };
+    (@name quad_in) => { $crate::EASE_IN_QUAD };
+    (@name cubic_in) => { $crate::EASE_IN_CUBIC };
+    (@name quart_in) => { $crate::EASE_IN_QUART };
+    (@name quint_in) => { $crate::EASE_IN_QUINT };
+    (@name expo_in) => { $crate::EASE_IN_EXPO };
+    (@name circ_in) => { $crate::EASE_IN_CIRC };
+    (@name back_in) => { $crate::EASE_IN_BACK };
+    (@name elastic_in) => { $crate::EASE_IN_ELASTIC };
+    (@name bounce_in) => { $crate::EASE_IN_BOUNCE };
+    (@name sine_out) => { $crate::EASE_OUT_SINE };
+    (@name quad_out) => { $crate::EASE_OUT_QUAD };
+    (@name cubic_out) => { $crate::EASE_OUT_CUBIC };
+    (@name quart_out) => { $crate::EASE_OUT_QUART };
+    (@name quint_out) => { $crate::EASE_OUT_QUINT };
+    (@name expo_out) => { $crate::EASE_OUT_EXPO };
+    (@name circ_out) => { $crate::EASE_OUT_CIRC };
+    (@name back_out) => { $crate::EASE_OUT_BACK };
+    (@name elastic_out) => { $crate::EASE_OUT_ELASTIC };
+    (@name bounce_out) => { $crate::EASE_OUT_BOUNCE };
+    (@name sine_in_out) => { $crate::EASE_IN_OUT_SINE };
+    (@name quad_in_out) => { $crate::EASE_IN_OUT_QUAD };
+    (@name cubic_in_out) => { $crate::EASE_IN_OUT_CUBIC };
+    (@name quart_in_out) => { $crate::EASE_IN_OUT_QUART };
+    (@name quint_in_out) => { $crate::EASE_IN_OUT_QUINT };
+    (@name expo_in_out) => { $crate::EASE_IN_OUT_EXPO };
+    (@name circ_in_out) => { $crate::EASE_IN_OUT_CIRC };
+    (@name back_in_out) => { $crate::EASE_IN_OUT_BACK };
+    (@name elastic_in_out) => { $crate::EASE_IN_OUT_ELASTIC };
+    (@name bounce_in_out) => { $crate::EASE_IN_OUT_BOUNCE };
+    (@name $other:tt) => { $other };
+
+    // Apply each method call in turn to the receiver.
+    (@methods ($($receiver:tt)*)) => { $($receiver)* };
+    (@methods $receiver:tt . $method:ident ( $($args:tt)* ) $($rest:tt)*) => {
+        $crate::easing!(@args $receiver $method [] [] [$($args)*] $($rest)*)
+    };
+
+    // Split a method's arguments on commas, then call it.
+    (@args $receiver:tt $method:ident [$($done:tt)*] [$($arg:tt)*] [, $($args:tt)*] $($rest:tt)*) => {
+        $crate::easing!(@args $receiver $method [$($done)* ($($arg)*)] [] [$($args)*] $($rest)*)
+    };
+    (@args $receiver:tt $method:ident [$($done:tt)*] [$($arg:tt)*] [$next:tt $($args:tt)*] $($rest:tt)*) => {
+        $crate::easing!(@args $receiver $method [$($done)*] [$($arg)* $next] [$($args)*] $($rest)*)
+    };
+    (@args $receiver:tt $method:ident [$($done:tt)*] [$($arg:tt)+] [] $($rest:tt)*) => {
+        $crate::easing!(@args $receiver $method [$($done)* ($($arg)+)] [] [] $($rest)*)
+    };
+    (@args ($($receiver:tt)*) $method:ident [$(($($arg:tt)*))*] [] [] $($rest:tt)*) => {
+        $crate::easing!(@methods
+            (($($receiver)*).$method($($crate::easing!(@arg $($arg)*)),*))
+            $($rest)*
+        )
+    };
+
+    // Arguments are either easings, composed like the whole expression, or
+    // any other expression.
+    (@arg $base:ident $(. $method:ident ( $($args:tt)* ))*) => {
+        $crate::easing!(@methods ($crate::easing!(@name $base)) $(. $method ( $($args)* ))*)
+    };
+    (@arg $($expression:tt)+) => { $($expression)+ };
+
+    ($base:ident $($rest:tt)*) => {
+        {
+            #[allow(unused_imports)]
+            use $crate::Lerper as _;
+
+            $crate::easing!(@methods ($crate::easing!(@name $base)) $($rest)*)
+        }
+    };
+}
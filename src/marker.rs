@@ -0,0 +1,117 @@
+#[derive(Debug, Clone, PartialEq)]
+/// Named point in time on a [`MarkerTrack`][0], such as a footstep or
+/// subtitle cue.
+///
+/// [0]: struct.MarkerTrack.html
+pub struct Marker<P> {
+    /// Time of the marker.
+    pub time: f32,
+    /// Name of the marker.
+    pub name: String,
+    /// Data carried by the marker.
+    pub payload: P,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Non-interpolating track of markers, which are reported as playback
+/// crosses them, for footstep sounds, spawn triggers and subtitle cues.
+///
+/// # Usage
+/// ```
+/// use soy::{Marker, MarkerTrack};
+///
+/// let mut track = MarkerTrack::new();
+/// track.insert(Marker { time: 0.5, name: String::from("step"), payload: "left" });
+/// track.insert(Marker { time: 1.0, name: String::from("step"), payload: "right" });
+///
+/// let mut last = 0.0;
+/// let mut heard = Vec::new();
+/// for frame in 1..=60 {
+///     let now = frame as f32 / 30.0;
+///     heard.extend(track.crossed(last, now).map(|m| m.payload));
+///     last = now;
+/// }
+///
+/// assert_eq!(heard, vec!["left", "right"]);
+/// ```
+pub struct MarkerTrack<P> {
+    markers: Vec<Marker<P>>,
+}
+
+impl<P> MarkerTrack<P> {
+    /// Create a new, empty, marker track.
+    pub fn new() -> MarkerTrack<P> {
+        MarkerTrack {
+            markers: Vec::new(),
+        }
+    }
+
+    /// Add a marker, after any other markers at the same time.
+    pub fn insert(&mut self, marker: Marker<P>) {
+        let index = self.markers.partition_point(|m| m.time <= marker.time);
+        self.markers.insert(index, marker);
+    }
+
+    /// Remove every marker with the given name.
+    pub fn remove(&mut self, name: &str) {
+        self.markers.retain(|m| m.name != name);
+    }
+
+    /// Markers, sorted by time.
+    pub fn markers(&self) -> &[Marker<P>] {
+        &self.markers
+    }
+
+    /// Markers crossed by playback moving from `from` to `to`, in the order
+    /// they're crossed.
+    ///
+    /// Moving forwards, markers in _(from, to]_ are crossed, so a marker
+    /// exactly at the start of each step isn't reported twice. Moving
+    /// backwards, markers in _[to, from)_ are crossed, latest first.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{Marker, MarkerTrack};
+    ///
+    /// let mut track = MarkerTrack::new();
+    /// track.insert(Marker { time: 1.0, name: String::from("a"), payload: () });
+    /// track.insert(Marker { time: 2.0, name: String::from("b"), payload: () });
+    ///
+    /// let names = |from, to| -> Vec<&str> {
+    ///     track.crossed(from, to).map(|m| m.name.as_str()).collect()
+    /// };
+    ///
+    /// assert_eq!(names(0.0, 1.0), vec!["a"]);
+    /// assert_eq!(names(1.0, 3.0), vec!["b"]);
+    /// assert_eq!(names(3.0, 0.0), vec!["b", "a"]);
+    /// ```
+    pub fn crossed(&self, from: f32, to: f32) -> impl Iterator<Item = &Marker<P>> {
+        let (forwards, low, high) = if from <= to {
+            let low = self.markers.partition_point(|m| m.time <= from);
+            let high = self.markers.partition_point(|m| m.time <= to);
+            (true, low, high)
+        } else {
+            let low = self.markers.partition_point(|m| m.time < to);
+            let high = self.markers.partition_point(|m| m.time < from);
+            (false, low, high)
+        };
+
+        let crossed = &self.markers[low..high];
+        let (ahead, behind) = if forwards {
+            (Some(crossed.iter()), None)
+        } else {
+            (None, Some(crossed.iter().rev()))
+        };
+
+        ahead
+            .into_iter()
+            .flatten()
+            .chain(behind.into_iter().flatten())
+    }
+}
+
+impl<P> Default for MarkerTrack<P> {
+    fn default() -> MarkerTrack<P> {
+        MarkerTrack::new()
+    }
+}
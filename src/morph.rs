@@ -0,0 +1,165 @@
+use core::ops::{Add, Mul, Sub};
+
+use crate::vector::{distance, Dot};
+use crate::{lerp_unclamped, Lerper};
+
+/// Resample a closed polygon to `count` points, evenly spaced along its
+/// perimeter, starting at its first point.
+///
+/// # Panics
+/// Panics if `points` is empty.
+///
+/// # Usage
+/// ```
+/// let points = soy::resample_polygon(&[0.0, 4.0], 4);
+/// assert_eq!(points, vec![0.0, 2.0, 4.0, 2.0]);
+/// ```
+pub fn resample_polygon<T>(points: &[T], count: usize) -> Vec<T>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+    T: Dot,
+{
+    assert!(!points.is_empty(), "a polygon requires at least one point");
+
+    let edge = |i: usize| (points[i], points[(i + 1) % points.len()]);
+    let lengths: Vec<f32> = (0..points.len())
+        .map(|i| {
+            let (a, b) = edge(i);
+            distance(a, b)
+        })
+        .collect();
+    let perimeter: f32 = lengths.iter().sum();
+
+    if perimeter <= 0.0 {
+        return vec![points[0]; count];
+    }
+
+    let mut output = Vec::with_capacity(count);
+    let (mut index, mut start) = (0, 0.0);
+
+    for k in 0..count {
+        let target = perimeter * k as f32 / count as f32;
+
+        while index + 1 < lengths.len() && start + lengths[index] < target {
+            start += lengths[index];
+            index += 1;
+        }
+
+        let (a, b) = edge(index);
+        let t = if lengths[index] > 0.0 {
+            ((target - start) / lengths[index]).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        output.push(a + (b - a) * t);
+    }
+
+    output
+}
+
+#[derive(Debug)]
+/// Morph between two closed polygons, which may have different numbers of
+/// points, for blob and icon animations.
+///
+/// Both polygons are resampled to the same number of points, and the target
+/// is rotated so each point travels as short a distance as possible.
+pub struct Morph<T> {
+    from: Vec<T>,
+    to: Vec<T>,
+}
+
+impl<T> Morph<T>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+    T: Dot,
+{
+    /// Create a new morph from `from` to `to`, each resampled to `count`
+    /// points.
+    ///
+    /// # Panics
+    /// Panics if either polygon is empty.
+    ///
+    /// # Usage
+    /// ```
+    /// # use core::ops::{Add, Mul, Sub};
+    /// # #[derive(Debug, Clone, Copy, PartialEq)]
+    /// # struct Vec2(f32, f32);
+    /// # impl Add for Vec2 { type Output = Vec2; fn add(self, o: Vec2) -> Vec2 { Vec2(self.0 + o.0, self.1 + o.1) } }
+    /// # impl Sub for Vec2 { type Output = Vec2; fn sub(self, o: Vec2) -> Vec2 { Vec2(self.0 - o.0, self.1 - o.1) } }
+    /// # impl Mul<f32> for Vec2 { type Output = Vec2; fn mul(self, s: f32) -> Vec2 { Vec2(self.0 * s, self.1 * s) } }
+    /// # impl soy::Dot for Vec2 { fn dot(self, o: Vec2) -> f32 { self.0 * o.0 + self.1 * o.1 } }
+    /// let square = [Vec2(0.0, 0.0), Vec2(2.0, 0.0), Vec2(2.0, 2.0), Vec2(0.0, 2.0)];
+    /// let triangle = [Vec2(1.0, 3.0), Vec2(0.0, 0.0), Vec2(2.0, 0.0)];
+    ///
+    /// let morph = soy::Morph::new(&square, &triangle, 12);
+    /// let start = morph.sample(soy::EASE_IN_OUT, 0.0);
+    /// let end = morph.sample(soy::EASE_IN_OUT, 1.0);
+    ///
+    /// assert_eq!(start.len(), 12);
+    /// assert_eq!(start[0], Vec2(0.0, 0.0));
+    /// // The triangle was rotated to start near the square's first point,
+    /// // rather than at its apex.
+    /// let Vec2(x, y) = end[0] - start[0];
+    /// assert!((x * x + y * y).sqrt() < 0.5);
+    /// ```
+    pub fn new(from: &[T], to: &[T], count: usize) -> Morph<T> {
+        let from = resample_polygon(from, count);
+        let mut to = resample_polygon(to, count);
+
+        let cost = |shift: usize| -> f32 {
+            (0..count)
+                .map(|i| {
+                    let d = to[(i + shift) % count] - from[i];
+                    d.dot(d)
+                })
+                .sum()
+        };
+        let best = (0..count)
+            .min_by(|&a, &b| cost(a).total_cmp(&cost(b)))
+            .unwrap_or(0);
+        to.rotate_left(best);
+
+        Morph { from, to }
+    }
+
+    /// Resampled start polygon.
+    pub fn from(&self) -> &[T] {
+        &self.from
+    }
+
+    /// Resampled, and rotated, end polygon.
+    pub fn to(&self) -> &[T] {
+        &self.to
+    }
+
+    /// Calculate the polygon at `t`, eased with the given lerper. _t_ is
+    /// clamped to `[0, 1]`.
+    pub fn sample<L: Lerper>(&self, lerper: L, t: f32) -> Vec<T> {
+        let mut output = self.from.clone();
+        self.sample_into(lerper, t, &mut output);
+        output
+    }
+
+    /// Calculate the polygon at `t` into `output`, without allocating.
+    ///
+    /// # Panics
+    /// Panics if `output` is not the same length as the polygons.
+    pub fn sample_into<L: Lerper>(&self, lerper: L, t: f32, output: &mut [T]) {
+        assert_eq!(
+            output.len(),
+            self.from.len(),
+            "output must be the same length as the polygons"
+        );
+
+        let t = lerper.calculate(t.clamp(0.0, 1.0));
+        for ((out, &a), &b) in output.iter_mut().zip(&self.from).zip(&self.to) {
+            *out = lerp_unclamped(crate::Linear, a, b, t);
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use core::hash::{Hash, Hasher};
+
+use crate::hash::hash_f32;
+use crate::Lerper;
+
+/// Hash an integer lattice coordinate with a seed into well-mixed bits.
+pub(crate) fn hash(seed: u32, i: i32) -> u32 {
+    // Based on the "lowbias32" integer hash.
+    let mut x = (i as u32) ^ seed.wrapping_mul(0x9E37_79B9);
+    x ^= x >> 16;
+    x = x.wrapping_mul(0x7FEB_352D);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x846C_A68B);
+    x ^= x >> 16;
+    x
+}
+
+/// Random value in `[0, 1]` for the given lattice coordinate.
+pub(crate) fn random(seed: u32, i: i32) -> f32 {
+    (hash(seed, i) >> 8) as f32 / ((1 << 24) - 1) as f32
+}
+
+/// Smooth value noise in `[-1, 1]`.
+pub(crate) fn value_noise(seed: u32, x: f32) -> f32 {
+    let cell = x.floor();
+    let i = cell as i32;
+    let t = x - cell;
+    let t = t * t * (3.0 - 2.0 * t);
+
+    let a = random(seed, i) * 2.0 - 1.0;
+    let b = random(seed, i + 1) * 2.0 - 1.0;
+
+    a + (b - a) * t
+}
+
+/// One-dimensional gradient (Perlin) noise, approximately in `[-1, 1]`.
+pub(crate) fn gradient_noise(seed: u32, x: f32) -> f32 {
+    let cell = x.floor();
+    let i = cell as i32;
+    let t = x - cell;
+    let fade = t * t * t * (t * (t * 6.0 - 15.0) + 10.0);
+
+    let a = (random(seed, i) * 2.0 - 1.0) * t;
+    let b = (random(seed, i + 1) * 2.0 - 1.0) * (t - 1.0);
+
+    // The raw noise is within `[-0.5, 0.5]`.
+    2.0 * (a + (b - a) * fade)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Lerper whose output is perturbed by small, seeded noise, giving motion a
+/// hand-drawn or organic feel.
+///
+/// The noise fades out towards _t = 0_ and _t = 1_, so the endpoints of the
+/// wrapped lerper are preserved.
+pub struct Jittered<L> {
+    lerper: L,
+    /// Maximum displacement of the output.
+    pub amplitude: f32,
+    /// Number of noise cycles over the range of _t_.
+    pub frequency: f32,
+    /// Seed of the noise. The same seed always gives the same jitter.
+    pub seed: u32,
+}
+
+impl<L: Lerper> Jittered<L> {
+    /// Default number of noise cycles over the range of _t_.
+    pub const FREQUENCY: f32 = 8.0;
+
+    /// Wrap `lerper`, perturbing its output by up to `amplitude`.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Lerper;
+    ///
+    /// let shaky = soy::Jittered::new(soy::Linear, 0.05, 42);
+    ///
+    /// assert_eq!(shaky.calculate(0.0), 0.0);
+    /// assert_eq!(shaky.calculate(1.0), 1.0);
+    /// assert!((shaky.calculate(0.5) - 0.5).abs() <= 0.05);
+    /// ```
+    pub fn new(lerper: L, amplitude: f32, seed: u32) -> Jittered<L> {
+        Jittered {
+            lerper,
+            amplitude,
+            frequency: Self::FREQUENCY,
+            seed,
+        }
+    }
+
+    /// Set the number of noise cycles over the range of _t_.
+    pub fn with_frequency(mut self, frequency: f32) -> Jittered<L> {
+        self.frequency = frequency;
+        self
+    }
+}
+
+impl<L: Hash> Hash for Jittered<L> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.lerper.hash(state);
+        hash_f32(self.amplitude, state);
+        hash_f32(self.frequency, state);
+        self.seed.hash(state);
+    }
+}
+
+impl<L: Lerper> Lerper for Jittered<L> {
+    fn calculate(&self, t: f32) -> f32 {
+        let envelope = (4.0 * t * (1.0 - t)).max(0.0);
+        let noise = value_noise(self.seed, t * self.frequency);
+
+        self.lerper.calculate(t) + self.amplitude * envelope * noise
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Procedural lerper driven by seeded, one-dimensional gradient noise.
+///
+/// The output wanders smoothly within `[0, 1]`, useful for flicker, sway and
+/// drift. Several octaves of noise can be layered for finer detail.
+pub struct GradientNoise {
+    /// Number of noise cycles over the range of _t_, for the first octave.
+    pub frequency: f32,
+    /// Number of layers of noise, each with double the frequency of the last.
+    pub octaves: u32,
+    /// Amplitude of each octave relative to the last.
+    pub persistence: f32,
+    /// Seed of the noise. The same seed always gives the same output.
+    pub seed: u32,
+}
+
+impl GradientNoise {
+    /// Create a new, single octave, noise lerper.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Lerper;
+    ///
+    /// let sway = soy::GradientNoise::new(4.0, 7).with_octaves(3, 0.5);
+    ///
+    /// for i in 0..=100 {
+    ///     let y = sway.calculate(i as f32 / 100.0);
+    ///     assert!(y >= 0.0 && y <= 1.0);
+    /// }
+    /// assert_eq!(sway.calculate(0.3), soy::GradientNoise::new(4.0, 7).with_octaves(3, 0.5).calculate(0.3));
+    /// ```
+    pub fn new(frequency: f32, seed: u32) -> GradientNoise {
+        GradientNoise {
+            frequency,
+            octaves: 1,
+            persistence: 0.5,
+            seed,
+        }
+    }
+
+    /// Set the number of octaves, and the amplitude of each octave relative to
+    /// the last.
+    pub fn with_octaves(mut self, octaves: u32, persistence: f32) -> GradientNoise {
+        self.octaves = octaves.max(1);
+        self.persistence = persistence;
+        self
+    }
+}
+
+impl Hash for GradientNoise {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f32(self.frequency, state);
+        self.octaves.hash(state);
+        hash_f32(self.persistence, state);
+        self.seed.hash(state);
+    }
+}
+
+impl Lerper for GradientNoise {
+    fn calculate(&self, t: f32) -> f32 {
+        let mut total = 0.0;
+        let mut amplitude = 1.0;
+        let mut max = 0.0;
+        let mut frequency = self.frequency;
+
+        for octave in 0..self.octaves {
+            let seed = self.seed.wrapping_add(octave);
+            total += amplitude * gradient_noise(seed, t * frequency);
+            max += amplitude;
+
+            amplitude *= self.persistence;
+            frequency *= 2.0;
+        }
+
+        let n = if max > 0.0 { total / max } else { 0.0 };
+        (0.5 + 0.5 * n).clamp(0.0, 1.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Lerper which jumps between `0.0` and `1.0`, holding each value for a
+/// moment, for broken-light and glitch effects.
+///
+/// The output is deterministic, so the same seed always replays the same
+/// flicker.
+pub struct Flicker {
+    /// Number of holds over the range of _t_.
+    pub frequency: f32,
+    /// Chance of each hold being `1.0`, rather than `0.0`.
+    pub duty: f32,
+    /// Seed of the flicker.
+    pub seed: u32,
+}
+
+impl Flicker {
+    /// Create a new flicker, with an even chance of each hold being on or
+    /// off.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Lerper;
+    ///
+    /// let light = soy::Flicker::new(20.0, 3).with_duty(0.8);
+    ///
+    /// let y = light.calculate(0.42);
+    /// assert!(y == 0.0 || y == 1.0);
+    /// // Values are held within each step.
+    /// assert_eq!(light.calculate(0.401), light.calculate(0.449));
+    /// ```
+    pub fn new(frequency: f32, seed: u32) -> Flicker {
+        Flicker {
+            frequency,
+            duty: 0.5,
+            seed,
+        }
+    }
+
+    /// Set the chance of each hold being `1.0`, rather than `0.0`.
+    pub fn with_duty(mut self, duty: f32) -> Flicker {
+        self.duty = duty;
+        self
+    }
+}
+
+impl Hash for Flicker {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f32(self.frequency, state);
+        hash_f32(self.duty, state);
+        self.seed.hash(state);
+    }
+}
+
+impl Lerper for Flicker {
+    fn calculate(&self, t: f32) -> f32 {
+        let step = (t * self.frequency).floor() as i32;
+
+        if random(self.seed, step) < self.duty {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Mul, Sub};
+
+use crate::hash::hash_f32;
+use crate::{
+    Anticipate, AnticipateOvershoot, Bezier, Bias, Clamped, EqualGain, EqualPower, Flicker, Gain,
+    GradientNoise, Jittered, Lerper, Lfo, Linear, Lut, Normalized, Overshoot, Penner, Remap,
+    Repeat, Reverse, Sigmoid, Smoothstep, Spring, Steps, Then,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Lerper adding the outputs of two lerpers.
+///
+/// This is usually created with the `+` operator.
+///
+/// # Usage
+/// ```
+/// use soy::{Lerper, Lfo, Waveform};
+///
+/// let wobble = Lfo::new(Waveform::Sine, 4.0) * 0.05;
+/// let wobbly = soy::EASE_OUT + wobble;
+///
+/// assert!((wobbly.calculate(0.5) - soy::EASE_OUT.calculate(0.5)).abs() <= 0.05);
+/// ```
+pub struct Sum<A, B> {
+    pub(crate) first: A,
+    pub(crate) second: B,
+}
+
+impl<A: Lerper, B: Lerper> Lerper for Sum<A, B> {
+    fn calculate(&self, t: f32) -> f32 {
+        self.first.calculate(t) + self.second.calculate(t)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Lerper subtracting the output of one lerper from another.
+///
+/// This is usually created with the `-` operator.
+pub struct Difference<A, B> {
+    pub(crate) first: A,
+    pub(crate) second: B,
+}
+
+impl<A: Lerper, B: Lerper> Lerper for Difference<A, B> {
+    fn calculate(&self, t: f32) -> f32 {
+        self.first.calculate(t) - self.second.calculate(t)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Lerper multiplying the outputs of two lerpers.
+///
+/// This is usually created with the `*` operator.
+///
+/// # Usage
+/// ```
+/// use soy::Lerper;
+///
+/// let double_ease = soy::EASE_IN * soy::EASE_IN;
+/// assert_eq!(double_ease.calculate(1.0), 1.0);
+/// ```
+pub struct Product<A, B> {
+    pub(crate) first: A,
+    pub(crate) second: B,
+}
+
+impl<A: Lerper, B: Lerper> Lerper for Product<A, B> {
+    fn calculate(&self, t: f32) -> f32 {
+        self.first.calculate(t) * self.second.calculate(t)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Lerper multiplying the output of another lerper by a constant.
+///
+/// This is usually created with the `*` operator.
+///
+/// # Usage
+/// ```
+/// use soy::Lerper;
+///
+/// let half = soy::Linear * 0.5;
+/// assert_eq!(half.calculate(1.0), 0.5);
+/// ```
+pub struct Scaled<L> {
+    pub(crate) lerper: L,
+    pub(crate) factor: f32,
+}
+
+impl<L: Hash> Hash for Scaled<L> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.lerper.hash(state);
+        hash_f32(self.factor, state);
+    }
+}
+
+impl<L: Lerper> Lerper for Scaled<L> {
+    fn calculate(&self, t: f32) -> f32 {
+        self.lerper.calculate(t) * self.factor
+    }
+}
+
+/// Implement the arithmetic operators for lerper types, so curves can be
+/// built algebraically.
+macro_rules! lerper_ops {
+    ($(<$($param:ident),*> $ty:ty),* $(,)?) => {
+        $(
+            impl<$($param,)* R: Lerper> Add<R> for $ty {
+                type Output = Sum<Self, R>;
+
+                fn add(self, other: R) -> Sum<Self, R> {
+                    Sum { first: self, second: other }
+                }
+            }
+
+            impl<$($param,)* R: Lerper> Sub<R> for $ty {
+                type Output = Difference<Self, R>;
+
+                fn sub(self, other: R) -> Difference<Self, R> {
+                    Difference { first: self, second: other }
+                }
+            }
+
+            impl<$($param,)* R: Lerper> Mul<R> for $ty {
+                type Output = Product<Self, R>;
+
+                fn mul(self, other: R) -> Product<Self, R> {
+                    Product { first: self, second: other }
+                }
+            }
+
+            impl<$($param),*> Mul<f32> for $ty {
+                type Output = Scaled<Self>;
+
+                fn mul(self, factor: f32) -> Scaled<Self> {
+                    Scaled { lerper: self, factor }
+                }
+            }
+        )*
+    };
+}
+
+lerper_ops!(
+    <> Anticipate,
+    <> AnticipateOvershoot,
+    <> Bezier,
+    <> Bias,
+    <> EqualGain,
+    <> EqualPower,
+    <> Flicker,
+    <> Gain,
+    <> GradientNoise,
+    <> Lfo,
+    <> Linear,
+    <> Lut,
+    <> Overshoot,
+    <> Penner,
+    <> Sigmoid,
+    <> Smoothstep,
+    <> Spring,
+    <> Steps,
+    <L> Clamped<L>,
+    <A, B> Difference<A, B>,
+    <L> Jittered<L>,
+    <L> Normalized<L>,
+    <A, B> Product<A, B>,
+    <A, B> Remap<A, B>,
+    <L> Repeat<L>,
+    <L> Reverse<L>,
+    <L> Scaled<L>,
+    <A, B> Sum<A, B>,
+    <A, B> Then<A, B>,
+);
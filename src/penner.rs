@@ -0,0 +1,216 @@
+use core::f32::consts::PI;
+
+use crate::Lerper;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Family of a [`Penner`][0] easing, as catalogued by
+/// [easings.net](https://easings.net).
+///
+/// [0]: struct.Penner.html
+pub enum EasingFamily {
+    /// Sinusoidal.
+    Sine,
+    /// Quadratic, _t²_.
+    Quad,
+    /// Cubic, _t³_.
+    Cubic,
+    /// Quartic, _t⁴_.
+    Quart,
+    /// Quintic, _t⁵_.
+    Quint,
+    /// Exponential, _2^(10t - 10)_.
+    Expo,
+    /// Circular.
+    Circ,
+    /// Pulls back before moving, overshooting _[0, 1]_.
+    Back,
+    /// Oscillates like a spring, overshooting _[0, 1]_.
+    Elastic,
+    /// Bounces like a dropped ball.
+    Bounce,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Direction of a [`Penner`][0] easing.
+///
+/// [0]: struct.Penner.html
+pub enum EasingMode {
+    /// Starts slowly.
+    In,
+    /// Ends slowly.
+    Out,
+    /// Starts and ends slowly.
+    InOut,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Robert Penner's easing functions, with the exact formulas from
+/// [easings.net](https://easings.net).
+///
+/// Every combination is available as a constant, such as
+/// [`EASE_OUT_BOUNCE`][0].
+///
+/// [0]: constant.EASE_OUT_BOUNCE.html
+pub struct Penner {
+    pub(crate) family: EasingFamily,
+    pub(crate) mode: EasingMode,
+}
+
+impl Penner {
+    /// Create a new easing of the given family and direction.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{EasingFamily, EasingMode, Lerper, Penner};
+    ///
+    /// let quad = Penner::new(EasingFamily::Quad, EasingMode::In);
+    /// assert_eq!(quad.calculate(0.5), 0.25);
+    /// assert_eq!(quad, soy::EASE_IN_QUAD);
+    /// ```
+    pub const fn new(family: EasingFamily, mode: EasingMode) -> Penner {
+        Penner { family, mode }
+    }
+
+    /// Family of the easing.
+    pub fn family(&self) -> EasingFamily {
+        self.family
+    }
+
+    /// Direction of the easing.
+    pub fn mode(&self) -> EasingMode {
+        self.mode
+    }
+
+    /// Name of the easing on [easings.net](https://easings.net), in kebab
+    /// case, such as `"ease-in-out-elastic"`.
+    pub fn name(&self) -> &'static str {
+        use EasingFamily::*;
+        use EasingMode::*;
+
+        match (self.mode, self.family) {
+            (In, Sine) => "ease-in-sine",
+            (In, Quad) => "ease-in-quad",
+            (In, Cubic) => "ease-in-cubic",
+            (In, Quart) => "ease-in-quart",
+            (In, Quint) => "ease-in-quint",
+            (In, Expo) => "ease-in-expo",
+            (In, Circ) => "ease-in-circ",
+            (In, Back) => "ease-in-back",
+            (In, Elastic) => "ease-in-elastic",
+            (In, Bounce) => "ease-in-bounce",
+            (Out, Sine) => "ease-out-sine",
+            (Out, Quad) => "ease-out-quad",
+            (Out, Cubic) => "ease-out-cubic",
+            (Out, Quart) => "ease-out-quart",
+            (Out, Quint) => "ease-out-quint",
+            (Out, Expo) => "ease-out-expo",
+            (Out, Circ) => "ease-out-circ",
+            (Out, Back) => "ease-out-back",
+            (Out, Elastic) => "ease-out-elastic",
+            (Out, Bounce) => "ease-out-bounce",
+            (InOut, Sine) => "ease-in-out-sine",
+            (InOut, Quad) => "ease-in-out-quad",
+            (InOut, Cubic) => "ease-in-out-cubic",
+            (InOut, Quart) => "ease-in-out-quart",
+            (InOut, Quint) => "ease-in-out-quint",
+            (InOut, Expo) => "ease-in-out-expo",
+            (InOut, Circ) => "ease-in-out-circ",
+            (InOut, Back) => "ease-in-out-back",
+            (InOut, Elastic) => "ease-in-out-elastic",
+            (InOut, Bounce) => "ease-in-out-bounce",
+        }
+    }
+}
+
+impl Lerper for Penner {
+    fn calculate(&self, t: f32) -> f32 {
+        match (self.mode, self.family) {
+            // These two use a different overshoot and period when easing in
+            // and out, so aren't built from the `In` variant.
+            (EasingMode::InOut, EasingFamily::Back) => in_out(t, |u| back_in(u, BACK * 1.525)),
+            (EasingMode::InOut, EasingFamily::Elastic) => elastic_in_out(t),
+            (EasingMode::In, family) => ease_in(family, t),
+            (EasingMode::Out, family) => 1.0 - ease_in(family, 1.0 - t),
+            (EasingMode::InOut, family) => in_out(t, |u| ease_in(family, u)),
+        }
+    }
+}
+
+/// Overshoot of the back easings.
+const BACK: f32 = 1.70158;
+
+fn ease_in(family: EasingFamily, t: f32) -> f32 {
+    match family {
+        EasingFamily::Sine => 1.0 - (t * PI / 2.0).cos(),
+        EasingFamily::Quad => t * t,
+        EasingFamily::Cubic => t * t * t,
+        EasingFamily::Quart => t * t * t * t,
+        EasingFamily::Quint => t * t * t * t * t,
+        EasingFamily::Expo => {
+            if t <= 0.0 {
+                0.0
+            } else {
+                2.0_f32.powf(10.0 * t - 10.0)
+            }
+        }
+        EasingFamily::Circ => 1.0 - (1.0 - t * t).max(0.0).sqrt(),
+        EasingFamily::Back => back_in(t, BACK),
+        EasingFamily::Elastic => {
+            if t <= 0.0 {
+                0.0
+            } else if t >= 1.0 {
+                1.0
+            } else {
+                let c4 = 2.0 * PI / 3.0;
+                -(2.0_f32.powf(10.0 * t - 10.0)) * ((t * 10.0 - 10.75) * c4).sin()
+            }
+        }
+        EasingFamily::Bounce => 1.0 - bounce_out(1.0 - t),
+    }
+}
+
+fn in_out(t: f32, ease_in: impl Fn(f32) -> f32) -> f32 {
+    if t < 0.5 {
+        ease_in(2.0 * t) / 2.0
+    } else {
+        1.0 - ease_in(2.0 - 2.0 * t) / 2.0
+    }
+}
+
+fn back_in(t: f32, overshoot: f32) -> f32 {
+    (overshoot + 1.0) * t * t * t - overshoot * t * t
+}
+
+fn elastic_in_out(t: f32) -> f32 {
+    let c5 = 2.0 * PI / 4.5;
+
+    if t <= 0.0 {
+        0.0
+    } else if t >= 1.0 {
+        1.0
+    } else if t < 0.5 {
+        -(2.0_f32.powf(20.0 * t - 10.0) * ((20.0 * t - 11.125) * c5).sin()) / 2.0
+    } else {
+        2.0_f32.powf(-20.0 * t + 10.0) * ((20.0 * t - 11.125) * c5).sin() / 2.0 + 1.0
+    }
+}
+
+fn bounce_out(t: f32) -> f32 {
+    let (n1, d1) = (7.5625, 2.75);
+
+    if t < 1.0 / d1 {
+        n1 * t * t
+    } else if t < 2.0 / d1 {
+        let t = t - 1.5 / d1;
+        n1 * t * t + 0.75
+    } else if t < 2.5 / d1 {
+        let t = t - 2.25 / d1;
+        n1 * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / d1;
+        n1 * t * t + 0.984375
+    }
+}
@@ -0,0 +1,198 @@
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::registry::builtin;
+use crate::{
+    Bezier, Bias, BoxedLerper, Clamped, Gain, Normalized, Product, Repeat, Reverse, Scaled,
+    Sigmoid, Smoothstep, Spring, Sum, Then,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// Plain data description of a composite easing, which is built into a
+/// boxed lerper with [`build`][0].
+///
+/// With the `serde` feature, pipelines can be loaded from any serde format,
+/// so compositions can be tweaked in a RON or JSON file without recompiling.
+/// For example, in JSON:
+///
+/// ```json
+/// { "then": { "first": { "named": "ease-in" }, "second": { "spring": [0.5, 0.3] }, "split": 0.4 } }
+/// ```
+///
+/// # Usage
+/// ```
+/// use soy::{Lerper, Pipeline};
+///
+/// let pipeline = Pipeline::Clamped(Box::new(Pipeline::Then {
+///     first: Box::new(Pipeline::Named(String::from("ease-in"))),
+///     second: Box::new(Pipeline::Spring(0.5, 0.3)),
+///     split: 0.4,
+/// }));
+///
+/// let lerper = pipeline.build().unwrap();
+/// assert_eq!(lerper.calculate(0.0), 0.0);
+/// assert_eq!(lerper.calculate(1.0), 1.0);
+/// ```
+///
+/// [0]: #method.build
+pub enum Pipeline {
+    /// Built-in easing, by its name in [`Registry::with_builtins`][0].
+    ///
+    /// [0]: struct.Registry.html#method.with_builtins
+    Named(String),
+    /// Cubic bezier, from its control points _x1, y1, x2, y2_.
+    CubicBezier(f32, f32, f32, f32),
+    /// Spring, from its damping ratio and response, like
+    /// [`Spring::with_response`][0].
+    ///
+    /// [0]: struct.Spring.html#method.with_response
+    Spring(f32, f32),
+    /// Logistic S-curve, from its steepness.
+    Sigmoid(f32),
+    /// Schlick bias.
+    Bias(f32),
+    /// Schlick gain.
+    Gain(f32),
+    /// Generalized smoothstep, from its order.
+    Smoothstep(u32),
+    /// Play `first` until `split`, then `second`.
+    Then {
+        /// Pipeline played before `split`.
+        first: Box<Pipeline>,
+        /// Pipeline played after `split`.
+        second: Box<Pipeline>,
+        /// _t_ at which to switch pipelines.
+        split: f32,
+    },
+    /// Play a pipeline `count` times over the range of _t_.
+    Repeat {
+        /// Pipeline to repeat.
+        lerper: Box<Pipeline>,
+        /// Number of repetitions.
+        count: u32,
+    },
+    /// Play a pipeline backwards.
+    Reverse(Box<Pipeline>),
+    /// Saturate the output of a pipeline to `[0, 1]`.
+    Clamped(Box<Pipeline>),
+    /// Rescale a pipeline to start at `0.0` and end at `1.0`.
+    Normalized(Box<Pipeline>),
+    /// Add the outputs of two pipelines.
+    Sum(Box<Pipeline>, Box<Pipeline>),
+    /// Multiply the outputs of two pipelines.
+    Product(Box<Pipeline>, Box<Pipeline>),
+    /// Multiply the output of a pipeline by a constant.
+    Scaled {
+        /// Pipeline to scale.
+        lerper: Box<Pipeline>,
+        /// Constant to multiply by.
+        factor: f32,
+    },
+}
+
+impl Pipeline {
+    /// Build the lerper described by this pipeline.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{Pipeline, PipelineError};
+    ///
+    /// let unknown = Pipeline::Named(String::from("ease-sideways"));
+    /// let undamped = Pipeline::Spring(0.0, 0.3);
+    ///
+    /// assert!(matches!(unknown.build(), Err(PipelineError::UnknownName(_))));
+    /// assert!(matches!(undamped.build(), Err(PipelineError::InvalidSpring(..))));
+    /// assert!(matches!(
+    ///     Pipeline::Smoothstep(u32::MAX).build(),
+    ///     Err(PipelineError::InvalidOrder(_))
+    /// ));
+    /// ```
+    pub fn build(&self) -> Result<BoxedLerper, PipelineError> {
+        let lerper: BoxedLerper = match self {
+            Pipeline::Named(name) => match builtin(name) {
+                Some(lerper) => Box::new(lerper),
+                None => return Err(PipelineError::UnknownName(name.clone())),
+            },
+            Pipeline::CubicBezier(x1, y1, x2, y2) => Box::new(Bezier::new(*x1, *y1, *x2, *y2)),
+            &Pipeline::Spring(damping_ratio, response) => {
+                // Also rejects NaN.
+                if !(damping_ratio > 0.0 && response > 0.0 && response.is_finite()) {
+                    return Err(PipelineError::InvalidSpring(damping_ratio, response));
+                }
+                Box::new(Spring::with_response(damping_ratio, response))
+            }
+            Pipeline::Sigmoid(steepness) => Box::new(Sigmoid::new(*steepness)),
+            Pipeline::Bias(bias) => Box::new(Bias(*bias)),
+            Pipeline::Gain(gain) => Box::new(Gain(*gain)),
+            &Pipeline::Smoothstep(order) => {
+                if order > Smoothstep::MAX_ORDER {
+                    return Err(PipelineError::InvalidOrder(order));
+                }
+                Box::new(Smoothstep::new(order))
+            }
+            Pipeline::Then {
+                first,
+                second,
+                split,
+            } => Box::new(Then::new(first.build()?, second.build()?, *split)),
+            Pipeline::Repeat { lerper, count } => Box::new(Repeat::new(lerper.build()?, *count)),
+            Pipeline::Reverse(lerper) => Box::new(Reverse::new(lerper.build()?)),
+            Pipeline::Clamped(lerper) => Box::new(Clamped::new(lerper.build()?)),
+            Pipeline::Normalized(lerper) => Box::new(Normalized::new(lerper.build()?)),
+            Pipeline::Sum(first, second) => Box::new(Sum {
+                first: first.build()?,
+                second: second.build()?,
+            }),
+            Pipeline::Product(first, second) => Box::new(Product {
+                first: first.build()?,
+                second: second.build()?,
+            }),
+            Pipeline::Scaled { lerper, factor } => Box::new(Scaled {
+                lerper: lerper.build()?,
+                factor: *factor,
+            }),
+        };
+
+        Ok(lerper)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Error building a [`Pipeline`][0].
+///
+/// [0]: enum.Pipeline.html
+pub enum PipelineError {
+    /// No built-in easing has the given name.
+    UnknownName(String),
+    /// A spring's damping ratio or response, given in that order, isn't
+    /// positive.
+    InvalidSpring(f32, f32),
+    /// A smoothstep's order is greater than
+    /// [`Smoothstep::MAX_ORDER`](struct.Smoothstep.html#associatedconstant.MAX_ORDER).
+    InvalidOrder(u32),
+}
+
+impl fmt::Display for PipelineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PipelineError::UnknownName(name) => write!(f, "unknown easing `{}`", name),
+            PipelineError::InvalidSpring(damping_ratio, response) => write!(
+                f,
+                "spring damping ratio {} and response {} must be positive",
+                damping_ratio, response
+            ),
+            PipelineError::InvalidOrder(order) => write!(
+                f,
+                "smoothstep order {} is greater than {}",
+                order,
+                Smoothstep::MAX_ORDER
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PipelineError {}
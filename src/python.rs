@@ -0,0 +1,125 @@
+//! Python bindings through [PyO3](https://pyo3.rs), enabled with the `python`
+//! feature.
+//!
+//! Build the extension module with [maturin](https://www.maturin.rs):
+//! ```text
+//! maturin develop --features extension-module
+//! ```
+//!
+//! maturin builds the crate as a `cdylib` itself, so it doesn't need to be
+//! listed in `Cargo.toml`.
+//!
+//! ```python
+//! import soy
+//!
+//! ease = soy.Easing.keyword("ease-in-out")
+//! overshoot = soy.Easing.cubic_bezier(0.3, 0.0, 0.5, 1.5)
+//!
+//! points = overshoot.then(ease.reverse(), 0.5).sample(100)
+//!
+//! (x1, y1, x2, y2), error = soy.fit_bezier(points)
+//! fitted = soy.Easing.cubic_bezier(x1, y1, x2, y2)
+//! ```
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::registry::builtin;
+use crate::{sample, Bezier, DynLerper, Lerper, Linear, Repeat, Reverse, Then};
+
+#[pyclass(name = "Easing", frozen)]
+/// Easing function, exported to Python.
+pub struct PyEasing {
+    lerper: DynLerper,
+}
+
+impl PyEasing {
+    fn from_lerper<L>(lerper: L) -> PyEasing
+    where
+        L: Lerper + Send + Sync + 'static,
+    {
+        PyEasing {
+            lerper: lerper.into_dyn(),
+        }
+    }
+}
+
+#[pymethods]
+impl PyEasing {
+    /// Linear easing.
+    #[staticmethod]
+    fn linear() -> PyEasing {
+        PyEasing::from_lerper(Linear)
+    }
+
+    /// Easing for a CSS keyword, such as `"ease-in-out"`, raising
+    /// `ValueError` if the keyword is unknown.
+    #[staticmethod]
+    fn keyword(name: &str) -> PyResult<PyEasing> {
+        match builtin(name) {
+            Some(lerper) => Ok(PyEasing::from_lerper(lerper)),
+            None => Err(PyValueError::new_err(format!("unknown easing `{}`", name))),
+        }
+    }
+
+    /// Easing for a CSS `cubic-bezier(x1, y1, x2, y2)` timing function.
+    #[staticmethod]
+    fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> PyEasing {
+        PyEasing::from_lerper(Bezier::new(x1, y1, x2, y2))
+    }
+
+    /// This easing until `split`, followed by `next`.
+    fn then(&self, next: &PyEasing, split: f32) -> PyEasing {
+        PyEasing::from_lerper(Then::new(self.lerper.clone(), next.lerper.clone(), split))
+    }
+
+    /// This easing played `count` times.
+    fn repeat(&self, count: u32) -> PyEasing {
+        PyEasing::from_lerper(Repeat::new(self.lerper.clone(), count))
+    }
+
+    /// This easing played backwards.
+    fn reverse(&self) -> PyEasing {
+        PyEasing::from_lerper(Reverse::new(self.lerper.clone()))
+    }
+
+    /// Calculate the eased progression at `t`.
+    fn calculate(&self, t: f32) -> f32 {
+        self.lerper.calculate(t)
+    }
+
+    fn __call__(&self, t: f32) -> f32 {
+        self.lerper.calculate(t)
+    }
+
+    /// Interpolate from `start` to `end`, eased at `t`.
+    fn lerp(&self, start: f32, end: f32, t: f32) -> f32 {
+        crate::lerp(&self.lerper, start, end, t)
+    }
+
+    /// Sample `count` evenly spaced `(t, y)` points of the easing.
+    fn sample(&self, count: usize) -> Vec<(f32, f32)> {
+        sample(&self.lerper, count).collect()
+    }
+}
+
+#[pyfunction]
+/// Fit a CSS-style cubic bezier to `(t, y)` samples, returning its control
+/// points, `(x1, y1, x2, y2)`, and the root mean square error of the fit.
+///
+/// Raises `ValueError` if `samples` is empty.
+fn fit_bezier(samples: Vec<(f32, f32)>) -> PyResult<((f32, f32, f32, f32), f32)> {
+    if samples.is_empty() {
+        return Err(PyValueError::new_err("no samples to fit"));
+    }
+
+    let fit = crate::fit_bezier(&samples);
+    let [x1, y1, x2, y2] = <[f32; 4]>::from(fit.bezier);
+    Ok(((x1, y1, x2, y2), fit.error))
+}
+
+#[pymodule]
+/// Python module entry point.
+fn soy(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyEasing>()?;
+    module.add_function(wrap_pyfunction!(fit_bezier, module)?)
+}
@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use crate::Lerper;
+
+/// Boxed lerper stored in a [`Registry`][0].
+///
+/// [0]: struct.Registry.html
+pub type BoxedLerper = Box<dyn Lerper + Send + Sync>;
+
+/// Every built-in easing, in the order listed by [`all_easings`][0].
+///
+/// [0]: fn.all_easings.html
+static BUILTINS: &[(&str, &(dyn Lerper + Send + Sync))] = &[
+    ("linear", &crate::Linear),
+    ("ease", &crate::EASE),
+    ("ease-in", &crate::EASE_IN),
+    ("ease-out", &crate::EASE_OUT),
+    ("ease-in-out", &crate::EASE_IN_OUT),
+    ("step-start", &crate::Steps::STEP_START),
+    ("step-end", &crate::Steps::STEP_END),
+    (
+        "anticipate",
+        &crate::Anticipate {
+            tension: crate::Anticipate::TENSION,
+        },
+    ),
+    (
+        "overshoot",
+        &crate::Overshoot {
+            tension: crate::Overshoot::TENSION,
+        },
+    ),
+    (
+        "anticipate-overshoot",
+        &crate::AnticipateOvershoot {
+            tension: crate::AnticipateOvershoot::TENSION,
+        },
+    ),
+    ("material-standard", &crate::MATERIAL_STANDARD),
+    (
+        "material-standard-decelerate",
+        &crate::MATERIAL_STANDARD_DECELERATE,
+    ),
+    (
+        "material-standard-accelerate",
+        &crate::MATERIAL_STANDARD_ACCELERATE,
+    ),
+    ("material-emphasized", &crate::MATERIAL_EMPHASIZED),
+    (
+        "material-emphasized-decelerate",
+        &crate::MATERIAL_EMPHASIZED_DECELERATE,
+    ),
+    (
+        "material-emphasized-accelerate",
+        &crate::MATERIAL_EMPHASIZED_ACCELERATE,
+    ),
+    ("material-legacy", &crate::MATERIAL_LEGACY),
+    (
+        "material-legacy-decelerate",
+        &crate::MATERIAL_LEGACY_DECELERATE,
+    ),
+    (
+        "material-legacy-accelerate",
+        &crate::MATERIAL_LEGACY_ACCELERATE,
+    ),
+    ("ease-in-sine", &crate::EASE_IN_SINE),
+    ("ease-in-quad", &crate::EASE_IN_QUAD),
+    ("ease-in-cubic", &crate::EASE_IN_CUBIC),
+    ("ease-in-quart", &crate::EASE_IN_QUART),
+    ("ease-in-quint", &crate::EASE_IN_QUINT),
+    ("ease-in-expo", &crate::EASE_IN_EXPO),
+    ("ease-in-circ", &crate::EASE_IN_CIRC),
+    ("ease-in-back", &crate::EASE_IN_BACK),
+    ("ease-in-elastic", &crate::EASE_IN_ELASTIC),
+    ("ease-in-bounce", &crate::EASE_IN_BOUNCE),
+    ("ease-out-sine", &crate::EASE_OUT_SINE),
+    ("ease-out-quad", &crate::EASE_OUT_QUAD),
+    ("ease-out-cubic", &crate::EASE_OUT_CUBIC),
+    ("ease-out-quart", &crate::EASE_OUT_QUART),
+    ("ease-out-quint", &crate::EASE_OUT_QUINT),
+    ("ease-out-expo", &crate::EASE_OUT_EXPO),
+    ("ease-out-circ", &crate::EASE_OUT_CIRC),
+    ("ease-out-back", &crate::EASE_OUT_BACK),
+    ("ease-out-elastic", &crate::EASE_OUT_ELASTIC),
+    ("ease-out-bounce", &crate::EASE_OUT_BOUNCE),
+    ("ease-in-out-sine", &crate::EASE_IN_OUT_SINE),
+    ("ease-in-out-quad", &crate::EASE_IN_OUT_QUAD),
+    ("ease-in-out-cubic", &crate::EASE_IN_OUT_CUBIC),
+    ("ease-in-out-quart", &crate::EASE_IN_OUT_QUART),
+    ("ease-in-out-quint", &crate::EASE_IN_OUT_QUINT),
+    ("ease-in-out-expo", &crate::EASE_IN_OUT_EXPO),
+    ("ease-in-out-circ", &crate::EASE_IN_OUT_CIRC),
+    ("ease-in-out-back", &crate::EASE_IN_OUT_BACK),
+    ("ease-in-out-elastic", &crate::EASE_IN_OUT_ELASTIC),
+    ("ease-in-out-bounce", &crate::EASE_IN_OUT_BOUNCE),
+];
+
+/// Iterate over every built-in easing, along with the name it's registered
+/// under in [`Registry::with_builtins`][0].
+///
+/// # Usage
+/// ```
+/// use soy::Lerper;
+///
+/// let names: Vec<&str> = soy::all_easings().map(|(name, _)| name).collect();
+/// assert!(names.contains(&"ease-in-out"));
+/// assert!(names.contains(&"ease-out-bounce"));
+///
+/// for (name, lerper) in soy::all_easings() {
+///     assert!((lerper.calculate(1.0) - 1.0).abs() < 1.0e-3, "{}", name);
+/// }
+/// ```
+///
+/// [0]: struct.Registry.html#method.with_builtins
+pub fn all_easings() -> impl Iterator<Item = (&'static str, &'static dyn Lerper)> {
+    BUILTINS
+        .iter()
+        .map(|&(name, lerper)| (name, lerper as &'static dyn Lerper))
+}
+
+/// Built-in easing registered under `name`, without building a registry.
+pub(crate) fn builtin(name: &str) -> Option<&'static (dyn Lerper + Send + Sync)> {
+    BUILTINS
+        .iter()
+        .find(|&&(builtin, _)| builtin == name)
+        .map(|&(_, lerper)| lerper)
+}
+
+/// Registry mapping names to lerpers, so data-driven configuration can refer
+/// to easings by name.
+pub struct Registry {
+    lerpers: HashMap<String, BoxedLerper>,
+}
+
+impl Registry {
+    /// Create a new, empty, registry.
+    pub fn new() -> Registry {
+        Registry {
+            lerpers: HashMap::new(),
+        }
+    }
+
+    /// Create a new registry containing all of the built-in easings, named
+    /// after their CSS keywords, such as `"ease-in-out"`, or their
+    /// easings.net names, such as `"ease-out-bounce"`. Material Design's
+    /// easings are prefixed, such as `"material-emphasized"`.
+    ///
+    /// # Usage
+    /// ```
+    /// let registry = soy::Registry::with_builtins();
+    ///
+    /// let ease = registry.get("ease-in-out").unwrap();
+    /// assert_eq!(soy::lerp(ease, 0.0, 10.0, 1.0), 10.0);
+    /// ```
+    pub fn with_builtins() -> Registry {
+        let mut registry = Registry::new();
+
+        for &(name, lerper) in BUILTINS {
+            registry.register(name, lerper);
+        }
+
+        registry
+    }
+
+    /// Register a lerper under `name`, returning the lerper it replaced, if
+    /// any.
+    ///
+    /// # Usage
+    /// ```
+    /// let mut registry = soy::Registry::with_builtins();
+    /// registry.register("snappy", soy::cubic_bezier(0.2, 0.0, 0.0, 1.0));
+    ///
+    /// assert!(registry.get("snappy").is_some());
+    /// ```
+    pub fn register<L>(&mut self, name: impl Into<String>, lerper: L) -> Option<BoxedLerper>
+    where
+        L: Lerper + Send + Sync + 'static,
+    {
+        self.lerpers.insert(name.into(), Box::new(lerper))
+    }
+
+    /// Remove the lerper registered under `name`, returning it, if any.
+    pub fn remove(&mut self, name: &str) -> Option<BoxedLerper> {
+        self.lerpers.remove(name)
+    }
+
+    /// Look up the lerper registered under `name`.
+    pub fn get(&self, name: &str) -> Option<&(dyn Lerper + Send + Sync)> {
+        self.lerpers.get(name).map(|lerper| lerper.as_ref())
+    }
+
+    /// Whether a lerper is registered under `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.lerpers.contains_key(name)
+    }
+
+    /// Names of all registered lerpers, in no particular order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.lerpers.keys().map(String::as_str)
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Registry {
+        Registry::new()
+    }
+}
@@ -0,0 +1,99 @@
+use core::ops::{Add, Mul, Sub};
+
+use crate::{lerp_unclamped, CatmullRom, Linear, Spline};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Interpolation kernel used by [`resample`][0].
+///
+/// [0]: fn.resample.html
+pub enum Kernel {
+    /// Use the closest source sample.
+    Nearest,
+    /// Linearly interpolate between the two closest source samples.
+    Linear,
+    /// Interpolate with a Catmull-Rom spline through the source samples.
+    Cubic,
+}
+
+/// Resample `samples` to `len` evenly spaced samples, using the given
+/// kernel.
+///
+/// The first and last samples are preserved.
+///
+/// # Usage
+/// ```
+/// use soy::Kernel;
+///
+/// let keys = [0.0, 10.0, 20.0];
+///
+/// assert_eq!(soy::resample(&keys, 5, Kernel::Linear), vec![0.0, 5.0, 10.0, 15.0, 20.0]);
+/// assert_eq!(soy::resample(&keys, 5, Kernel::Nearest), vec![0.0, 10.0, 10.0, 20.0, 20.0]);
+/// ```
+pub fn resample<T>(samples: &[T], len: usize, kernel: Kernel) -> Vec<T>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    let mut output = Vec::with_capacity(len);
+    if samples.is_empty() || len == 0 {
+        return output;
+    }
+
+    output.resize(len, samples[0]);
+    resample_into(samples, &mut output, kernel);
+    output
+}
+
+/// Resample `samples` to fill `output` with evenly spaced samples, using the
+/// given kernel.
+///
+/// # Panics
+/// Panics if `samples` is empty and `output` isn't.
+pub fn resample_into<T>(samples: &[T], output: &mut [T], kernel: Kernel)
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    if output.is_empty() {
+        return;
+    }
+    assert!(
+        !samples.is_empty(),
+        "resampling requires at least one sample"
+    );
+
+    let last = samples.len() - 1;
+    let scale = if output.len() > 1 {
+        last as f32 / (output.len() - 1) as f32
+    } else {
+        0.0
+    };
+
+    match kernel {
+        Kernel::Nearest => {
+            for (i, value) in output.iter_mut().enumerate() {
+                let index = (i as f32 * scale).round() as usize;
+                *value = samples[index.min(last)];
+            }
+        }
+        Kernel::Linear => {
+            for (i, value) in output.iter_mut().enumerate() {
+                let position = i as f32 * scale;
+                let index = (position as usize).min(last);
+                let next = (index + 1).min(last);
+
+                *value = lerp_unclamped(
+                    Linear,
+                    samples[index],
+                    samples[next],
+                    position - index as f32,
+                );
+            }
+        }
+        Kernel::Cubic => CatmullRom::new(samples.to_vec()).bake_into(output),
+    }
+}
@@ -0,0 +1,64 @@
+/// Constant used by iOS scroll views when rubber-banding.
+pub const RUBBER_BAND_COEFFICIENT: f32 = 0.55;
+
+/// iOS-style rubber-banding of an overscroll `offset`.
+///
+/// The displayed displacement grows ever slower as the offset increases,
+/// approaching, but never reaching, `dimension` (usually the size of the
+/// scroll view along the dragged axis).
+///
+/// # Usage
+/// ```
+/// let shown = soy::rubber_band(100.0, 500.0);
+/// assert!(shown > 0.0 && shown < 100.0);
+///
+/// // Negative offsets are rubber-banded symmetrically.
+/// assert_eq!(soy::rubber_band(-100.0, 500.0), -shown);
+/// ```
+pub fn rubber_band(offset: f32, dimension: f32) -> f32 {
+    let c = RUBBER_BAND_COEFFICIENT;
+    let x = offset.abs();
+
+    let banded = (1.0 - 1.0 / (x * c / dimension + 1.0)) * dimension;
+    banded.copysign(offset)
+}
+
+/// Inverse of [`rubber_band`][0], converting a displayed displacement back to
+/// the overscroll offset that produced it.
+///
+/// # Usage
+/// ```
+/// let shown = soy::rubber_band(100.0, 500.0);
+/// let offset = soy::rubber_band_inverse(shown, 500.0);
+///
+/// assert!((offset - 100.0).abs() < 1.0e-3);
+/// ```
+///
+/// [0]: fn.rubber_band.html
+pub fn rubber_band_inverse(displacement: f32, dimension: f32) -> f32 {
+    let c = RUBBER_BAND_COEFFICIENT;
+    let y = displacement.abs().min(dimension);
+
+    let offset = dimension * y / (c * (dimension - y));
+    offset.copysign(displacement)
+}
+
+/// Restrict `value` to `[min, max]`, rubber-banding any amount past either
+/// boundary.
+///
+/// # Usage
+/// ```
+/// assert_eq!(soy::rubber_band_clamp(50.0, 0.0, 100.0, 500.0), 50.0);
+///
+/// let past = soy::rubber_band_clamp(150.0, 0.0, 100.0, 500.0);
+/// assert!(past > 100.0 && past < 150.0);
+/// ```
+pub fn rubber_band_clamp(value: f32, min: f32, max: f32, dimension: f32) -> f32 {
+    if value < min {
+        min + rubber_band(value - min, dimension)
+    } else if value > max {
+        max + rubber_band(value - max, dimension)
+    } else {
+        value
+    }
+}
@@ -0,0 +1,83 @@
+use core::ops::{Add, Mul, Sub};
+
+use crate::spline::bake_t;
+use crate::Lerper;
+
+/// Sample `count` evenly spaced _(t, y)_ points of a lerper, from _t = 0_ to
+/// _t = 1_ inclusive.
+///
+/// # Usage
+/// ```
+/// let points: Vec<_> = soy::sample(soy::Linear, 3).collect();
+/// assert_eq!(points, vec![(0.0, 0.0), (0.5, 0.5), (1.0, 1.0)]);
+/// ```
+pub fn sample<L: Lerper>(lerper: L, count: usize) -> impl Iterator<Item = (f32, f32)> {
+    (0..count).map(move |i| {
+        let t = bake_t(i, count);
+        (t, lerper.calculate(t))
+    })
+}
+
+#[derive(Debug, Clone)]
+/// Iterator over evenly spaced, eased, samples of a lerper, from _t = 0_ to
+/// _t = 1_ inclusive.
+///
+/// This is usually created using [`Lerper::eased_steps`][0].
+///
+/// [0]: trait.Lerper.html#method.eased_steps
+pub struct EasedSteps<L> {
+    lerper: L,
+    index: usize,
+    count: usize,
+}
+
+impl<L: Lerper> EasedSteps<L> {
+    /// Sample `lerper` `count` times.
+    pub fn new(lerper: L, count: usize) -> EasedSteps<L> {
+        EasedSteps {
+            lerper,
+            index: 0,
+            count,
+        }
+    }
+}
+
+impl<L: Lerper> Iterator for EasedSteps<L> {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        if self.index >= self.count {
+            return None;
+        }
+
+        let t = bake_t(self.index, self.count);
+        self.index += 1;
+        Some(self.lerper.calculate(t))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.count - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<L: Lerper> ExactSizeIterator for EasedSteps<L> {}
+
+/// Interpolate `count` evenly spaced values from `start` to `end` inclusive,
+/// eased by `lerper`.
+///
+/// # Usage
+/// ```
+/// let ramp: Vec<f32> = soy::lerp_iter(soy::Linear, 10.0, 20.0, 5).collect();
+/// assert_eq!(ramp, vec![10.0, 12.5, 15.0, 17.5, 20.0]);
+/// ```
+pub fn lerp_iter<L, D>(lerper: L, start: D, end: D, count: usize) -> impl Iterator<Item = D>
+where
+    L: Lerper,
+    D: Copy,
+    D: Add<Output = D>,
+    D: Sub<Output = D>,
+    D: Mul<f32, Output = D>,
+{
+    EasedSteps::new(lerper, count).map(move |y| start + (end - start) * y)
+}
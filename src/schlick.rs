@@ -0,0 +1,78 @@
+use core::hash::{Hash, Hasher};
+
+use crate::hash::hash_f32;
+use crate::Lerper;
+
+/// Schlick's bias function.
+fn bias(t: f32, a: f32) -> f32 {
+    t / ((1.0 / a - 2.0) * (1.0 - t) + 1.0)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Schlick's fast approximation of Perlin's bias curve.
+///
+/// The bias, within `(0, 1)`, skews the curve: `0.5` is linear, smaller
+/// values ease in and larger values ease out. The curve always passes
+/// through _(0.5, bias)_.
+///
+/// # Usage
+/// ```
+/// use soy::Lerper;
+///
+/// let skewed = soy::Bias(0.8);
+///
+/// assert_eq!(skewed.calculate(0.0), 0.0);
+/// assert!((skewed.calculate(0.5) - 0.8).abs() < 1.0e-6);
+/// assert_eq!(skewed.calculate(1.0), 1.0);
+/// ```
+pub struct Bias(pub f32);
+
+impl Hash for Bias {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f32(self.0, state);
+    }
+}
+
+impl Lerper for Bias {
+    fn calculate(&self, t: f32) -> f32 {
+        bias(t, self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Schlick's fast approximation of Perlin's gain curve.
+///
+/// The gain, within `(0, 1)`, shapes the curve around its midpoint: `0.5` is
+/// linear, larger values steepen the middle into an ease in out, and smaller
+/// values flatten it. The curve always passes through _(0.25, (1 - gain) /
+/// 2)_.
+///
+/// # Usage
+/// ```
+/// use soy::Lerper;
+///
+/// let sharp = soy::Gain(0.8);
+///
+/// assert_eq!(sharp.calculate(0.5), 0.5);
+/// assert!((sharp.calculate(0.25) - 0.1).abs() < 1.0e-6);
+/// assert!((sharp.calculate(0.75) - 0.9).abs() < 1.0e-6);
+/// ```
+pub struct Gain(pub f32);
+
+impl Hash for Gain {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f32(self.0, state);
+    }
+}
+
+impl Lerper for Gain {
+    fn calculate(&self, t: f32) -> f32 {
+        if t < 0.5 {
+            0.5 * bias(2.0 * t, 1.0 - self.0)
+        } else {
+            1.0 - 0.5 * bias(2.0 - 2.0 * t, 1.0 - self.0)
+        }
+    }
+}
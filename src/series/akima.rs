@@ -0,0 +1,73 @@
+use super::{secants, PiecewiseCubic};
+
+#[derive(Debug)]
+/// Akima spline interpolation over `(x, y)` data.
+///
+/// Slopes are chosen from the neighbouring data only, so a single outlier
+/// only disturbs the curve locally, unlike a natural cubic spline.
+pub struct Akima {
+    curve: PiecewiseCubic,
+}
+
+impl Akima {
+    /// Create a new Akima interpolator over the given data.
+    ///
+    /// # Panics
+    /// Panics if `points` is empty, or the points are not sorted by strictly
+    /// increasing _x_.
+    ///
+    /// # Usage
+    /// ```
+    /// let points = [(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (3.0, 5.0), (4.0, 0.0), (5.0, 0.0), (6.0, 0.0)];
+    /// let sensor = soy::Akima::new(&points);
+    ///
+    /// assert_eq!(sensor.evaluate(3.0), 5.0);
+    /// // The outlier doesn't ripple out into the flat data.
+    /// assert_eq!(sensor.evaluate(0.5), 0.0);
+    /// assert_eq!(sensor.evaluate(5.5), 0.0);
+    /// ```
+    pub fn new(points: &[(f32, f32)]) -> Akima {
+        let (xs, ys) = PiecewiseCubic::split(points);
+        let m = secants(&xs, &ys);
+
+        let slopes = match m.len() {
+            0 => vec![0.0],
+            1 => vec![m[0]; 2],
+            n => {
+                // Extend the secants by two on either side.
+                let mut e = Vec::with_capacity(n + 4);
+                let before = 2.0 * m[0] - m[1];
+                e.push(2.0 * before - m[0]);
+                e.push(before);
+                e.extend_from_slice(&m);
+                let after = 2.0 * m[n - 1] - m[n - 2];
+                e.push(after);
+                e.push(2.0 * after - m[n - 1]);
+
+                (0..xs.len())
+                    .map(|i| {
+                        let (m0, m1, m2, m3) = (e[i], e[i + 1], e[i + 2], e[i + 3]);
+                        let w1 = (m3 - m2).abs();
+                        let w2 = (m1 - m0).abs();
+
+                        if w1 + w2 == 0.0 {
+                            (m1 + m2) / 2.0
+                        } else {
+                            (w1 * m1 + w2 * m2) / (w1 + w2)
+                        }
+                    })
+                    .collect()
+            }
+        };
+
+        Akima {
+            curve: PiecewiseCubic::new(xs, ys, slopes),
+        }
+    }
+
+    /// Interpolate the data at `x`, holding the first and last values outside
+    /// of the data range.
+    pub fn evaluate(&self, x: f32) -> f32 {
+        self.curve.evaluate(x)
+    }
+}
@@ -0,0 +1,83 @@
+mod akima;
+mod monotone;
+mod pchip;
+mod smoothing;
+
+pub use akima::Akima;
+pub use monotone::MonotoneCubic;
+pub use pchip::Pchip;
+pub use smoothing::SmoothingSpline;
+
+#[derive(Debug)]
+/// Piecewise cubic Hermite curve over `(x, y)` data, shared by the data-series
+/// interpolators.
+pub(crate) struct PiecewiseCubic {
+    xs: Vec<f32>,
+    ys: Vec<f32>,
+    slopes: Vec<f32>,
+}
+
+impl PiecewiseCubic {
+    /// Split sorted `(x, y)` points into their coordinates.
+    ///
+    /// # Panics
+    /// Panics if `points` is empty, or the points are not sorted by strictly
+    /// increasing _x_.
+    pub(crate) fn split(points: &[(f32, f32)]) -> (Vec<f32>, Vec<f32>) {
+        assert!(
+            !points.is_empty(),
+            "interpolation requires at least one point"
+        );
+        assert!(
+            points.windows(2).all(|w| w[0].0 < w[1].0),
+            "points must be sorted by strictly increasing x"
+        );
+
+        points.iter().copied().unzip()
+    }
+
+    pub(crate) fn new(xs: Vec<f32>, ys: Vec<f32>, slopes: Vec<f32>) -> PiecewiseCubic {
+        PiecewiseCubic { xs, ys, slopes }
+    }
+
+    /// Evaluate the curve at `x`, holding the first and last values outside of
+    /// the data range.
+    pub(crate) fn evaluate(&self, x: f32) -> f32 {
+        let last = self.xs.len() - 1;
+
+        if x <= self.xs[0] {
+            return self.ys[0];
+        }
+        if x >= self.xs[last] {
+            return self.ys[last];
+        }
+
+        // Index of the segment containing `x`.
+        let i = match self.xs.binary_search_by(|probe| probe.total_cmp(&x)) {
+            Ok(i) => return self.ys[i],
+            Err(i) => i - 1,
+        };
+
+        let h = self.xs[i + 1] - self.xs[i];
+        let s = (x - self.xs[i]) / h;
+        let (s2, s3) = (s * s, s * s * s);
+
+        let h00 = 2.0 * s3 - 3.0 * s2 + 1.0;
+        let h10 = s3 - 2.0 * s2 + s;
+        let h01 = 3.0 * s2 - 2.0 * s3;
+        let h11 = s3 - s2;
+
+        self.ys[i] * h00
+            + self.slopes[i] * h10 * h
+            + self.ys[i + 1] * h01
+            + self.slopes[i + 1] * h11 * h
+    }
+}
+
+/// Slopes of the straight lines between consecutive points.
+pub(crate) fn secants(xs: &[f32], ys: &[f32]) -> Vec<f32> {
+    xs.windows(2)
+        .zip(ys.windows(2))
+        .map(|(x, y)| (y[1] - y[0]) / (x[1] - x[0]))
+        .collect()
+}
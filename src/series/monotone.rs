@@ -0,0 +1,71 @@
+use super::{secants, PiecewiseCubic};
+
+#[derive(Debug)]
+/// Monotone cubic interpolation over `(x, y)` data, using the Fritsch-Carlson
+/// method.
+///
+/// The interpolated curve never overshoots the data: between any two points,
+/// it stays within their _y_ values.
+pub struct MonotoneCubic {
+    curve: PiecewiseCubic,
+}
+
+impl MonotoneCubic {
+    /// Create a new monotone interpolator over the given data.
+    ///
+    /// # Panics
+    /// Panics if `points` is empty, or the points are not sorted by strictly
+    /// increasing _x_.
+    ///
+    /// # Usage
+    /// ```
+    /// let battery = soy::MonotoneCubic::new(&[(0.0, 100.0), (1.0, 100.0), (2.0, 20.0), (3.0, 15.0)]);
+    ///
+    /// assert_eq!(battery.evaluate(0.0), 100.0);
+    /// assert!(battery.evaluate(0.5) <= 100.0);
+    /// assert!(battery.evaluate(2.5) >= 15.0 && battery.evaluate(2.5) <= 20.0);
+    /// ```
+    pub fn new(points: &[(f32, f32)]) -> MonotoneCubic {
+        let (xs, ys) = PiecewiseCubic::split(points);
+        let deltas = secants(&xs, &ys);
+        let mut slopes = vec![0.0; xs.len()];
+
+        if !deltas.is_empty() {
+            slopes[0] = deltas[0];
+            slopes[xs.len() - 1] = deltas[deltas.len() - 1];
+        }
+        for i in 1..deltas.len() {
+            let (a, b) = (deltas[i - 1], deltas[i]);
+            slopes[i] = if a * b > 0.0 { (a + b) / 2.0 } else { 0.0 };
+        }
+
+        // Restrict the slopes so each segment stays monotone.
+        for (i, &delta) in deltas.iter().enumerate() {
+            if delta == 0.0 {
+                slopes[i] = 0.0;
+                slopes[i + 1] = 0.0;
+                continue;
+            }
+
+            let a = slopes[i] / delta;
+            let b = slopes[i + 1] / delta;
+            let magnitude = a * a + b * b;
+
+            if magnitude > 9.0 {
+                let tau = 3.0 / magnitude.sqrt();
+                slopes[i] = tau * a * delta;
+                slopes[i + 1] = tau * b * delta;
+            }
+        }
+
+        MonotoneCubic {
+            curve: PiecewiseCubic::new(xs, ys, slopes),
+        }
+    }
+
+    /// Interpolate the data at `x`, holding the first and last values outside
+    /// of the data range.
+    pub fn evaluate(&self, x: f32) -> f32 {
+        self.curve.evaluate(x)
+    }
+}
@@ -0,0 +1,83 @@
+use super::{secants, PiecewiseCubic};
+
+#[derive(Debug)]
+/// Piecewise cubic Hermite interpolating polynomial (PCHIP) over irregularly
+/// spaced `(x, y)` data.
+///
+/// Like [`MonotoneCubic`][0], the curve preserves the shape of the data and
+/// never overshoots it, but slopes are chosen with a weighted harmonic mean
+/// that accounts for uneven spacing between samples.
+///
+/// [0]: struct.MonotoneCubic.html
+pub struct Pchip {
+    curve: PiecewiseCubic,
+}
+
+impl Pchip {
+    /// Create a new PCHIP interpolator over the given data.
+    ///
+    /// # Panics
+    /// Panics if `points` is empty, or the points are not sorted by strictly
+    /// increasing _x_.
+    ///
+    /// # Usage
+    /// ```
+    /// let log = soy::Pchip::new(&[(0.0, 1.0), (0.5, 3.0), (4.0, 4.0), (5.0, 0.0)]);
+    ///
+    /// assert_eq!(log.evaluate(0.5), 3.0);
+    /// assert!(log.evaluate(2.0) >= 3.0 && log.evaluate(2.0) <= 4.0);
+    /// ```
+    pub fn new(points: &[(f32, f32)]) -> Pchip {
+        let (xs, ys) = PiecewiseCubic::split(points);
+        let d = secants(&xs, &ys);
+        let h: Vec<f32> = xs.windows(2).map(|w| w[1] - w[0]).collect();
+        let n = xs.len();
+
+        let slopes = match d.len() {
+            0 => vec![0.0],
+            1 => vec![d[0]; 2],
+            _ => {
+                let mut slopes = vec![0.0; n];
+
+                for k in 1..n - 1 {
+                    let (a, b) = (d[k - 1], d[k]);
+                    if a * b <= 0.0 {
+                        continue;
+                    }
+
+                    let w1 = 2.0 * h[k] + h[k - 1];
+                    let w2 = h[k] + 2.0 * h[k - 1];
+                    slopes[k] = (w1 + w2) / (w1 / a + w2 / b);
+                }
+
+                slopes[0] = end_slope(h[0], h[1], d[0], d[1]);
+                slopes[n - 1] = end_slope(h[n - 2], h[n - 3], d[n - 2], d[n - 3]);
+                slopes
+            }
+        };
+
+        Pchip {
+            curve: PiecewiseCubic::new(xs, ys, slopes),
+        }
+    }
+
+    /// Interpolate the data at `x`, holding the first and last values outside
+    /// of the data range.
+    pub fn evaluate(&self, x: f32) -> f32 {
+        self.curve.evaluate(x)
+    }
+}
+
+/// Shape-preserving three-point estimate of the slope at an end point, where
+/// `h0` and `d0` belong to the segment touching the end point.
+fn end_slope(h0: f32, h1: f32, d0: f32, d1: f32) -> f32 {
+    let slope = ((2.0 * h0 + h1) * d0 - h0 * d1) / (h0 + h1);
+
+    if slope * d0 <= 0.0 {
+        0.0
+    } else if d0 * d1 < 0.0 && slope.abs() > 3.0 * d0.abs() {
+        3.0 * d0
+    } else {
+        slope
+    }
+}
@@ -0,0 +1,159 @@
+use super::PiecewiseCubic;
+
+#[derive(Debug)]
+/// Cubic smoothing spline, fitted to noisy `(x, y)` data by penalized least
+/// squares.
+///
+/// The curve balances staying close to the data against being smooth,
+/// minimizing the squared error plus `smoothing` times the integral of the
+/// squared second derivative. A smoothing of `0.0` passes through every
+/// point, and larger values approach a straight line of best fit. As the
+/// penalty is measured over _x_, suitable values depend on the spacing of the
+/// data.
+pub struct SmoothingSpline {
+    curve: PiecewiseCubic,
+}
+
+impl SmoothingSpline {
+    /// Fit a smoothing spline to the given data.
+    ///
+    /// # Panics
+    /// Panics if `points` is empty, the points are not sorted by strictly
+    /// increasing _x_, or `smoothing` is negative.
+    ///
+    /// # Usage
+    /// ```
+    /// // A straight line, with alternating noise.
+    /// let noisy: Vec<(f32, f32)> = (0..20)
+    ///     .map(|i| {
+    ///         let x = i as f32 / 19.0;
+    ///         let noise = if i % 2 == 0 { 0.05 } else { -0.05 };
+    ///         (x, x + noise)
+    ///     })
+    ///     .collect();
+    ///
+    /// let exact = soy::SmoothingSpline::new(&noisy, 0.0);
+    /// assert!((exact.evaluate(noisy[3].0) - noisy[3].1).abs() < 1.0e-5);
+    ///
+    /// let smooth = soy::SmoothingSpline::new(&noisy, 1.0e-3);
+    /// assert!((smooth.evaluate(0.5) - 0.5).abs() < 0.02);
+    /// ```
+    pub fn new(points: &[(f32, f32)], smoothing: f32) -> SmoothingSpline {
+        assert!(smoothing >= 0.0, "smoothing must not be negative");
+
+        let (xs, ys) = PiecewiseCubic::split(points);
+        let n = xs.len();
+
+        let (ys, slopes) = match n {
+            1 => (ys, vec![0.0]),
+            2 => {
+                let slope = (ys[1] - ys[0]) / (xs[1] - xs[0]);
+                (ys, vec![slope; 2])
+            }
+            _ => {
+                let (values, curvatures) = reinsch(&xs, &ys, f64::from(smoothing));
+                let h: Vec<f64> = xs.windows(2).map(|w| f64::from(w[1] - w[0])).collect();
+
+                // Slopes of the natural cubic spline through the fitted
+                // values.
+                let mut slopes: Vec<f32> = (0..n - 1)
+                    .map(|i| {
+                        let secant = (values[i + 1] - values[i]) / h[i];
+                        (secant - h[i] * (2.0 * curvatures[i] + curvatures[i + 1]) / 6.0) as f32
+                    })
+                    .collect();
+
+                let i = n - 2;
+                let secant = (values[i + 1] - values[i]) / h[i];
+                slopes
+                    .push((secant + h[i] * (curvatures[i] + 2.0 * curvatures[i + 1]) / 6.0) as f32);
+
+                (values.into_iter().map(|y| y as f32).collect(), slopes)
+            }
+        };
+
+        SmoothingSpline {
+            curve: PiecewiseCubic::new(xs, ys, slopes),
+        }
+    }
+
+    /// Evaluate the fitted curve at `x`, holding the first and last values
+    /// outside of the data range.
+    pub fn evaluate(&self, x: f32) -> f32 {
+        self.curve.evaluate(x)
+    }
+}
+
+/// Fit a natural cubic smoothing spline with Reinsch's algorithm, returning
+/// the fitted value and second derivative at each knot.
+///
+/// Requires at least three points.
+fn reinsch(xs: &[f32], ys: &[f32], smoothing: f64) -> (Vec<f64>, Vec<f64>) {
+    let n = xs.len();
+    let m = n - 2;
+    let h: Vec<f64> = xs.windows(2).map(|w| f64::from(w[1] - w[0])).collect();
+    let y: Vec<f64> = ys.iter().map(|&y| f64::from(y)).collect();
+
+    // Non-zero entries of each column of the second difference matrix `Q`, at
+    // rows `j`, `j + 1` and `j + 2`.
+    let q: Vec<[f64; 3]> = (0..m)
+        .map(|j| [1.0 / h[j], -1.0 / h[j] - 1.0 / h[j + 1], 1.0 / h[j + 1]])
+        .collect();
+
+    // Banded `R + λQᵀQ`, where `band[j][2 + k - j]` holds entry `(j, k)`.
+    let mut band = vec![[0.0; 5]; m];
+    let mut rhs = vec![0.0; m];
+
+    for j in 0..m {
+        band[j][2] = (h[j] + h[j + 1]) / 3.0
+            + smoothing * (q[j][0] * q[j][0] + q[j][1] * q[j][1] + q[j][2] * q[j][2]);
+
+        if j + 1 < m {
+            let entry =
+                h[j + 1] / 6.0 + smoothing * (q[j][1] * q[j + 1][0] + q[j][2] * q[j + 1][1]);
+            band[j][3] = entry;
+            band[j + 1][1] = entry;
+        }
+        if j + 2 < m {
+            let entry = smoothing * q[j][2] * q[j + 2][0];
+            band[j][4] = entry;
+            band[j + 2][0] = entry;
+        }
+
+        rhs[j] = q[j][0] * y[j] + q[j][1] * y[j + 1] + q[j][2] * y[j + 2];
+    }
+
+    // The system is symmetric positive definite, so eliminate without
+    // pivoting.
+    for k in 0..m {
+        for i in k + 1..m.min(k + 3) {
+            let factor = band[i][2 + k - i] / band[k][2];
+
+            for c in k..m.min(k + 3) {
+                band[i][2 + c - i] -= factor * band[k][2 + c - k];
+            }
+            rhs[i] -= factor * rhs[k];
+        }
+    }
+
+    let mut gamma = vec![0.0; m];
+    for k in (0..m).rev() {
+        let mut sum = rhs[k];
+        for c in k + 1..m.min(k + 3) {
+            sum -= band[k][2 + c - k] * gamma[c];
+        }
+        gamma[k] = sum / band[k][2];
+    }
+
+    let mut values = y;
+    for j in 0..m {
+        for (r, &entry) in q[j].iter().enumerate() {
+            values[j + r] -= smoothing * entry * gamma[j];
+        }
+    }
+
+    let mut curvatures = vec![0.0; n];
+    curvatures[1..n - 1].copy_from_slice(&gamma);
+
+    (values, curvatures)
+}
@@ -0,0 +1,96 @@
+use crate::noise::gradient_noise;
+use crate::Lerper;
+
+#[derive(Debug)]
+/// Trauma based screen-shake.
+///
+/// Events add "trauma", in `[0, 1]`, which decays linearly over time. The
+/// trauma is shaped by a lerper into the shake intensity, which scales smooth
+/// noise-driven positional and rotational offsets.
+pub struct Shake<L> {
+    shaping: L,
+    trauma: f32,
+    time: f32,
+    /// Trauma removed per second.
+    pub decay: f32,
+    /// Maximum positional offset along each axis, at full intensity.
+    pub max_offset: f32,
+    /// Maximum rotational offset, in radians, at full intensity.
+    pub max_angle: f32,
+    /// Number of noise cycles per second.
+    pub frequency: f32,
+    /// Seed of the noise.
+    pub seed: u32,
+}
+
+impl<L: Lerper> Shake<L> {
+    /// Create a new shake, with trauma shaped into intensity by `shaping`.
+    ///
+    /// # Usage
+    /// ```
+    /// let mut shake = soy::Shake::new(soy::EASE_IN);
+    /// assert_eq!(shake.offset(), (0.0, 0.0));
+    ///
+    /// shake.add_trauma(0.5);
+    /// shake.update(0.1);
+    /// assert!(shake.intensity() > 0.0);
+    ///
+    /// // Trauma decays back to rest.
+    /// shake.update(1.0);
+    /// assert_eq!(shake.trauma(), 0.0);
+    /// assert_eq!(shake.rotation(), 0.0);
+    /// ```
+    pub fn new(shaping: L) -> Shake<L> {
+        Shake {
+            shaping,
+            trauma: 0.0,
+            time: 0.0,
+            decay: 1.0,
+            max_offset: 1.0,
+            max_angle: 0.1,
+            frequency: 15.0,
+            seed: 0,
+        }
+    }
+
+    /// Add trauma, saturating at `1.0`.
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).clamp(0.0, 1.0);
+    }
+
+    /// Current trauma.
+    pub fn trauma(&self) -> f32 {
+        self.trauma
+    }
+
+    /// Advance the shake by `dt` seconds, decaying the trauma.
+    pub fn update(&mut self, dt: f32) {
+        self.time += dt;
+        self.trauma = (self.trauma - self.decay * dt).max(0.0);
+    }
+
+    /// Current shake intensity, which is the shaped trauma.
+    pub fn intensity(&self) -> f32 {
+        if self.trauma <= 0.0 {
+            return 0.0;
+        }
+        self.shaping.calculate(self.trauma)
+    }
+
+    /// Current positional offset along each axis.
+    pub fn offset(&self) -> (f32, f32) {
+        let scale = self.intensity() * self.max_offset;
+
+        (scale * self.noise(1), scale * self.noise(2))
+    }
+
+    /// Current rotational offset, in radians.
+    pub fn rotation(&self) -> f32 {
+        self.intensity() * self.max_angle * self.noise(0)
+    }
+
+    fn noise(&self, channel: u32) -> f32 {
+        let seed = self.seed.wrapping_mul(3).wrapping_add(channel);
+        gradient_noise(seed, self.time * self.frequency).clamp(-1.0, 1.0)
+    }
+}
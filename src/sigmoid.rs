@@ -0,0 +1,57 @@
+use core::hash::{Hash, Hasher};
+
+use crate::hash::hash_f32;
+use crate::Lerper;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Logistic S-curve, normalized to pass exactly through _(0, 0)_ and
+/// _(1, 1)_.
+///
+/// The steepness is the slope of the underlying logistic function at the
+/// midpoint, over the range of _t_. Steepness near `0.0` is linear, and
+/// larger values sharpen the curve into a step.
+///
+/// # Usage
+/// ```
+/// use soy::Lerper;
+///
+/// let s = soy::Sigmoid::new(10.0);
+///
+/// assert_eq!(s.calculate(0.0), 0.0);
+/// assert_eq!(s.calculate(0.5), 0.5);
+/// assert_eq!(s.calculate(1.0), 1.0);
+/// assert!(s.calculate(0.25) < soy::Sigmoid::new(2.0).calculate(0.25));
+/// ```
+pub struct Sigmoid {
+    /// Sharpness of the curve.
+    pub steepness: f32,
+}
+
+impl Sigmoid {
+    /// Create a new sigmoid with the given steepness.
+    pub fn new(steepness: f32) -> Sigmoid {
+        Sigmoid { steepness }
+    }
+}
+
+impl Hash for Sigmoid {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f32(self.steepness, state);
+    }
+}
+
+impl Lerper for Sigmoid {
+    fn calculate(&self, t: f32) -> f32 {
+        let k = self.steepness;
+
+        if k == 0.0 {
+            return t;
+        }
+
+        // The logistic function is `0.5 + 0.5 tanh(x / 2)`, and `tanh` keeps
+        // its precision for shallow curves, where the logistic function's
+        // range would almost cancel out.
+        0.5 + 0.5 * (0.5 * k * (t - 0.5)).tanh() / (0.25 * k).tanh()
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{sample, Lerper};
+
+/// Simplify sorted _(t, y)_ points with the Ramer-Douglas-Peucker algorithm,
+/// keeping as few points as possible while the straight lines between kept
+/// points stay within `tolerance` of every original point.
+///
+/// Distance is measured along _y_, so the error in the animated value is
+/// bounded, whatever the spacing of _t_. The first and last points are always
+/// kept.
+///
+/// # Usage
+/// ```
+/// let points = [(0.0, 0.0), (0.25, 0.25), (0.5, 0.5), (0.75, 0.8), (1.0, 1.0)];
+///
+/// assert_eq!(
+///     soy::simplify(&points, 0.1),
+///     vec![(0.0, 0.0), (1.0, 1.0)],
+/// );
+/// assert_eq!(
+///     soy::simplify(&points, 0.01),
+///     vec![(0.0, 0.0), (0.5, 0.5), (0.75, 0.8), (1.0, 1.0)],
+/// );
+/// ```
+pub fn simplify(points: &[(f32, f32)], tolerance: f32) -> Vec<(f32, f32)> {
+    if points.len() <= 2 {
+        return points.to_vec();
+    }
+
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    let mut ranges = vec![(0, points.len() - 1)];
+    while let Some((first, last)) = ranges.pop() {
+        let (a, b) = (points[first], points[last]);
+
+        let error = |&(t, y): &(f32, f32)| {
+            let line = if b.0 > a.0 {
+                a.1 + (b.1 - a.1) * (t - a.0) / (b.0 - a.0)
+            } else {
+                a.1
+            };
+            (y - line).abs()
+        };
+
+        let farthest =
+            (first + 1..last)
+                .map(|i| (i, error(&points[i])))
+                .fold(
+                    (first, 0.0),
+                    |best, next| if next.1 > best.1 { next } else { best },
+                );
+
+        if farthest.1 > tolerance {
+            keep[farthest.0] = true;
+            ranges.push((first, farthest.0));
+            ranges.push((farthest.0, last));
+        }
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter(|(_, keep)| *keep)
+        .map(|(&point, _)| point)
+        .collect()
+}
+
+/// Sample a lerper `samples` times, then [`simplify`][0] the samples, giving
+/// a compact piecewise linear approximation of the curve.
+///
+/// # Usage
+/// ```
+/// use soy::Lerper;
+///
+/// let points = soy::simplify_lerper(soy::EASE_IN_OUT, 1000, 1.0e-3);
+/// assert!(points.len() < 50);
+///
+/// // Points can be stored, then rebuilt into a curve.
+/// let rebuilt = soy::MonotoneCubic::new(&points);
+/// assert!((rebuilt.evaluate(0.3) - soy::EASE_IN_OUT.calculate(0.3)).abs() < 1.0e-3);
+/// ```
+///
+/// [0]: fn.simplify.html
+pub fn simplify_lerper<L: Lerper>(lerper: L, samples: usize, tolerance: f32) -> Vec<(f32, f32)> {
+    let points: Vec<_> = sample(lerper, samples).collect();
+    simplify(&points, tolerance)
+}
@@ -0,0 +1,130 @@
+use core::ops::{Add, Mul, Sub};
+
+use crate::{lerp_unclamped, Linear};
+
+/// Frame-rate independent exponential smoothing of `current` towards `target`.
+///
+/// After `half_life` seconds, half of the distance to the target is covered,
+/// regardless of how many steps `dt` is split across.
+///
+/// # Arguments:
+/// - `current`: Current value.
+/// - `target`: Value to approach.
+/// - `half_life`: Time, in seconds, to cover half the remaining distance.
+/// - `dt`: Elapsed time, in seconds, since the last update.
+///
+/// # Usage
+/// ```
+/// // One step of 1 second...
+/// let once = soy::exp_smooth(0.0, 10.0, 0.5, 1.0);
+///
+/// // ...is the same as 60 steps of 1/60th of a second.
+/// let mut many = 0.0;
+/// for _ in 0..60 {
+///     many = soy::exp_smooth(many, 10.0, 0.5, 1.0 / 60.0);
+/// }
+///
+/// assert!((once - 7.5_f32).abs() < 1.0e-4);
+/// assert!((once - many).abs() < 1.0e-4);
+/// ```
+pub fn exp_smooth<D>(current: D, target: D, half_life: f32, dt: f32) -> D
+where
+    D: Copy,
+    D: Add<Output = D>,
+    D: Sub<Output = D>,
+    D: Mul<f32, Output = D>,
+{
+    if half_life <= 0.0 {
+        return target;
+    }
+
+    let t = 1.0 - (-dt / half_life).exp2();
+    lerp_unclamped(Linear, current, target, t)
+}
+
+/// Frame-rate independent exponential approach of `current` towards `target`,
+/// at a rate of `lambda` per second.
+///
+/// This is [`exp_smooth`][0] parameterized by decay rate rather than half
+/// life: each second, the remaining distance shrinks by a factor of
+/// _e^λ_. Higher rates follow more tightly.
+///
+/// # Arguments:
+/// - `current`: Current value.
+/// - `target`: Value to approach.
+/// - `lambda`: Rate of approach, per second.
+/// - `dt`: Elapsed time, in seconds, since the last update.
+///
+/// # Usage
+/// ```
+/// let mut x = 0.0;
+/// for _ in 0..60 {
+///     x = soy::damp(x, 10.0, 2.0, 1.0 / 60.0);
+/// }
+///
+/// let expected = 10.0 * (1.0 - (-2.0_f32).exp());
+/// assert!((x - expected).abs() < 1.0e-4);
+/// ```
+///
+/// [0]: fn.exp_smooth.html
+pub fn damp<D>(current: D, target: D, lambda: f32, dt: f32) -> D
+where
+    D: Copy,
+    D: Add<Output = D>,
+    D: Sub<Output = D>,
+    D: Mul<f32, Output = D>,
+{
+    let t = 1.0 - (-lambda * dt).exp();
+    lerp_unclamped(Linear, current, target, t)
+}
+
+#[derive(Debug)]
+/// Stateful wrapper around [`exp_smooth`][0].
+///
+/// [0]: fn.exp_smooth.html
+pub struct ExpSmoother<T> {
+    /// Time, in seconds, to cover half the remaining distance.
+    pub half_life: f32,
+    value: T,
+}
+
+impl<T> ExpSmoother<T>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    /// Create a new smoother, starting at `initial`.
+    ///
+    /// # Usage
+    /// ```
+    /// let mut smoother = soy::ExpSmoother::new(0.25, 0.0);
+    ///
+    /// smoother.update(0.25, 1.0);
+    /// assert!((smoother.value() - 0.5).abs() < 1.0e-6);
+    /// ```
+    pub fn new(half_life: f32, initial: T) -> ExpSmoother<T> {
+        ExpSmoother {
+            half_life,
+            value: initial,
+        }
+    }
+
+    /// Advance the smoother by `dt` seconds towards `target`, returning the
+    /// new value.
+    pub fn update(&mut self, dt: f32, target: T) -> T {
+        self.value = exp_smooth(self.value, target, self.half_life, dt);
+        self.value
+    }
+
+    /// Current value of the smoother.
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Jump straight to the given value.
+    pub fn reset(&mut self, value: T) {
+        self.value = value;
+    }
+}
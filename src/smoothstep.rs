@@ -0,0 +1,138 @@
+use core::hash::{Hash, Hasher};
+
+use crate::Lerper;
+
+/// Binomial coefficient _n choose k_.
+fn binomial(n: u32, k: u32) -> f64 {
+    (0..k).fold(1.0, |c, i| c * f64::from(n - i) / f64::from(i + 1))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Generalized smoothstep polynomial, whose first `order` derivatives are
+/// zero at both ends.
+///
+/// This is usually created using [`smoothstep_n`][0]. _t_ is clamped to
+/// `[0, 1]`, so the curve holds at either end.
+///
+/// [0]: fn.smoothstep_n.html
+pub struct Smoothstep {
+    order: u32,
+    /// Coefficient of each Bernstein basis polynomial of degree
+    /// _2·order + 1_ with a nonzero weight, starting from _k = order + 1_.
+    coefficients: Vec<f64>,
+}
+
+impl Smoothstep {
+    /// Highest supported order. The polynomial's coefficients overflow an
+    /// `f64` beyond it.
+    pub const MAX_ORDER: u32 = 500;
+
+    /// Create a new smoothstep polynomial of the given order.
+    ///
+    /// # Panics
+    /// Panics if `order` is greater than [`MAX_ORDER`][0].
+    ///
+    /// [0]: #associatedconstant.MAX_ORDER
+    pub fn new(order: u32) -> Smoothstep {
+        assert!(
+            order <= Smoothstep::MAX_ORDER,
+            "smoothstep order must be at most {}",
+            Smoothstep::MAX_ORDER
+        );
+
+        let degree = 2 * order + 1;
+
+        // The power basis coefficients alternate in sign and grow quickly,
+        // cancelling badly at higher orders, so sum the Bernstein terms
+        // instead, which are all positive.
+        let coefficients = (order + 1..=degree).map(|k| binomial(degree, k)).collect();
+
+        Smoothstep {
+            order,
+            coefficients,
+        }
+    }
+
+    /// Order of the polynomial, the number of derivatives which are zero at
+    /// both ends.
+    pub fn order(&self) -> u32 {
+        self.order
+    }
+}
+
+impl Hash for Smoothstep {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // The coefficients are decided by the order.
+        self.order.hash(state);
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Smoothstep {
+    /// Smoothstep polynomials are serialized as their order.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.order, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Smoothstep {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Smoothstep, D::Error> {
+        let order = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+        if order > Smoothstep::MAX_ORDER {
+            return Err(<D::Error as serde::de::Error>::custom(format_args!(
+                "smoothstep order must be at most {}",
+                Smoothstep::MAX_ORDER
+            )));
+        }
+        Ok(Smoothstep::new(order))
+    }
+}
+
+impl Lerper for Smoothstep {
+    fn calculate(&self, t: f32) -> f32 {
+        let t = f64::from(t.clamp(0.0, 1.0));
+        let s = 1.0 - t;
+        let degree = 2 * self.order as i32 + 1;
+
+        let sum: f64 = (self.order as i32 + 1..=degree)
+            .zip(&self.coefficients)
+            .map(|(k, &c)| c * t.powi(k) * s.powi(degree - k))
+            .sum();
+        sum as f32
+    }
+}
+
+/// Smoothstep polynomial of the given order, at most
+/// [`Smoothstep::MAX_ORDER`][0].
+///
+/// Order `0` is linear, `1` is the classic smoothstep, _3t² - 2t³_, and `2`
+/// is Perlin's smootherstep, _6t⁵ - 15t⁴ + 10t³_. Higher orders are
+/// continuous in more derivatives, useful for camera motion and audio ramps,
+/// at the cost of a steeper middle.
+///
+/// # Usage
+/// ```
+/// use soy::Lerper;
+///
+/// let smootherstep = soy::smoothstep_n(2);
+///
+/// let t: f32 = 0.3;
+/// let expected = t * t * t * (t * (t * 6.0 - 15.0) + 10.0);
+/// assert!((smootherstep.calculate(t) - expected).abs() < 1.0e-6);
+///
+/// assert_eq!(soy::smoothstep_n(5).calculate(0.5), 0.5);
+/// assert_eq!(soy::smoothstep_n(5).calculate(1.0), 1.0);
+///
+/// // High orders stay accurate, and symmetric about the middle.
+/// let steep = soy::smoothstep_n(12);
+/// assert_eq!(steep.calculate(1.0), 1.0);
+/// for &t in &[0.1, 0.25, 0.4] {
+///     assert!((steep.calculate(t) + steep.calculate(1.0 - t) - 1.0).abs() < 1.0e-6);
+/// }
+/// ```
+///
+/// [0]: struct.Smoothstep.html#associatedconstant.MAX_ORDER
+pub fn smoothstep_n(order: u32) -> Smoothstep {
+    Smoothstep::new(order)
+}
@@ -0,0 +1,95 @@
+use core::ops::Sub;
+
+use super::Spline;
+use crate::vector::{distance, Dot};
+
+#[derive(Debug)]
+/// Arc-length parameterized spline, which moves at constant speed as _t_
+/// increases linearly.
+///
+/// This is usually created using [`Spline::by_arc_length`][0].
+///
+/// [0]: trait.Spline.html#method.by_arc_length
+pub struct ArcLength<S> {
+    spline: S,
+    /// Cumulative length of the spline at evenly spaced values of _t_.
+    lengths: Vec<f32>,
+}
+
+impl<S> ArcLength<S> {
+    /// Default number of linear pieces used to approximate the spline.
+    pub const SAMPLES: usize = 256;
+
+    /// Reparameterize `spline` by arc-length, approximating it with `samples`
+    /// linear pieces.
+    ///
+    /// # Panics
+    /// Panics if `samples` is zero.
+    pub fn with_samples<T>(spline: S, samples: usize) -> ArcLength<S>
+    where
+        S: Spline<T>,
+        T: Copy + Dot + Sub<Output = T>,
+    {
+        assert!(samples > 0, "arc-length requires at least one sample");
+
+        let mut lengths = Vec::with_capacity(samples + 1);
+        let mut total = 0.0;
+        let mut previous = spline.sample(0.0);
+        lengths.push(total);
+
+        for i in 1..=samples {
+            let point = spline.sample(i as f32 / samples as f32);
+            total += distance(previous, point);
+            lengths.push(total);
+            previous = point;
+        }
+
+        ArcLength { spline, lengths }
+    }
+
+    /// Approximate total length of the spline.
+    pub fn length(&self) -> f32 {
+        self.lengths[self.lengths.len() - 1]
+    }
+
+    /// Underlying spline.
+    pub fn spline(&self) -> &S {
+        &self.spline
+    }
+
+    /// Unwrap the underlying spline.
+    pub fn into_inner(self) -> S {
+        self.spline
+    }
+
+    /// Convert a distance along the spline into the underlying spline's _t_.
+    pub fn parameter_at(&self, distance: f32) -> f32 {
+        let samples = self.lengths.len() - 1;
+        let distance = distance.max(0.0).min(self.length());
+
+        let i = match self.lengths.binary_search_by(|l| l.total_cmp(&distance)) {
+            Ok(i) => return i as f32 / samples as f32,
+            Err(i) => i.max(1) - 1,
+        };
+
+        let (start, end) = (self.lengths[i], self.lengths[i + 1]);
+        let local = if end > start {
+            (distance - start) / (end - start)
+        } else {
+            0.0
+        };
+
+        (i as f32 + local) / samples as f32
+    }
+}
+
+impl<T, S> Spline<T> for ArcLength<S>
+where
+    S: Spline<T>,
+{
+    /// Calculate the point on the spline at the given fraction of its total
+    /// length.
+    fn sample(&self, t: f32) -> T {
+        self.spline.sample(self.parameter_at(t * self.length()))
+    }
+}
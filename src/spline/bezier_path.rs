@@ -0,0 +1,268 @@
+use core::ops::{Add, Mul, Sub};
+
+use super::{segment, Spline};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Continuity enforced where two segments of a [`BezierPath`][0] join.
+///
+/// [0]: struct.BezierPath.html
+pub enum Continuity {
+    /// Segments only share their end points, allowing sharp corners.
+    Corner,
+    /// The first control point of each segment mirrors the last control point
+    /// of the previous segment, so the path's direction and speed are
+    /// continuous across the join.
+    Smooth,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Single cubic segment of a [`BezierPath`][0].
+///
+/// [0]: struct.BezierPath.html
+pub struct BezierSegment<T> {
+    /// Start point of the segment.
+    pub from: T,
+    /// First control point.
+    pub c1: T,
+    /// Second control point.
+    pub c2: T,
+    /// End point of the segment.
+    pub to: T,
+}
+
+impl<T> BezierSegment<T>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    /// Calculate the point on the segment at the given _t_.
+    pub fn sample(&self, t: f32) -> T {
+        let u = 1.0 - t;
+
+        self.from * (u * u * u)
+            + self.c1 * (3.0 * u * u * t)
+            + self.c2 * (3.0 * u * t * t)
+            + self.to * (t * t * t)
+    }
+
+    /// Calculate the rate of change of the segment with respect to _t_.
+    pub fn derivative(&self, t: f32) -> T {
+        let u = 1.0 - t;
+
+        (self.c1 - self.from) * (3.0 * u * u)
+            + (self.c2 - self.c1) * (6.0 * u * t)
+            + (self.to - self.c2) * (3.0 * t * t)
+    }
+
+    /// Calculate the second derivative of the segment with respect to _t_.
+    pub fn second_derivative(&self, t: f32) -> T {
+        let u = 1.0 - t;
+
+        (self.c2 - self.c1 * 2.0 + self.from) * (6.0 * u)
+            + (self.to - self.c2 * 2.0 + self.c1) * (6.0 * t)
+    }
+}
+
+#[derive(Debug)]
+/// Path of connected cubic bezier segments, like an SVG path.
+pub struct BezierPath<T> {
+    start: T,
+    segments: Vec<BezierSegment<T>>,
+    continuity: Continuity,
+    closed: bool,
+}
+
+impl<T> BezierPath<T>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    /// Create a new, empty, path starting at `start`.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Spline;
+    ///
+    /// let path = soy::BezierPath::new(0.0)
+    ///     .cubic_to(0.0, 5.0, 5.0)
+    ///     .line_to(8.0);
+    ///
+    /// assert_eq!(path.sample(0.0), 0.0);
+    /// assert_eq!(path.sample(0.5), 5.0);
+    /// assert_eq!(path.sample(1.0), 8.0);
+    /// ```
+    pub fn new(start: T) -> BezierPath<T> {
+        BezierPath {
+            start,
+            segments: Vec::new(),
+            continuity: Continuity::Corner,
+            closed: false,
+        }
+    }
+
+    /// Set the continuity enforced on segments added after this call.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{BezierPath, Continuity};
+    ///
+    /// let path = BezierPath::new(0.0)
+    ///     .with_continuity(Continuity::Smooth)
+    ///     .cubic_to(1.0, 2.0, 3.0)
+    ///     .cubic_to(0.0, 5.0, 6.0);
+    ///
+    /// // The first control point was mirrored from the previous segment.
+    /// assert_eq!(path.segments()[1].c1, 4.0);
+    /// ```
+    pub fn with_continuity(mut self, continuity: Continuity) -> BezierPath<T> {
+        self.continuity = continuity;
+        self
+    }
+
+    /// Add a cubic segment from the current end point to `to`, with control
+    /// points `c1` and `c2`.
+    ///
+    /// With [`Continuity::Smooth`][0], `c1` is replaced by the mirror of the
+    /// previous segment's last control point.
+    ///
+    /// [0]: enum.Continuity.html#variant.Smooth
+    pub fn cubic_to(mut self, c1: T, c2: T, to: T) -> BezierPath<T> {
+        let c1 = match (self.continuity, self.segments.last()) {
+            (Continuity::Smooth, Some(last)) => last.to * 2.0 - last.c2,
+            _ => c1,
+        };
+
+        let from = self.end();
+        self.segments.push(BezierSegment { from, c1, c2, to });
+        self
+    }
+
+    /// Add a smooth cubic segment from the current end point to `to`, whose
+    /// first control point mirrors the previous segment's last control point,
+    /// like SVG's `S` command.
+    pub fn smooth_to(self, c2: T, to: T) -> BezierPath<T> {
+        let c1 = match self.segments.last() {
+            Some(last) => last.to * 2.0 - last.c2,
+            None => self.start,
+        };
+
+        self.cubic_to(c1, c2, to)
+    }
+
+    /// Add a straight segment from the current end point to `to`.
+    pub fn line_to(self, to: T) -> BezierPath<T> {
+        let from = self.end();
+        let c1 = from + (to - from) * (1.0 / 3.0);
+        let c2 = from + (to - from) * (2.0 / 3.0);
+
+        self.cubic_to(c1, c2, to)
+    }
+
+    /// Close the path with a segment from the current end point back to the
+    /// start.
+    ///
+    /// With [`Continuity::Smooth`][0], the closing segment mirrors the
+    /// control points on both sides, so the path is smooth across the seam.
+    /// Otherwise, the closing segment is a straight line.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{BezierPath, Continuity, Spline};
+    ///
+    /// let loop_path = BezierPath::new(0.0)
+    ///     .with_continuity(Continuity::Smooth)
+    ///     .cubic_to(2.0, 8.0, 10.0)
+    ///     .close();
+    ///
+    /// assert_eq!(loop_path.sample(1.0), 0.0);
+    /// assert_eq!(loop_path.derivative(0.0), loop_path.derivative(1.0));
+    /// ```
+    ///
+    /// [0]: enum.Continuity.html#variant.Smooth
+    pub fn close(self) -> BezierPath<T> {
+        let first = match self.segments.first() {
+            Some(first) => *first,
+            None => return self,
+        };
+
+        let start = self.start;
+        let mut path = match self.continuity {
+            Continuity::Smooth => {
+                let c2 = start * 2.0 - first.c1;
+                self.cubic_to(start, c2, start)
+            }
+            Continuity::Corner => self.line_to(start),
+        };
+
+        path.closed = true;
+        path
+    }
+
+    /// Whether the path has been closed with [`close`][0].
+    ///
+    /// [0]: #method.close
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Start point of the path.
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    /// End point of the path.
+    pub fn end(&self) -> T {
+        match self.segments.last() {
+            Some(last) => last.to,
+            None => self.start,
+        }
+    }
+
+    /// Segments of the path.
+    pub fn segments(&self) -> &[BezierSegment<T>] {
+        &self.segments
+    }
+}
+
+impl<T> Spline<T> for BezierPath<T>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    /// Calculate the point on the path, where each segment covers an equal
+    /// range of _t_.
+    fn sample(&self, t: f32) -> T {
+        if self.segments.is_empty() {
+            return self.start;
+        }
+
+        let (index, t) = segment(t, self.segments.len());
+        self.segments[index].sample(t)
+    }
+
+    fn derivative(&self, t: f32) -> T {
+        if self.segments.is_empty() {
+            return self.start * 0.0;
+        }
+
+        let count = self.segments.len();
+        let (index, t) = segment(t, count);
+        self.segments[index].derivative(t) * count as f32
+    }
+
+    fn second_derivative(&self, t: f32) -> T {
+        if self.segments.is_empty() {
+            return self.start * 0.0;
+        }
+
+        let count = self.segments.len();
+        let (index, t) = segment(t, count);
+        self.segments[index].second_derivative(t) * (count * count) as f32
+    }
+}
@@ -0,0 +1,232 @@
+use core::ops::{Add, Mul, Sub};
+
+use super::Spline;
+
+#[derive(Debug)]
+/// B-spline curve of configurable degree over a set of control points.
+///
+/// Unlike a [`CatmullRom`][0] spline, a B-spline doesn't pass through its
+/// control points, but is smoother as a result.
+///
+/// [0]: struct.CatmullRom.html
+pub struct BSpline<T> {
+    points: Vec<T>,
+    degree: usize,
+    knots: Vec<f32>,
+}
+
+impl<T> BSpline<T>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    /// Create a new uniform B-spline, with evenly spaced knots.
+    ///
+    /// A uniform B-spline starts and ends near, but not at, its first and last
+    /// control points.
+    ///
+    /// # Panics
+    /// Panics if there are not more points than the degree.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Spline;
+    ///
+    /// let spline = soy::BSpline::uniform(vec![0.0, 0.0, 6.0, 6.0], 2);
+    ///
+    /// assert_eq!(spline.sample(0.0), 0.0);
+    /// assert_eq!(spline.sample(0.5), 3.0);
+    /// assert_eq!(spline.sample(1.0), 6.0);
+    /// ```
+    pub fn uniform(points: Vec<T>, degree: usize) -> BSpline<T> {
+        let count = points.len() + degree + 1;
+        let knots = (0..count).map(|i| i as f32).collect();
+
+        BSpline::with_knots(points, degree, knots)
+    }
+
+    /// Create a new clamped B-spline, which starts at its first control point
+    /// and ends at its last.
+    ///
+    /// # Panics
+    /// Panics if there are not more points than the degree.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Spline;
+    ///
+    /// let spline = soy::BSpline::clamped(vec![0.0, 8.0, 2.0, 4.0], 3);
+    ///
+    /// assert_eq!(spline.sample(0.0), 0.0);
+    /// assert_eq!(spline.sample(1.0), 4.0);
+    /// ```
+    pub fn clamped(points: Vec<T>, degree: usize) -> BSpline<T> {
+        let knots = clamped_knots(points.len(), degree);
+
+        BSpline::with_knots(points, degree, knots)
+    }
+
+    /// Create a new closed B-spline, which loops smoothly back to its start.
+    ///
+    /// # Panics
+    /// Panics if there are not more points than the degree.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Spline;
+    ///
+    /// let spline = soy::BSpline::closed(vec![0.0, 6.0, 6.0, 0.0], 3);
+    ///
+    /// assert!((spline.sample(0.0) - spline.sample(1.0)).abs() < 1.0e-5);
+    /// ```
+    pub fn closed(points: Vec<T>, degree: usize) -> BSpline<T> {
+        assert!(
+            points.len() > degree,
+            "a B-spline requires more points than its degree"
+        );
+
+        // Wrapping the first `degree` points around to the end gives a
+        // periodic spline.
+        let mut wrapped = points.clone();
+        wrapped.extend_from_slice(&points[..degree]);
+
+        BSpline::uniform(wrapped, degree)
+    }
+
+    /// Create a new B-spline with an explicit knot vector.
+    ///
+    /// # Panics
+    /// Panics if there are not more points than the degree, if there are not
+    /// exactly `points.len() + degree + 1` knots, or if the knots are
+    /// decreasing.
+    pub fn with_knots(points: Vec<T>, degree: usize, knots: Vec<f32>) -> BSpline<T> {
+        validate(points.len(), degree, &knots);
+
+        BSpline {
+            points,
+            degree,
+            knots,
+        }
+    }
+
+    /// Control points of the spline.
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+
+    /// Degree of the spline.
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Knot vector of the spline.
+    pub fn knots(&self) -> &[f32] {
+        &self.knots
+    }
+
+    /// Calculate the value of the spline at the given knot value `u`.
+    pub fn evaluate(&self, u: f32) -> T {
+        let (span, basis) = basis_functions(&self.knots, self.degree, self.points.len(), u);
+        let first = span - self.degree;
+
+        (1..basis.len()).fold(self.points[first] * basis[0], |acc, j| {
+            acc + self.points[first + j] * basis[j]
+        })
+    }
+}
+
+impl<T> Spline<T> for BSpline<T>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    fn sample(&self, t: f32) -> T {
+        let (start, end) = domain(&self.knots, self.degree, self.points.len());
+        self.evaluate(start + (end - start) * t)
+    }
+}
+
+/// Knot vector for a clamped spline over `count` points.
+pub(crate) fn clamped_knots(count: usize, degree: usize) -> Vec<f32> {
+    let inner = count.saturating_sub(degree);
+
+    (0..count + degree + 1)
+        .map(|i| i.saturating_sub(degree).min(inner) as f32 / inner.max(1) as f32)
+        .collect()
+}
+
+/// Check that a knot vector is valid for `count` points of the given degree.
+pub(crate) fn validate(count: usize, degree: usize, knots: &[f32]) {
+    assert!(
+        count > degree,
+        "a B-spline requires more points than its degree"
+    );
+    assert_eq!(
+        knots.len(),
+        count + degree + 1,
+        "a B-spline requires `points + degree + 1` knots"
+    );
+    assert!(
+        knots.windows(2).all(|w| w[0] <= w[1]),
+        "knots must not be decreasing"
+    );
+}
+
+/// Range of knot values over which the spline is defined.
+pub(crate) fn domain(knots: &[f32], degree: usize, count: usize) -> (f32, f32) {
+    (knots[degree], knots[count])
+}
+
+/// Calculate the non-zero basis functions at `u`, returning the knot span
+/// containing `u` and the `degree + 1` basis function values.
+///
+/// Implementation based on "The NURBS Book", algorithms A2.1 and A2.2.
+pub(crate) fn basis_functions(
+    knots: &[f32],
+    degree: usize,
+    count: usize,
+    u: f32,
+) -> (usize, Vec<f32>) {
+    let (start, end) = domain(knots, degree, count);
+    let u = u.max(start).min(end);
+
+    // Find the knot span, treating the end of the domain as part of the last
+    // span.
+    let span = if u >= end {
+        (degree..count)
+            .rev()
+            .find(|&i| knots[i] < end)
+            .unwrap_or(degree)
+    } else {
+        (degree..count)
+            .rev()
+            .find(|&i| knots[i] <= u)
+            .unwrap_or(degree)
+    };
+
+    let mut basis = vec![0.0; degree + 1];
+    let mut left = vec![0.0; degree + 1];
+    let mut right = vec![0.0; degree + 1];
+    basis[0] = 1.0;
+
+    for j in 1..=degree {
+        left[j] = u - knots[span + 1 - j];
+        right[j] = knots[span + j] - u;
+        let mut saved = 0.0;
+
+        for r in 0..j {
+            let denom = right[r + 1] + left[j - r];
+            let temp = if denom == 0.0 { 0.0 } else { basis[r] / denom };
+
+            basis[r] = saved + right[r + 1] * temp;
+            saved = left[j - r] * temp;
+        }
+        basis[j] = saved;
+    }
+
+    (span, basis)
+}
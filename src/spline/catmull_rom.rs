@@ -0,0 +1,266 @@
+use core::ops::{Add, Mul, Sub};
+
+use super::{segment, Spline};
+use crate::vector::{distance, Dot};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Knot spacing used when constructing a spline.
+pub enum Parameterization {
+    /// Knots are evenly spaced, regardless of the distance between points.
+    /// Unevenly spaced points may produce loops or cusps.
+    Uniform,
+    /// Knots are spaced by the square root of the distance between points.
+    /// Never produces loops or cusps within a segment.
+    Centripetal,
+    /// Knots are spaced by the distance between points.
+    Chordal,
+}
+
+impl Parameterization {
+    /// Exponent applied to the distance between points to get the knot
+    /// spacing.
+    pub fn alpha(self) -> f32 {
+        match self {
+            Parameterization::Uniform => 0.0,
+            Parameterization::Centripetal => 0.5,
+            Parameterization::Chordal => 1.0,
+        }
+    }
+}
+
+#[derive(Debug)]
+/// Catmull-Rom spline, passing smoothly through every one of its points.
+pub struct CatmullRom<T> {
+    points: Vec<T>,
+    /// Knot for each point, including the extrapolated points before the
+    /// start and past the end.
+    knots: Vec<f32>,
+    closed: bool,
+}
+
+impl<T> CatmullRom<T>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    /// Create a new, uniformly parameterized, Catmull-Rom spline through the
+    /// given points.
+    ///
+    /// # Panics
+    /// Panics if `points` is empty.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Spline;
+    ///
+    /// let spline = soy::CatmullRom::new(vec![0.0, 4.0, 2.0, 6.0]);
+    ///
+    /// assert_eq!(spline.sample(0.0), 0.0);
+    /// assert_eq!(spline.sample(1.0 / 3.0), 4.0);
+    /// assert_eq!(spline.sample(1.0), 6.0);
+    /// ```
+    pub fn new(points: Vec<T>) -> CatmullRom<T> {
+        CatmullRom::build(points, false)
+    }
+
+    /// Create a new, uniformly parameterized, closed Catmull-Rom spline
+    /// through the given points, which loops back from the last point to the
+    /// first.
+    ///
+    /// # Panics
+    /// Panics if `points` is empty.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Spline;
+    ///
+    /// let patrol = soy::CatmullRom::closed(vec![0.0, 4.0, 8.0, 4.0]);
+    ///
+    /// assert_eq!(patrol.sample(0.0), 0.0);
+    /// assert_eq!(patrol.sample(0.5), 8.0);
+    /// assert_eq!(patrol.sample(1.0), 0.0);
+    /// ```
+    pub fn closed(points: Vec<T>) -> CatmullRom<T> {
+        CatmullRom::build(points, true)
+    }
+
+    /// Create a new Catmull-Rom spline through the given points, spacing
+    /// knots with the given parameterization.
+    ///
+    /// # Panics
+    /// Panics if `points` is empty.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{CatmullRom, Parameterization, Spline};
+    ///
+    /// let points = vec![0.0, 1.0, 10.0, 11.0];
+    /// let spline = CatmullRom::with_parameterization(points, Parameterization::Centripetal);
+    ///
+    /// assert_eq!(spline.sample(0.0), 0.0);
+    /// assert_eq!(spline.sample(1.0), 11.0);
+    /// ```
+    pub fn with_parameterization(
+        points: Vec<T>,
+        parameterization: Parameterization,
+    ) -> CatmullRom<T>
+    where
+        T: Dot,
+    {
+        CatmullRom::build(points, false).parameterize(parameterization)
+    }
+
+    /// Create a new closed Catmull-Rom spline through the given points,
+    /// spacing knots with the given parameterization.
+    ///
+    /// # Panics
+    /// Panics if `points` is empty.
+    pub fn closed_with_parameterization(
+        points: Vec<T>,
+        parameterization: Parameterization,
+    ) -> CatmullRom<T>
+    where
+        T: Dot,
+    {
+        CatmullRom::build(points, true).parameterize(parameterization)
+    }
+
+    fn build(points: Vec<T>, closed: bool) -> CatmullRom<T> {
+        assert!(!points.is_empty(), "a spline requires at least one point");
+
+        let mut spline = CatmullRom {
+            points,
+            knots: Vec::new(),
+            closed,
+        };
+        spline.knots = (0..spline.segments() + 3).map(|i| i as f32).collect();
+        spline
+    }
+
+    fn parameterize(mut self, parameterization: Parameterization) -> CatmullRom<T>
+    where
+        T: Dot,
+    {
+        let alpha = parameterization.alpha();
+
+        let mut knot = 0.0;
+        for i in 0..self.knots.len() {
+            self.knots[i] = knot;
+
+            let index = i as isize - 1;
+            let d = distance(self.point(index), self.point(index + 1));
+            let step = d.powf(alpha);
+
+            // Coincident points would otherwise produce a zero length knot
+            // interval.
+            knot += if step > f32::EPSILON { step } else { 1.0 };
+        }
+
+        self
+    }
+
+    /// Points the spline passes through.
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+
+    /// Whether the spline loops back from its last point to its first.
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Number of segments between points.
+    fn segments(&self) -> usize {
+        if self.closed {
+            self.points.len()
+        } else {
+            self.points.len() - 1
+        }
+    }
+
+    /// Point at `index`, wrapping around closed splines, and extrapolating the
+    /// first and last segments of open splines.
+    fn point(&self, index: isize) -> T {
+        let last = self.points.len() as isize - 1;
+
+        if self.closed {
+            self.points[index.rem_euclid(last + 1) as usize]
+        } else if index < 0 {
+            let (a, b) = (self.points[0], self.points[1.min(last) as usize]);
+            a * 2.0 - b
+        } else if index > last {
+            let (a, b) = (
+                self.points[last as usize],
+                self.points[(last - 1).max(0) as usize],
+            );
+            a * 2.0 - b
+        } else {
+            self.points[index as usize]
+        }
+    }
+
+    /// Knot of the point at `index`.
+    fn knot(&self, index: isize) -> f32 {
+        self.knots[(index + 1) as usize]
+    }
+}
+
+impl<T> Spline<T> for CatmullRom<T>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    fn sample(&self, t: f32) -> T {
+        if self.points.len() == 1 {
+            return self.points[0];
+        }
+
+        let (index, u) = segment(t, self.segments());
+        let i = index as isize;
+        let (p0, p1, p2, p3) = (
+            self.point(i - 1),
+            self.point(i),
+            self.point(i + 1),
+            self.point(i + 2),
+        );
+        let (t0, t1, t2, t3) = (
+            self.knot(i - 1),
+            self.knot(i),
+            self.knot(i + 1),
+            self.knot(i + 2),
+        );
+
+        // Barry and Goldman's pyramidal formulation.
+        let t = t1 + (t2 - t1) * u;
+        let blend =
+            |a: T, b: T, ta: f32, tb: f32| a * ((tb - t) / (tb - ta)) + b * ((t - ta) / (tb - ta));
+
+        let a1 = blend(p0, p1, t0, t1);
+        let a2 = blend(p1, p2, t1, t2);
+        let a3 = blend(p2, p3, t2, t3);
+
+        let b1 = blend(a1, a2, t0, t2);
+        let b2 = blend(a2, a3, t1, t3);
+
+        blend(b1, b2, t1, t2)
+    }
+}
+
+/// Evaluate a uniform Catmull-Rom segment from `p1` to `p2`.
+pub(crate) fn uniform_segment<T>(p0: T, p1: T, p2: T, p3: T, t: f32) -> T
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    let a = (p2 - p0) * 0.5;
+    let b = (p0 * 2.0 - p1 * 5.0 + p2 * 4.0 - p3) * 0.5;
+    let c = ((p1 - p2) * 3.0 + p3 - p0) * 0.5;
+
+    p1 + (a + (b + c * t) * t) * t
+}
@@ -0,0 +1,176 @@
+use core::ops::{Add, Mul, Sub};
+
+use super::hermite::{evaluate, evaluate_at, evaluate_derivative, evaluate_second_derivative};
+use super::{HermiteCursor, HermiteKey, KeyError, Spline};
+
+#[derive(Debug, Clone, Copy)]
+/// Cubic Hermite keyframe track holding up to `N` keys inline, so it never
+/// allocates, such as for animating on microcontrollers.
+///
+/// Keys are edited like a [`Hermite`][0] spline's, except that inserting into
+/// a full track is an error rather than growing it. Tangents are always
+/// kept as given.
+///
+/// # Usage
+/// ```
+/// use soy::{FixedHermite, HermiteKey, KeyError};
+///
+/// let mut track: FixedHermite<f32, 3> = FixedHermite::new(&[
+///     HermiteKey { time: 0.0, value: 0.0, tangent: 0.0 },
+///     HermiteKey { time: 2.0, value: 10.0, tangent: 0.0 },
+/// ]);
+/// assert_eq!(track.evaluate(1.0), 5.0);
+///
+/// let key = HermiteKey { time: 3.0, value: 0.0, tangent: 0.0 };
+/// assert_eq!(track.insert(key), Ok(2));
+/// assert_eq!(track.end_time(), 3.0);
+///
+/// let key = HermiteKey { time: 4.0, value: 0.0, tangent: 0.0 };
+/// assert_eq!(track.insert(key), Err(KeyError::Full));
+/// ```
+///
+/// [0]: struct.Hermite.html
+pub struct FixedHermite<T, const N: usize> {
+    keys: [HermiteKey<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> FixedHermite<T, N>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    /// Create a new track from the given keys.
+    ///
+    /// # Panics
+    /// Panics if `keys` is empty, has more than `N` keys, or the keys are not
+    /// sorted by strictly increasing time.
+    pub fn new(keys: &[HermiteKey<T>]) -> FixedHermite<T, N> {
+        assert!(!keys.is_empty(), "a spline requires at least one key");
+        assert!(keys.len() <= N, "too many keys for the track's capacity");
+        assert!(
+            keys.windows(2).all(|w| w[0].time < w[1].time),
+            "keys must be sorted by strictly increasing time"
+        );
+
+        // Unused slots are filled with the first key, so no default value is
+        // needed.
+        let mut storage = [keys[0]; N];
+        storage[..keys.len()].copy_from_slice(keys);
+
+        FixedHermite {
+            keys: storage,
+            len: keys.len(),
+        }
+    }
+
+    /// Keys of the track.
+    pub fn keys(&self) -> &[HermiteKey<T>] {
+        &self.keys[..self.len]
+    }
+
+    /// Maximum number of keys the track can hold.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Insert a key, keeping the keys sorted by time, and return its index.
+    pub fn insert(&mut self, key: HermiteKey<T>) -> Result<usize, KeyError> {
+        if !key.time.is_finite() {
+            return Err(KeyError::NonFiniteTime(key.time));
+        }
+
+        let index = self.keys().partition_point(|k| k.time < key.time);
+        if self.keys().get(index).is_some_and(|k| k.time == key.time) {
+            return Err(KeyError::DuplicateTime(key.time));
+        }
+        if self.len == N {
+            return Err(KeyError::Full);
+        }
+
+        self.keys.copy_within(index..self.len, index + 1);
+        self.keys[index] = key;
+        self.len += 1;
+        Ok(index)
+    }
+
+    /// Remove and return the key at `index`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Result<HermiteKey<T>, KeyError> {
+        assert!(index < self.len, "key index out of bounds");
+        if self.len == 1 {
+            return Err(KeyError::LastKey);
+        }
+
+        let key = self.keys[index];
+        self.keys.copy_within(index + 1..self.len, index);
+        self.len -= 1;
+        Ok(key)
+    }
+
+    /// Calculate the value of the track at the given time, holding the first
+    /// and last values outside of the keyed range.
+    pub fn evaluate(&self, time: f32) -> T {
+        evaluate(self.keys(), time)
+    }
+
+    /// Calculate the value of the track at the given time, like
+    /// [`evaluate`][0], starting the search for the key from `cursor`.
+    ///
+    /// [0]: #method.evaluate
+    pub fn evaluate_at(&self, cursor: &mut HermiteCursor, time: f32) -> T {
+        evaluate_at(self.keys(), cursor, time)
+    }
+
+    /// Calculate the rate of change of the track, per unit of time, at the
+    /// given time.
+    pub fn evaluate_derivative(&self, time: f32) -> T {
+        evaluate_derivative(self.keys(), time)
+    }
+
+    /// Calculate the second derivative of the track, per unit of time, at the
+    /// given time.
+    pub fn evaluate_second_derivative(&self, time: f32) -> T {
+        evaluate_second_derivative(self.keys(), time)
+    }
+
+    /// Time of the first key.
+    pub fn start_time(&self) -> f32 {
+        self.keys[0].time
+    }
+
+    /// Time of the last key.
+    pub fn end_time(&self) -> f32 {
+        self.keys[self.len - 1].time
+    }
+}
+
+impl<T, const N: usize> Spline<T> for FixedHermite<T, N>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    /// Calculate the value of the track, where _t_ is mapped from the time of
+    /// the first key to the time of the last key.
+    fn sample(&self, t: f32) -> T {
+        let (start, end) = (self.start_time(), self.end_time());
+        self.evaluate(start + (end - start) * t)
+    }
+
+    fn derivative(&self, t: f32) -> T {
+        let (start, end) = (self.start_time(), self.end_time());
+        self.evaluate_derivative(start + (end - start) * t) * (end - start)
+    }
+
+    fn second_derivative(&self, t: f32) -> T {
+        let (start, end) = (self.start_time(), self.end_time());
+        let scale = (end - start) * (end - start);
+        self.evaluate_second_derivative(start + (end - start) * t) * scale
+    }
+}
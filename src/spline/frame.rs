@@ -0,0 +1,78 @@
+use core::ops::{Add, Mul, Sub};
+
+use super::Spline;
+use crate::vector::{normalize, Cross, Dot};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Orientation of a three-dimensional spline at a point.
+pub struct Frame<T> {
+    /// Point on the spline.
+    pub position: T,
+    /// Unit direction of travel.
+    pub tangent: T,
+    /// Unit vector perpendicular to the tangent.
+    pub normal: T,
+    /// Unit vector perpendicular to both the tangent and the normal.
+    pub binormal: T,
+}
+
+/// Calculate `count` rotation minimizing frames along `spline`, using the
+/// double reflection method by Wang et al.
+pub(crate) fn frames<S, T>(spline: &S, count: usize, up: T) -> Vec<Frame<T>>
+where
+    S: Spline<T> + ?Sized,
+    T: Copy + Dot + Cross,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    let mut frames: Vec<Frame<T>> = Vec::with_capacity(count);
+    if count == 0 {
+        return frames;
+    }
+
+    let at = |t: f32| (spline.sample(t), spline.tangent(t));
+    let reflect = |v: T, axis: T, c: f32| v - axis * (2.0 / c * axis.dot(v));
+
+    let (position, tangent) = at(0.0);
+    let normal = normalize(up - tangent * up.dot(tangent));
+    frames.push(Frame {
+        position,
+        tangent,
+        normal,
+        binormal: tangent.cross(normal),
+    });
+
+    for i in 1..count {
+        let previous = frames[i - 1];
+        let (position, tangent) = at(i as f32 / (count - 1) as f32);
+
+        let v1 = position - previous.position;
+        let c1 = v1.dot(v1);
+        let (normal_l, tangent_l) = if c1 > 0.0 {
+            (
+                reflect(previous.normal, v1, c1),
+                reflect(previous.tangent, v1, c1),
+            )
+        } else {
+            (previous.normal, previous.tangent)
+        };
+
+        let v2 = tangent - tangent_l;
+        let c2 = v2.dot(v2);
+        let normal = if c2 > 0.0 {
+            reflect(normal_l, v2, c2)
+        } else {
+            normal_l
+        };
+
+        frames.push(Frame {
+            position,
+            tangent,
+            normal,
+            binormal: tangent.cross(normal),
+        });
+    }
+
+    frames
+}
@@ -0,0 +1,803 @@
+use core::fmt;
+use core::iter;
+use core::ops::{Add, Mul, Sub};
+
+use super::Spline;
+use crate::vector::{distance, Dot};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Key of a [`Hermite`][0] spline.
+///
+/// [0]: struct.Hermite.html
+pub struct HermiteKey<T> {
+    /// Time of the key.
+    pub time: f32,
+    /// Value of the spline at `time`.
+    pub value: T,
+    /// Rate of change of the value, per unit of time, at `time`.
+    pub tangent: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Method used to automatically generate [`Hermite`][0] tangents.
+///
+/// [0]: struct.Hermite.html
+pub enum TangentMode {
+    /// Tangents are zero, easing in and out of every key.
+    Flat,
+    /// Tangents are the average of the slopes of the neighbouring segments.
+    FiniteDifference,
+    /// Tangents are the slope between the neighbouring keys.
+    CatmullRom,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Error editing the keys of a [`Hermite`][0] spline.
+///
+/// [0]: struct.Hermite.html
+pub enum KeyError {
+    /// Another key already has the given time.
+    DuplicateTime(f32),
+    /// The given time is infinite or NaN.
+    NonFiniteTime(f32),
+    /// A spline requires at least one key, so its last key can't be removed.
+    LastKey,
+    /// A [`FixedHermite`][0] track has no room for another key.
+    ///
+    /// [0]: struct.FixedHermite.html
+    Full,
+}
+
+impl fmt::Display for KeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyError::DuplicateTime(time) => write!(f, "a key already has time {}", time),
+            KeyError::NonFiniteTime(time) => write!(f, "a key's time must be finite, not {}", time),
+            KeyError::LastKey => write!(f, "a spline requires at least one key"),
+            KeyError::Full => write!(f, "the track has no room for another key"),
+        }
+    }
+}
+
+impl std::error::Error for KeyError {}
+
+#[derive(Debug)]
+/// Cubic Hermite spline, where each key carries its own value and tangent.
+pub struct Hermite<T> {
+    keys: Vec<HermiteKey<T>>,
+    /// Mode used to regenerate tangents after editing, for splines created
+    /// with [`auto`](#method.auto), or given one later.
+    mode: Option<TangentMode>,
+}
+
+impl<T> Hermite<T>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    /// Create a new Hermite spline from the given keys.
+    ///
+    /// # Panics
+    /// Panics if `keys` is empty, or the keys are not sorted by strictly
+    /// increasing time.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{Hermite, HermiteKey};
+    ///
+    /// let spline = Hermite::new(vec![
+    ///     HermiteKey { time: 0.0, value: 0.0, tangent: 0.0 },
+    ///     HermiteKey { time: 2.0, value: 10.0, tangent: 0.0 },
+    /// ]);
+    ///
+    /// assert_eq!(spline.evaluate(1.0), 5.0);
+    /// ```
+    pub fn new(keys: Vec<HermiteKey<T>>) -> Hermite<T> {
+        assert!(!keys.is_empty(), "a spline requires at least one key");
+        assert!(
+            keys.windows(2).all(|w| w[0].time < w[1].time),
+            "keys must be sorted by strictly increasing time"
+        );
+
+        Hermite { keys, mode: None }
+    }
+
+    /// Create a new Hermite spline through the given `(time, value)` points,
+    /// generating tangents with the given mode.
+    ///
+    /// # Panics
+    /// Panics if `points` is empty, or the points are not sorted by strictly
+    /// increasing time.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{Hermite, TangentMode};
+    ///
+    /// let points = vec![(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)];
+    /// let spline = Hermite::auto(points, TangentMode::CatmullRom);
+    ///
+    /// assert_eq!(spline.keys()[1].tangent, 1.0);
+    /// assert_eq!(spline.evaluate(0.5), 0.5);
+    /// ```
+    pub fn auto(points: Vec<(f32, T)>, mode: TangentMode) -> Hermite<T> {
+        let keys = (0..points.len())
+            .map(|i| {
+                let (time, value) = points[i];
+                let tangent = auto_tangent(&points, i, mode);
+
+                HermiteKey {
+                    time,
+                    value,
+                    tangent,
+                }
+            })
+            .collect();
+
+        Hermite {
+            mode: Some(mode),
+            ..Hermite::new(keys)
+        }
+    }
+
+    /// Keys of the spline.
+    pub fn keys(&self) -> &[HermiteKey<T>] {
+        &self.keys
+    }
+
+    /// Mode used to regenerate the spline's tangents after every edit, if it
+    /// was created with [`auto`](#method.auto), or given one with
+    /// [`set_tangent_mode`](#method.set_tangent_mode).
+    pub fn tangent_mode(&self) -> Option<TangentMode> {
+        self.mode
+    }
+
+    /// Insert a key, keeping the keys sorted by time, and return its index.
+    ///
+    /// When the spline's tangents are generated automatically, the key's
+    /// tangent is ignored, and every tangent is regenerated to account for the
+    /// new neighbours.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{Hermite, HermiteKey, KeyError, TangentMode};
+    ///
+    /// let mut track = Hermite::auto(vec![(0.0, 0.0), (2.0, 2.0)], TangentMode::CatmullRom);
+    ///
+    /// let key = HermiteKey { time: 1.0, value: 4.0, tangent: 0.0 };
+    /// assert_eq!(track.insert(key), Ok(1));
+    /// assert_eq!(track.keys()[0].tangent, 4.0);
+    ///
+    /// assert_eq!(track.insert(key), Err(KeyError::DuplicateTime(1.0)));
+    ///
+    /// let key = HermiteKey { time: f32::NAN, ..key };
+    /// assert!(matches!(track.insert(key), Err(KeyError::NonFiniteTime(_))));
+    /// ```
+    pub fn insert(&mut self, key: HermiteKey<T>) -> Result<usize, KeyError> {
+        if !key.time.is_finite() {
+            return Err(KeyError::NonFiniteTime(key.time));
+        }
+
+        let index = self.keys.partition_point(|k| k.time < key.time);
+        if self.keys.get(index).is_some_and(|k| k.time == key.time) {
+            return Err(KeyError::DuplicateTime(key.time));
+        }
+
+        self.keys.insert(index, key);
+        self.regenerate_tangents();
+        Ok(index)
+    }
+
+    /// Remove and return the key at `index`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Result<HermiteKey<T>, KeyError> {
+        assert!(index < self.keys.len(), "key index out of bounds");
+        if self.keys.len() == 1 {
+            return Err(KeyError::LastKey);
+        }
+
+        let key = self.keys.remove(index);
+        self.regenerate_tangents();
+        Ok(key)
+    }
+
+    /// Move the key at `index` to a new time, keeping the keys sorted by
+    /// time, and return its new index.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{Hermite, TangentMode};
+    ///
+    /// let mut track = Hermite::auto(vec![(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)], TangentMode::Flat);
+    ///
+    /// assert_eq!(track.move_key(0, 3.0), Ok(2));
+    /// assert_eq!(track.start_time(), 1.0);
+    /// assert_eq!(track.end_time(), 3.0);
+    /// ```
+    pub fn move_key(&mut self, index: usize, time: f32) -> Result<usize, KeyError> {
+        assert!(index < self.keys.len(), "key index out of bounds");
+
+        let key = HermiteKey {
+            time,
+            ..self.keys[index]
+        };
+        self.set_key(index, key)
+    }
+
+    /// Replace the key at `index`, keeping the keys sorted by time, and
+    /// return the replacement's index.
+    ///
+    /// When the spline's tangents are generated automatically, the key's
+    /// tangent is ignored, and every tangent is regenerated.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn set_key(&mut self, index: usize, key: HermiteKey<T>) -> Result<usize, KeyError> {
+        assert!(index < self.keys.len(), "key index out of bounds");
+        if !key.time.is_finite() {
+            return Err(KeyError::NonFiniteTime(key.time));
+        }
+
+        let duplicate = self
+            .keys
+            .iter()
+            .enumerate()
+            .any(|(i, k)| i != index && k.time == key.time);
+        if duplicate {
+            return Err(KeyError::DuplicateTime(key.time));
+        }
+
+        self.keys.remove(index);
+        let index = self.keys.partition_point(|k| k.time < key.time);
+        self.keys.insert(index, key);
+        self.regenerate_tangents();
+        Ok(index)
+    }
+
+    /// Change how the spline's tangents are generated, which changes how it
+    /// eases through every key.
+    ///
+    /// With a mode, every tangent is regenerated now and after every edit,
+    /// like a spline created with [`auto`][0]. With `None`, the current
+    /// tangents are kept, and can be edited with [`set_key`][1].
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{Hermite, TangentMode};
+    ///
+    /// let mut track = Hermite::auto(vec![(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)], TangentMode::Flat);
+    /// assert_eq!(track.keys()[1].tangent, 0.0);
+    ///
+    /// track.set_tangent_mode(Some(TangentMode::CatmullRom));
+    /// assert_eq!(track.keys()[1].tangent, 1.0);
+    /// ```
+    ///
+    /// [0]: #method.auto
+    /// [1]: #method.set_key
+    pub fn set_tangent_mode(&mut self, mode: Option<TangentMode>) {
+        self.mode = mode;
+        self.regenerate_tangents();
+    }
+
+    /// Regenerate every tangent, if the spline's tangents are generated
+    /// automatically.
+    fn regenerate_tangents(&mut self) {
+        if let Some(mode) = self.mode {
+            let points: Vec<_> = self.keys.iter().map(|k| (k.time, k.value)).collect();
+            for (i, key) in self.keys.iter_mut().enumerate() {
+                key.tangent = auto_tangent(&points, i, mode);
+            }
+        }
+    }
+
+    /// Calculate the value of the spline at the given time, holding the first
+    /// and last values outside of the keyed range.
+    pub fn evaluate(&self, time: f32) -> T {
+        evaluate(&self.keys, time)
+    }
+
+    /// Calculate the value of the spline at the given time, like
+    /// [`evaluate`][0], starting the search for the key from `cursor`.
+    ///
+    /// When the spline is played forwards, the key is usually the same as, or
+    /// just after, the last one, so it's found in constant time.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{Hermite, HermiteCursor, TangentMode};
+    ///
+    /// let points: Vec<_> = (0..100).map(|i| (i as f32, (i % 2) as f32)).collect();
+    /// let track = Hermite::auto(points, TangentMode::Flat);
+    /// let mut cursor = HermiteCursor::new();
+    ///
+    /// for frame in 0..600 {
+    ///     let time = frame as f32 / 6.0;
+    ///     assert_eq!(track.evaluate_at(&mut cursor, time), track.evaluate(time));
+    /// }
+    /// ```
+    ///
+    /// [0]: #method.evaluate
+    pub fn evaluate_at(&self, cursor: &mut HermiteCursor, time: f32) -> T {
+        evaluate_at(&self.keys, cursor, time)
+    }
+
+    /// Calculate the rate of change of the spline, per unit of time, at the
+    /// given time.
+    pub fn evaluate_derivative(&self, time: f32) -> T {
+        evaluate_derivative(&self.keys, time)
+    }
+
+    /// Calculate the second derivative of the spline, per unit of time, at the
+    /// given time.
+    pub fn evaluate_second_derivative(&self, time: f32) -> T {
+        evaluate_second_derivative(&self.keys, time)
+    }
+
+    /// Iterate over `(time, value)` samples of the spline, at `rate` samples
+    /// per unit of time, from the first key to the last, such as for baking
+    /// to a buffer or writing golden-file tests.
+    ///
+    /// When the spline's duration isn't a whole number of samples, the last
+    /// sample is taken at the last key, rather than past it.
+    ///
+    /// # Panics
+    /// Panics if `rate` is not positive.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{Hermite, TangentMode};
+    ///
+    /// let track = Hermite::auto(vec![(0.0, 0.0), (1.0, 2.0), (1.25, 1.0)], TangentMode::Flat);
+    /// let samples: Vec<_> = track.samples(2.0).collect();
+    ///
+    /// assert_eq!(samples, vec![(0.0, 0.0), (0.5, 1.0), (1.0, 2.0), (1.25, 1.0)]);
+    /// ```
+    pub fn samples(&self, rate: f32) -> impl Iterator<Item = (f32, T)> + '_ {
+        assert!(rate > 0.0, "sampling requires a positive rate");
+
+        let (start, end) = (self.start_time(), self.end_time());
+
+        (0..)
+            .map(move |i| start + i as f32 / rate)
+            .take_while(move |&time| time < end)
+            .chain(iter::once(end))
+            .map(move |time| (time, self.evaluate(time)))
+    }
+
+    /// Bake the spline into one sample per frame, at `frame_rate` frames per
+    /// unit of time, from the first key to the last.
+    ///
+    /// Baked tracks are looked up in constant time, trading memory for speed
+    /// on tracks which are sampled every frame.
+    ///
+    /// # Panics
+    /// Panics if `frame_rate` is not positive.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{Hermite, TangentMode};
+    ///
+    /// let track = Hermite::auto(vec![(0.0, 0.0), (0.5, 2.0), (1.0, 1.0)], TangentMode::CatmullRom);
+    /// let baked = track.bake_frames(60.0);
+    ///
+    /// assert_eq!(baked.samples().len(), 61);
+    /// assert_eq!(baked.evaluate(0.5), track.evaluate(0.5));
+    /// assert!((baked.evaluate(0.123) - track.evaluate(0.123)).abs() < 1.0e-3);
+    ///
+    /// // The last frame ends at the last key, even between frames.
+    /// let uneven = track.bake_frames(7.0);
+    /// assert_eq!(uneven.end_time(), 1.0);
+    /// assert_eq!(uneven.evaluate(1.0), 1.0);
+    /// ```
+    pub fn bake_frames(&self, frame_rate: f32) -> BakedTrack<T> {
+        assert!(
+            frame_rate > 0.0,
+            "a baked track requires a positive frame rate"
+        );
+
+        BakedTrack {
+            samples: self.samples(frame_rate).map(|(_, value)| value).collect(),
+            start: self.start_time(),
+            end: self.end_time(),
+            frame_rate,
+        }
+    }
+
+    /// Time of the first key.
+    pub fn start_time(&self) -> f32 {
+        self.keys[0].time
+    }
+
+    /// Time of the last key.
+    pub fn end_time(&self) -> f32 {
+        self.keys[self.keys.len() - 1].time
+    }
+}
+
+impl<T> Hermite<T>
+where
+    T: Copy + Dot,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    /// Number of points checked within each segment when compressing.
+    const COMPRESS_SAMPLES: usize = 8;
+
+    /// Create a copy of the spline without the keys that can be removed while
+    /// staying within `tolerance` of the original, such as redundant keys in
+    /// imported animation tracks.
+    ///
+    /// Keys are removed greedily, keeping their neighbours' tangents. Each
+    /// segment of the result is checked against the original at every removed
+    /// key, and several points in between. The first and last keys are always
+    /// kept.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{Hermite, TangentMode};
+    ///
+    /// // Densely baked keys along a straight line.
+    /// let points: Vec<_> = (0..=60).map(|i| (i as f32, i as f32 * 0.5)).collect();
+    /// let track = Hermite::auto(points, TangentMode::FiniteDifference);
+    ///
+    /// let compressed = track.compress(1.0e-3);
+    /// assert_eq!(compressed.keys().len(), 2);
+    /// assert!((compressed.evaluate(21.3) - track.evaluate(21.3)).abs() < 1.0e-3);
+    /// ```
+    pub fn compress(&self, tolerance: f32) -> Hermite<T> {
+        let keys = &self.keys;
+        if keys.len() <= 2 {
+            return Hermite {
+                keys: keys.clone(),
+                mode: self.mode,
+            };
+        }
+
+        let mut kept = vec![keys[0]];
+        let mut anchor = 0;
+        for i in 1..keys.len() - 1 {
+            let (a, b) = (&keys[anchor], &keys[i + 1]);
+
+            let within = (anchor..=i).all(|j| {
+                let (from, to) = (keys[j].time, keys[j + 1].time);
+
+                (0..Self::COMPRESS_SAMPLES).all(|n| {
+                    let time = from + (to - from) * n as f32 / Self::COMPRESS_SAMPLES as f32;
+                    distance(hermite(a, b, time), self.evaluate(time)) <= tolerance
+                })
+            });
+
+            if !within {
+                kept.push(keys[i]);
+                anchor = i;
+            }
+        }
+        kept.push(keys[keys.len() - 1]);
+
+        // The kept keys' tangents no longer match their new neighbours, so
+        // they're kept as they are.
+        Hermite {
+            keys: kept,
+            mode: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Spline baked into one sample per frame, looked up in constant time.
+///
+/// This is created using [`Hermite::bake_frames`][0]. Values between frames
+/// are linearly interpolated, and the first and last values are held outside
+/// of the baked range. When the spline's duration isn't a whole number of
+/// frames, the last frame is shorter, ending at the last key.
+///
+/// [0]: struct.Hermite.html#method.bake_frames
+pub struct BakedTrack<T> {
+    samples: Vec<T>,
+    start: f32,
+    end: f32,
+    frame_rate: f32,
+}
+
+impl<T> BakedTrack<T>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    /// Calculate the value of the track at the given time.
+    pub fn evaluate(&self, time: f32) -> T {
+        let last = self.samples.len() - 1;
+        let time = time.clamp(self.start, self.end);
+        let position = (time - self.start) * self.frame_rate;
+        let index = (position as usize).min(last.saturating_sub(1));
+
+        match self.samples.get(index + 1) {
+            Some(&next) => {
+                let current = self.samples[index];
+                let from = self.start + index as f32 / self.frame_rate;
+                let to = (self.start + (index + 1) as f32 / self.frame_rate).min(self.end);
+                let t = if to > from {
+                    ((time - from) / (to - from)).clamp(0.0, 1.0)
+                } else {
+                    1.0
+                };
+
+                current + (next - current) * t
+            }
+            None => self.samples[index],
+        }
+    }
+
+    /// Samples of the track, one per frame.
+    pub fn samples(&self) -> &[T] {
+        &self.samples
+    }
+
+    /// Time of the first frame.
+    pub fn start_time(&self) -> f32 {
+        self.start
+    }
+
+    /// Time of the last frame, the time of the spline's last key.
+    pub fn end_time(&self) -> f32 {
+        self.end
+    }
+
+    /// Frames per unit of time.
+    pub fn frame_rate(&self) -> f32 {
+        self.frame_rate
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// Remembers the last key found in a [`Hermite`][0] spline, so playing it
+/// forwards finds each key in constant time, rather than searching.
+///
+/// A cursor can be used with any spline, but is only faster when it keeps
+/// being used with the same one.
+///
+/// [0]: struct.Hermite.html
+pub struct HermiteCursor {
+    index: usize,
+}
+
+impl HermiteCursor {
+    /// Create a new cursor, at the first key.
+    pub fn new() -> HermiteCursor {
+        HermiteCursor { index: 0 }
+    }
+
+    /// Index of the last key found.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Find the segment containing `time`, checking the last segment and the
+    /// one after it, before searching.
+    fn seek<T>(&mut self, keys: &[HermiteKey<T>], time: f32) -> usize {
+        let contains =
+            |i: usize| i + 1 < keys.len() && keys[i].time <= time && time < keys[i + 1].time;
+
+        if !contains(self.index) {
+            self.index = if contains(self.index + 1) {
+                self.index + 1
+            } else {
+                segment(keys, time)
+            };
+        }
+
+        self.index
+    }
+}
+
+impl<T> Spline<T> for Hermite<T>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    /// Calculate the value of the spline, where _t_ is mapped from the time of
+    /// the first key to the time of the last key.
+    fn sample(&self, t: f32) -> T {
+        let (start, end) = (self.start_time(), self.end_time());
+        self.evaluate(start + (end - start) * t)
+    }
+
+    fn derivative(&self, t: f32) -> T {
+        let (start, end) = (self.start_time(), self.end_time());
+        self.evaluate_derivative(start + (end - start) * t) * (end - start)
+    }
+
+    fn second_derivative(&self, t: f32) -> T {
+        let (start, end) = (self.start_time(), self.end_time());
+        let scale = (end - start) * (end - start);
+        self.evaluate_second_derivative(start + (end - start) * t) * scale
+    }
+}
+
+/// Calculate the value of a spline through `keys` at the given time, holding
+/// the first and last values outside of the keyed range.
+pub(super) fn evaluate<T>(keys: &[HermiteKey<T>], time: f32) -> T
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    let first = &keys[0];
+    let last = &keys[keys.len() - 1];
+
+    if time <= first.time {
+        return first.value;
+    }
+    if time >= last.time {
+        return last.value;
+    }
+
+    let index = segment(keys, time);
+    hermite(&keys[index], &keys[index + 1], time)
+}
+
+/// Calculate the value of a spline through `keys`, like [`evaluate`], starting
+/// the search for the key from `cursor`.
+pub(super) fn evaluate_at<T>(keys: &[HermiteKey<T>], cursor: &mut HermiteCursor, time: f32) -> T
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    let first = &keys[0];
+    let last = &keys[keys.len() - 1];
+
+    if time <= first.time {
+        return first.value;
+    }
+    if time >= last.time {
+        return last.value;
+    }
+
+    let index = cursor.seek(keys, time);
+    hermite(&keys[index], &keys[index + 1], time)
+}
+
+/// Calculate the rate of change of a spline through `keys` at the given time.
+pub(super) fn evaluate_derivative<T>(keys: &[HermiteKey<T>], time: f32) -> T
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    let first = &keys[0];
+    let last = &keys[keys.len() - 1];
+
+    if time < first.time || time > last.time || keys.len() == 1 {
+        return first.value * 0.0;
+    }
+
+    let index = segment(keys, time).min(keys.len() - 2);
+    hermite_derivative(&keys[index], &keys[index + 1], time)
+}
+
+/// Calculate the second derivative of a spline through `keys` at the given
+/// time.
+pub(super) fn evaluate_second_derivative<T>(keys: &[HermiteKey<T>], time: f32) -> T
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    let first = &keys[0];
+    let last = &keys[keys.len() - 1];
+
+    if time < first.time || time > last.time || keys.len() == 1 {
+        return first.value * 0.0;
+    }
+
+    let index = segment(keys, time).min(keys.len() - 2);
+    hermite_second_derivative(&keys[index], &keys[index + 1], time)
+}
+
+/// Index of the last key at or before `time`, found by binary search.
+fn segment<T>(keys: &[HermiteKey<T>], time: f32) -> usize {
+    keys.partition_point(|k| k.time <= time).saturating_sub(1)
+}
+
+/// Evaluate the cubic Hermite segment between two keys.
+fn hermite<T>(a: &HermiteKey<T>, b: &HermiteKey<T>, time: f32) -> T
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    let h = b.time - a.time;
+    let s = (time - a.time) / h;
+    let (s2, s3) = (s * s, s * s * s);
+
+    let h00 = 2.0 * s3 - 3.0 * s2 + 1.0;
+    let h10 = s3 - 2.0 * s2 + s;
+    let h01 = 3.0 * s2 - 2.0 * s3;
+    let h11 = s3 - s2;
+
+    a.value * h00 + a.tangent * (h10 * h) + b.value * h01 + b.tangent * (h11 * h)
+}
+
+/// Evaluate the rate of change of the cubic Hermite segment between two keys.
+fn hermite_derivative<T>(a: &HermiteKey<T>, b: &HermiteKey<T>, time: f32) -> T
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    let h = b.time - a.time;
+    let s = (time - a.time) / h;
+    let s2 = s * s;
+
+    let h00 = 6.0 * s2 - 6.0 * s;
+    let h10 = 3.0 * s2 - 4.0 * s + 1.0;
+    let h01 = 6.0 * s - 6.0 * s2;
+    let h11 = 3.0 * s2 - 2.0 * s;
+
+    a.value * (h00 / h) + a.tangent * h10 + b.value * (h01 / h) + b.tangent * h11
+}
+
+/// Evaluate the second derivative of the cubic Hermite segment between two
+/// keys.
+fn hermite_second_derivative<T>(a: &HermiteKey<T>, b: &HermiteKey<T>, time: f32) -> T
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    let h = b.time - a.time;
+    let s = (time - a.time) / h;
+
+    let h00 = 12.0 * s - 6.0;
+    let h10 = 6.0 * s - 4.0;
+    let h01 = 6.0 - 12.0 * s;
+    let h11 = 6.0 * s - 2.0;
+
+    a.value * (h00 / (h * h))
+        + a.tangent * (h10 / h)
+        + b.value * (h01 / (h * h))
+        + b.tangent * (h11 / h)
+}
+
+fn auto_tangent<T>(points: &[(f32, T)], i: usize, mode: TangentMode) -> T
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    let slope = |a: (f32, T), b: (f32, T)| (b.1 - a.1) * (1.0 / (b.0 - a.0));
+
+    let current = points[i];
+    let prev = if i > 0 { Some(points[i - 1]) } else { None };
+    let next = points.get(i + 1).copied();
+
+    match (mode, prev, next) {
+        (TangentMode::Flat, ..) | (_, None, None) => current.1 * 0.0,
+        (_, Some(prev), None) => slope(prev, current),
+        (_, None, Some(next)) => slope(current, next),
+        (TangentMode::FiniteDifference, Some(prev), Some(next)) => {
+            (slope(prev, current) + slope(current, next)) * 0.5
+        }
+        (TangentMode::CatmullRom, Some(prev), Some(next)) => slope(prev, next),
+    }
+}
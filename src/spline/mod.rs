@@ -0,0 +1,243 @@
+use core::ops::{Add, Mul, Sub};
+
+use crate::vector::{normalize, Cross, Dot, Perp};
+
+mod arc_length;
+mod bezier_path;
+mod bspline;
+pub(crate) mod catmull_rom;
+mod fixed_hermite;
+mod frame;
+mod hermite;
+mod nurbs;
+
+pub use arc_length::ArcLength;
+pub use bezier_path::{BezierPath, BezierSegment, Continuity};
+pub use bspline::BSpline;
+pub use catmull_rom::{CatmullRom, Parameterization};
+pub use fixed_hermite::FixedHermite;
+pub use frame::Frame;
+pub use hermite::{BakedTrack, Hermite, HermiteCursor, HermiteKey, KeyError, TangentMode};
+pub use nurbs::Nurbs;
+
+/// Trait implemented by all splines and paths.
+///
+/// Unlike a [`Lerper`][0], which maps time to interpolation progress, a
+/// spline maps its parameter directly to a value, such as a position.
+///
+/// [0]: ../trait.Lerper.html
+pub trait Spline<T> {
+    /// Calculate the value of the spline at the given _t_, where `0.0` is the
+    /// start of the spline and `1.0` is the end.
+    fn sample(&self, t: f32) -> T;
+
+    /// Calculate the rate of change of the spline with respect to _t_.
+    ///
+    /// By default, this is approximated by finite differences.
+    fn derivative(&self, t: f32) -> T
+    where
+        T: Copy,
+        T: Sub<Output = T>,
+        T: Mul<f32, Output = T>,
+    {
+        const H: f32 = 1.0e-3;
+        let (a, b) = ((t - H).max(0.0), (t + H).min(1.0));
+
+        (self.sample(b) - self.sample(a)) * (1.0 / (b - a))
+    }
+
+    /// Calculate the second derivative of the spline with respect to _t_.
+    ///
+    /// By default, this is approximated by finite differences of
+    /// [`derivative`][0].
+    ///
+    /// [0]: #method.derivative
+    fn second_derivative(&self, t: f32) -> T
+    where
+        T: Copy,
+        T: Sub<Output = T>,
+        T: Mul<f32, Output = T>,
+    {
+        const H: f32 = 1.0e-3;
+        let (a, b) = ((t - H).max(0.0), (t + H).min(1.0));
+
+        (self.derivative(b) - self.derivative(a)) * (1.0 / (b - a))
+    }
+
+    /// Curvature of the spline at the given _t_, which is the reciprocal of
+    /// the radius of the circle that best fits the spline at that point.
+    ///
+    /// # Usage
+    /// ```
+    /// # use core::ops::{Add, Mul, Sub};
+    /// # #[derive(Debug, Clone, Copy, PartialEq)]
+    /// # struct Vec2(f32, f32);
+    /// # impl Add for Vec2 { type Output = Vec2; fn add(self, o: Vec2) -> Vec2 { Vec2(self.0 + o.0, self.1 + o.1) } }
+    /// # impl Sub for Vec2 { type Output = Vec2; fn sub(self, o: Vec2) -> Vec2 { Vec2(self.0 - o.0, self.1 - o.1) } }
+    /// # impl Mul<f32> for Vec2 { type Output = Vec2; fn mul(self, s: f32) -> Vec2 { Vec2(self.0 * s, self.1 * s) } }
+    /// # impl soy::Dot for Vec2 { fn dot(self, o: Vec2) -> f32 { self.0 * o.0 + self.1 * o.1 } }
+    /// use soy::Spline;
+    ///
+    /// let straight = soy::BezierPath::new(Vec2(0.0, 0.0)).line_to(Vec2(5.0, 5.0));
+    /// assert_eq!(straight.curvature(0.5), 0.0);
+    ///
+    /// // Quarter of a circle with radius 2.
+    /// let points = vec![Vec2(2.0, 0.0), Vec2(2.0, 2.0), Vec2(0.0, 2.0)];
+    /// let weights = vec![1.0, core::f32::consts::FRAC_1_SQRT_2, 1.0];
+    /// let arc = soy::Nurbs::new(points, weights, 2);
+    /// assert!((arc.curvature(0.5) - 0.5).abs() < 1.0e-2);
+    /// ```
+    fn curvature(&self, t: f32) -> f32
+    where
+        T: Copy + Dot,
+        T: Sub<Output = T>,
+        T: Mul<f32, Output = T>,
+    {
+        let d1 = self.derivative(t);
+        let d2 = self.second_derivative(t);
+
+        let speed_squared = d1.dot(d1);
+        if speed_squared <= 0.0 {
+            return 0.0;
+        }
+
+        let d12 = d1.dot(d2);
+        let area = (speed_squared * d2.dot(d2) - d12 * d12).max(0.0);
+
+        area.sqrt() / (speed_squared * speed_squared.sqrt())
+    }
+
+    /// Unit direction of travel along the spline at the given _t_.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Spline;
+    ///
+    /// let path = soy::BezierPath::new(0.0).line_to(10.0);
+    /// assert_eq!(path.tangent(0.5), 1.0);
+    /// ```
+    fn tangent(&self, t: f32) -> T
+    where
+        T: Copy + Dot,
+        T: Sub<Output = T>,
+        T: Mul<f32, Output = T>,
+    {
+        normalize(self.derivative(t))
+    }
+
+    /// Unit normal of a two-dimensional spline at the given _t_, which points
+    /// to the left of the direction of travel.
+    ///
+    /// # Usage
+    /// ```
+    /// # use core::ops::{Add, Mul, Sub};
+    /// # #[derive(Debug, Clone, Copy, PartialEq)]
+    /// # struct Vec2(f32, f32);
+    /// # impl Add for Vec2 { type Output = Vec2; fn add(self, o: Vec2) -> Vec2 { Vec2(self.0 + o.0, self.1 + o.1) } }
+    /// # impl Sub for Vec2 { type Output = Vec2; fn sub(self, o: Vec2) -> Vec2 { Vec2(self.0 - o.0, self.1 - o.1) } }
+    /// # impl Mul<f32> for Vec2 { type Output = Vec2; fn mul(self, s: f32) -> Vec2 { Vec2(self.0 * s, self.1 * s) } }
+    /// # impl soy::Dot for Vec2 { fn dot(self, o: Vec2) -> f32 { self.0 * o.0 + self.1 * o.1 } }
+    /// # impl soy::Perp for Vec2 { fn perp(self) -> Vec2 { Vec2(-self.1, self.0) } }
+    /// use soy::Spline;
+    ///
+    /// let path = soy::BezierPath::new(Vec2(0.0, 0.0)).line_to(Vec2(5.0, 0.0));
+    ///
+    /// assert_eq!(path.tangent(0.5), Vec2(1.0, 0.0));
+    /// assert_eq!(path.normal(0.5), Vec2(0.0, 1.0));
+    /// ```
+    fn normal(&self, t: f32) -> T
+    where
+        T: Copy + Dot + Perp,
+        T: Sub<Output = T>,
+        T: Mul<f32, Output = T>,
+    {
+        self.tangent(t).perp()
+    }
+
+    /// Calculate `count` evenly spaced frames along a three-dimensional
+    /// spline.
+    ///
+    /// Frames are rotation minimizing, so an object oriented by them doesn't
+    /// twist unnecessarily along the spline. The first frame's normal is the
+    /// part of `up` perpendicular to the initial direction of travel.
+    fn frames(&self, count: usize, up: T) -> Vec<Frame<T>>
+    where
+        T: Copy + Dot + Cross,
+        T: Add<Output = T>,
+        T: Sub<Output = T>,
+        T: Mul<f32, Output = T>,
+    {
+        frame::frames(self, count, up)
+    }
+
+    /// Bake `count` evenly spaced samples of the spline, from the start to the
+    /// end inclusive.
+    ///
+    /// To space the samples evenly along the length of the spline instead,
+    /// bake an [arc-length parameterized][0] spline.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Spline;
+    ///
+    /// let path = soy::BezierPath::new(0.0).line_to(4.0);
+    /// assert_eq!(path.bake(5), vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+    ///
+    /// let even = soy::BezierPath::new(0.0).cubic_to(0.0, 0.0, 4.0).by_arc_length();
+    /// assert!((even.bake(5)[1] - 1.0).abs() < 1.0e-3);
+    /// ```
+    ///
+    /// [0]: #method.by_arc_length
+    fn bake(&self, count: usize) -> Vec<T> {
+        (0..count).map(|i| self.sample(bake_t(i, count))).collect()
+    }
+
+    /// Fill `buffer` with evenly spaced samples of the spline, from the start
+    /// to the end inclusive.
+    fn bake_into(&self, buffer: &mut [T]) {
+        let count = buffer.len();
+        for (i, value) in buffer.iter_mut().enumerate() {
+            *value = self.sample(bake_t(i, count));
+        }
+    }
+
+    /// Reparameterize the spline by arc-length, so that it's traversed at a
+    /// constant speed as _t_ increases linearly.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Spline;
+    ///
+    /// // Starts slowly, and speeds up towards the end.
+    /// let spline = soy::BezierPath::new(0.0).cubic_to(0.0, 0.0, 10.0);
+    /// assert!(spline.sample(0.25) < 2.5);
+    ///
+    /// let constant = spline.by_arc_length();
+    /// assert!((constant.sample(0.25) - 2.5).abs() < 1.0e-3);
+    /// ```
+    fn by_arc_length(self) -> ArcLength<Self>
+    where
+        Self: Sized,
+        T: Copy + Dot + Sub<Output = T>,
+    {
+        ArcLength::with_samples(self, ArcLength::<Self>::SAMPLES)
+    }
+}
+
+/// _t_ of the `i`th of `count` evenly spaced samples.
+pub(crate) fn bake_t(i: usize, count: usize) -> f32 {
+    if count > 1 {
+        i as f32 / (count - 1) as f32
+    } else {
+        0.0
+    }
+}
+
+/// Map a global _t_ over `segments` equal segments, returning the segment
+/// index and the _t_ local to that segment.
+pub(crate) fn segment(t: f32, segments: usize) -> (usize, f32) {
+    let scaled = t.clamp(0.0, 1.0) * segments as f32;
+    let index = (scaled as usize).min(segments.saturating_sub(1));
+
+    (index, scaled - index as f32)
+}
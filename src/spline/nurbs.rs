@@ -0,0 +1,131 @@
+use core::ops::{Add, Mul, Sub};
+
+use super::bspline::{basis_functions, clamped_knots, domain, validate};
+use super::Spline;
+
+#[derive(Debug)]
+/// Non-uniform rational B-spline (NURBS) curve.
+///
+/// Each control point carries a weight, pulling the curve towards it. This
+/// allows conic sections, such as circular arcs, to be represented exactly.
+pub struct Nurbs<T> {
+    points: Vec<T>,
+    weights: Vec<f32>,
+    degree: usize,
+    knots: Vec<f32>,
+}
+
+impl<T> Nurbs<T>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    /// Create a new clamped NURBS curve, which starts at its first control
+    /// point and ends at its last.
+    ///
+    /// # Panics
+    /// Panics if there are not more points than the degree, or if there is not
+    /// one weight per point.
+    ///
+    /// # Usage
+    /// ```
+    /// # use core::ops::{Add, Mul, Sub};
+    /// # #[derive(Debug, Clone, Copy)]
+    /// # struct Vec2(f32, f32);
+    /// # impl Add for Vec2 { type Output = Vec2; fn add(self, o: Vec2) -> Vec2 { Vec2(self.0 + o.0, self.1 + o.1) } }
+    /// # impl Sub for Vec2 { type Output = Vec2; fn sub(self, o: Vec2) -> Vec2 { Vec2(self.0 - o.0, self.1 - o.1) } }
+    /// # impl Mul<f32> for Vec2 { type Output = Vec2; fn mul(self, s: f32) -> Vec2 { Vec2(self.0 * s, self.1 * s) } }
+    /// use soy::Spline;
+    ///
+    /// // Quarter of a unit circle.
+    /// let points = vec![Vec2(1.0, 0.0), Vec2(1.0, 1.0), Vec2(0.0, 1.0)];
+    /// let weights = vec![1.0, core::f32::consts::FRAC_1_SQRT_2, 1.0];
+    /// let arc = soy::Nurbs::new(points, weights, 2);
+    ///
+    /// for i in 0..=10 {
+    ///     let Vec2(x, y) = arc.sample(i as f32 / 10.0);
+    ///     assert!(((x * x + y * y).sqrt() - 1.0).abs() < 1.0e-5);
+    /// }
+    /// ```
+    pub fn new(points: Vec<T>, weights: Vec<f32>, degree: usize) -> Nurbs<T> {
+        let knots = clamped_knots(points.len(), degree);
+
+        Nurbs::with_knots(points, weights, degree, knots)
+    }
+
+    /// Create a new NURBS curve with an explicit knot vector, such as one
+    /// exported from a CAD package.
+    ///
+    /// # Panics
+    /// Panics if there are not more points than the degree, if there is not
+    /// one weight per point, if there are not exactly
+    /// `points.len() + degree + 1` knots, or if the knots are decreasing.
+    pub fn with_knots(
+        points: Vec<T>,
+        weights: Vec<f32>,
+        degree: usize,
+        knots: Vec<f32>,
+    ) -> Nurbs<T> {
+        validate(points.len(), degree, &knots);
+        assert_eq!(
+            points.len(),
+            weights.len(),
+            "a NURBS curve requires one weight per point"
+        );
+
+        Nurbs {
+            points,
+            weights,
+            degree,
+            knots,
+        }
+    }
+
+    /// Control points of the curve.
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+
+    /// Weights of the control points.
+    pub fn weights(&self) -> &[f32] {
+        &self.weights
+    }
+
+    /// Degree of the curve.
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Knot vector of the curve.
+    pub fn knots(&self) -> &[f32] {
+        &self.knots
+    }
+
+    /// Calculate the value of the curve at the given knot value `u`.
+    pub fn evaluate(&self, u: f32) -> T {
+        let (span, basis) = basis_functions(&self.knots, self.degree, self.points.len(), u);
+        let first = span - self.degree;
+
+        let weighted = |j: usize| basis[j] * self.weights[first + j];
+        let total: f32 = (0..basis.len()).map(weighted).sum();
+
+        (1..basis.len()).fold(self.points[first] * (weighted(0) / total), |acc, j| {
+            acc + self.points[first + j] * (weighted(j) / total)
+        })
+    }
+}
+
+impl<T> Spline<T> for Nurbs<T>
+where
+    T: Copy,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<f32, Output = T>,
+{
+    fn sample(&self, t: f32) -> T {
+        let (start, end) = domain(&self.knots, self.degree, self.points.len());
+        self.evaluate(start + (end - start) * t)
+    }
+}
@@ -0,0 +1,423 @@
+use core::f32::consts::PI;
+
+use core::hash::{Hash, Hasher};
+
+use crate::hash::hash_f32;
+use crate::Lerper;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+/// Lerper following a damped spring from `0.0` to `1.0`.
+///
+/// The spring is solved analytically, so any _t_ can be calculated directly.
+/// _t = 1_ corresponds to the spring's [`duration`][0], by default the time
+/// taken for it to settle, where it snaps to exactly `1.0`.
+///
+/// [0]: #method.duration
+pub struct Spring {
+    /// Mass on the end of the spring.
+    pub(crate) mass: f32,
+    /// Stiffness of the spring.
+    pub(crate) stiffness: f32,
+    /// Damping of the spring.
+    pub(crate) damping: f32,
+    /// Initial velocity, in progress per second.
+    pub(crate) velocity: f32,
+    /// Time spanned by _t_, in seconds.
+    pub(crate) duration: f32,
+}
+
+impl Spring {
+    /// The spring has settled once it stays this close to `1.0`.
+    pub const SETTLE_THRESHOLD: f32 = 1.0e-3;
+
+    /// Create a new spring from its physical properties, like Core
+    /// Animation's `CASpringAnimation`.
+    ///
+    /// # Panics
+    /// Panics if `mass`, `stiffness` or `damping` is not positive. An
+    /// undamped spring oscillates forever, so never settles.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::Lerper;
+    ///
+    /// let spring = soy::Spring::new(1.0, 100.0, 10.0, 0.0);
+    ///
+    /// assert_eq!(spring.calculate(0.0), 0.0);
+    /// assert!(spring.position(0.36) > 1.0);
+    /// assert_eq!(spring.calculate(1.0), 1.0);
+    /// ```
+    pub fn new(mass: f32, stiffness: f32, damping: f32, velocity: f32) -> Spring {
+        assert!(mass > 0.0, "a spring requires a positive mass");
+        assert!(stiffness > 0.0, "a spring requires a positive stiffness");
+        assert!(damping > 0.0, "a spring requires positive damping");
+
+        let mut spring = Spring {
+            mass,
+            stiffness,
+            damping,
+            velocity,
+            duration: 0.0,
+        };
+        spring.duration = spring.settling_duration();
+        spring
+    }
+
+    /// Create a new spring from a damping ratio and response, in seconds,
+    /// like UIKit's `UISpringTimingParameters(dampingRatio:response:)` and
+    /// SwiftUI's `spring(response:dampingFraction:)`.
+    ///
+    /// The response is the period of the spring, ignoring damping. A damping
+    /// ratio of `1.0` settles as fast as possible without overshooting, and
+    /// smaller ratios bounce.
+    ///
+    /// # Panics
+    /// Panics if `damping_ratio` or `response` is not positive.
+    ///
+    /// # Usage
+    /// ```
+    /// let spring = soy::Spring::with_response(0.8, 0.5);
+    ///
+    /// assert!((spring.damping_ratio() - 0.8).abs() < 1.0e-6);
+    /// assert!((spring.response() - 0.5).abs() < 1.0e-6);
+    /// ```
+    pub fn with_response(damping_ratio: f32, response: f32) -> Spring {
+        assert!(
+            damping_ratio > 0.0,
+            "a spring requires a positive damping ratio"
+        );
+        assert!(response > 0.0, "a spring requires a positive response");
+
+        let stiffness = (2.0 * PI / response).powi(2);
+        let damping = 4.0 * PI * damping_ratio / response;
+
+        Spring::new(1.0, stiffness, damping, 0.0)
+    }
+
+    /// Create a new spring which settles within `duration` seconds, like
+    /// UIKit's legacy
+    /// `animate(withDuration:delay:usingSpringWithDamping:initialSpringVelocity:)`.
+    ///
+    /// As with UIKit, `velocity` is relative to the whole distance, so
+    /// `1.0` covers the distance in one second. UIKit doesn't document how it
+    /// derives the spring, so the stiffness is found by bisection for the
+    /// spring to [settle][0] at `duration`, for any damping ratio.
+    ///
+    /// # Usage
+    /// ```
+    /// use soy::{Lerper, Spring};
+    ///
+    /// let spring = Spring::with_duration(0.6, 0.5, 0.0);
+    ///
+    /// assert_eq!(spring.duration(), 0.6);
+    /// assert!(spring.calculate(0.3) > 1.0);
+    ///
+    /// // Critically damped and overdamped springs settle on time, too.
+    /// for &damping_ratio in &[1.0, 2.0] {
+    ///     let spring = Spring::with_duration(0.6, damping_ratio, 0.0);
+    ///     assert!((spring.position(0.6) - 1.0).abs() <= Spring::SETTLE_THRESHOLD);
+    ///     assert!(spring.position(0.5) > 0.99);
+    /// }
+    /// ```
+    ///
+    /// [0]: #method.settling_duration
+    pub fn with_duration(duration: f32, damping_ratio: f32, velocity: f32) -> Spring {
+        const ITERATIONS: usize = 64;
+
+        let damping_ratio = damping_ratio.max(1.0e-3);
+        let spring = |omega: f32| {
+            // Damping is derived from the stiffness's square root, so a
+            // damping ratio of `1.0` stays exactly critical.
+            let stiffness = omega * omega;
+            let damping = 2.0 * damping_ratio * stiffness.sqrt();
+            Spring::new(1.0, stiffness, damping, velocity)
+        };
+
+        // Bracket the natural frequency, starting from the frequency whose
+        // underdamped envelope, `e^(-ζωt)`, reaches the threshold at
+        // `duration`. Stiffer springs settle sooner.
+        let guess = (1.0 / Spring::SETTLE_THRESHOLD).ln() / (damping_ratio * duration);
+        let (mut low, mut high) = (guess, guess);
+        for _ in 0..ITERATIONS {
+            if spring(low).duration <= duration {
+                low *= 0.5;
+            } else if spring(high).duration > duration {
+                high *= 2.0;
+            } else {
+                break;
+            }
+        }
+
+        for _ in 0..ITERATIONS {
+            let middle = (low * high).sqrt();
+            if spring(middle).duration > duration {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        spring(high).timed(duration)
+    }
+
+    /// Create a new spring from react-spring's configuration, where tension
+    /// and friction are the stiffness and damping.
+    ///
+    /// # Usage
+    /// ```
+    /// // react-spring's `config.default`.
+    /// let spring = soy::Spring::from_react_spring(1.0, 170.0, 26.0);
+    ///
+    /// assert_eq!(spring.stiffness(), 170.0);
+    /// assert!(spring.damping_ratio() < 1.0);
+    /// ```
+    pub fn from_react_spring(mass: f32, tension: f32, friction: f32) -> Spring {
+        Spring::new(mass, tension, friction, 0.0)
+    }
+
+    /// Create a new spring from Framer Motion's duration, in seconds, and
+    /// bounce.
+    ///
+    /// Like Framer Motion, the damping ratio is `1.0 - bounce`, limited to
+    /// `[0.05, 1]`, and the stiffness is chosen for the spring's envelope to
+    /// decay to within `0.001` of the target by `duration`, which is limited
+    /// to `[0.01, 10]`.
+    ///
+    /// # Usage
+    /// ```
+    /// let spring = soy::Spring::from_framer(0.8, 0.25);
+    ///
+    /// assert!((spring.damping_ratio() - 0.75).abs() < 1.0e-5);
+    /// assert!((spring.position(0.8) - 1.0).abs() < 2.0e-3);
+    /// ```
+    pub fn from_framer(duration: f32, bounce: f32) -> Spring {
+        const SAFE_MIN: f32 = 1.0e-3;
+        const ITERATIONS: usize = 12;
+
+        let zeta = (1.0 - bounce).clamp(0.05, 1.0);
+        let duration = duration.clamp(0.01, 10.0);
+
+        // Framer Motion's envelope, whose root is the natural frequency.
+        let envelope = |omega: f32| {
+            let decay = (-omega * zeta * duration).exp();
+
+            if zeta < 1.0 {
+                let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+                SAFE_MIN - zeta * omega / omega_d * decay
+            } else {
+                decay * (omega * duration + 1.0) - SAFE_MIN
+            }
+        };
+
+        let mut omega = 5.0 / duration;
+        for _ in 0..ITERATIONS {
+            let h = omega * 1.0e-3;
+            let slope = (envelope(omega + h) - envelope(omega - h)) / (2.0 * h);
+            if slope == 0.0 {
+                break;
+            }
+            omega -= envelope(omega) / slope;
+        }
+
+        // Framer Motion falls back to a fixed spring if the search fails.
+        if !omega.is_finite() || omega <= 0.0 {
+            return Spring::new(1.0, 100.0, 10.0, 0.0);
+        }
+
+        Spring::new(1.0, omega * omega, 2.0 * zeta * omega, 0.0)
+    }
+
+    /// Set the time spanned by _t_, in seconds, instead of the settling
+    /// time.
+    pub fn timed(mut self, duration: f32) -> Spring {
+        self.duration = duration;
+        self
+    }
+
+    /// Mass on the end of the spring.
+    pub fn mass(&self) -> f32 {
+        self.mass
+    }
+
+    /// Stiffness of the spring.
+    pub fn stiffness(&self) -> f32 {
+        self.stiffness
+    }
+
+    /// Damping of the spring.
+    pub fn damping(&self) -> f32 {
+        self.damping
+    }
+
+    /// Initial velocity, in progress per second.
+    pub fn velocity(&self) -> f32 {
+        self.velocity
+    }
+
+    /// Time spanned by _t_, in seconds.
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    /// Ratio of the damping to the critical damping.
+    pub fn damping_ratio(&self) -> f32 {
+        self.damping / (2.0 * (self.stiffness * self.mass).sqrt())
+    }
+
+    /// Period of the spring, in seconds, ignoring damping.
+    pub fn response(&self) -> f32 {
+        2.0 * PI / self.natural_frequency()
+    }
+
+    /// Position of the spring after `time` seconds, without snapping to
+    /// `1.0` at the end of the duration.
+    pub fn position(&self, time: f32) -> f32 {
+        1.0 - self.displacement(time)
+    }
+
+    /// Time taken for the spring to settle within
+    /// [`SETTLE_THRESHOLD`][0] of `1.0`, in seconds.
+    ///
+    /// [0]: #associatedconstant.SETTLE_THRESHOLD
+    pub fn settling_duration(&self) -> f32 {
+        const ITERATIONS: usize = 64;
+
+        // The envelope bounding the displacement eventually only shrinks, and
+        // crosses the threshold once, so bracket the crossing and bisect.
+        let settled = |time: f32| self.envelope(time) < Spring::SETTLE_THRESHOLD;
+
+        let (mut low, mut high) = (0.0, 1.0 / self.natural_frequency());
+        for _ in 0..ITERATIONS {
+            if settled(high) {
+                break;
+            }
+            low = high;
+            high *= 2.0;
+        }
+
+        for _ in 0..ITERATIONS {
+            let middle = 0.5 * (low + high);
+            if settled(middle) {
+                high = middle;
+            } else {
+                low = middle;
+            }
+        }
+        high
+    }
+
+    fn natural_frequency(&self) -> f32 {
+        (self.stiffness / self.mass).sqrt()
+    }
+
+    /// Distance from `1.0` after `time` seconds.
+    fn displacement(&self, time: f32) -> f32 {
+        let omega = self.natural_frequency();
+        let zeta = self.damping_ratio();
+        let v0 = self.velocity;
+
+        if zeta < 1.0 {
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+            let b = (zeta * omega - v0) / omega_d;
+
+            (-zeta * omega * time).exp() * ((omega_d * time).cos() + b * (omega_d * time).sin())
+        } else if zeta == 1.0 {
+            (-omega * time).exp() * (1.0 + (omega - v0) * time)
+        } else {
+            let (r1, r2, a, b) = self.overdamped();
+            a * (r1 * time).exp() + b * (r2 * time).exp()
+        }
+    }
+
+    /// Upper bound on the displacement after `time` seconds.
+    fn envelope(&self, time: f32) -> f32 {
+        let omega = self.natural_frequency();
+        let zeta = self.damping_ratio();
+        let v0 = self.velocity;
+
+        if zeta < 1.0 {
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+            let b = (zeta * omega - v0) / omega_d;
+
+            (-zeta * omega * time).exp() * (1.0 + b * b).sqrt()
+        } else if zeta == 1.0 {
+            (-omega * time).exp() * (1.0 + (omega - v0).abs() * time)
+        } else {
+            let (r1, _, a, b) = self.overdamped();
+            (a.abs() + b.abs()) * (r1 * time).exp()
+        }
+    }
+
+    /// Exponents, slowest first, and coefficients of an overdamped spring.
+    fn overdamped(&self) -> (f32, f32, f32, f32) {
+        let omega = self.natural_frequency();
+        let zeta = self.damping_ratio();
+        let root = (zeta * zeta - 1.0).sqrt();
+
+        let r1 = -omega * (zeta - root);
+        let r2 = -omega * (zeta + root);
+        let a = (-self.velocity - r2) / (r1 - r2);
+
+        (r1, r2, a, 1.0 - a)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Spring {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Spring, D::Error> {
+        // Same fields as the derived `Serialize`, checked like `Spring::new`.
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Spring")]
+        struct Fields {
+            mass: f32,
+            stiffness: f32,
+            damping: f32,
+            velocity: f32,
+            duration: f32,
+        }
+
+        let Fields {
+            mass,
+            stiffness,
+            damping,
+            velocity,
+            duration,
+        } = <Fields as serde::Deserialize>::deserialize(deserializer)?;
+
+        let positive = |value: f32| value > 0.0;
+        if !positive(mass) || !positive(stiffness) || !positive(damping) {
+            return Err(serde::de::Error::custom(
+                "a spring requires a positive mass, stiffness and damping",
+            ));
+        }
+
+        Ok(Spring {
+            mass,
+            stiffness,
+            damping,
+            velocity,
+            duration,
+        })
+    }
+}
+
+impl Hash for Spring {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f32(self.mass, state);
+        hash_f32(self.stiffness, state);
+        hash_f32(self.damping, state);
+        hash_f32(self.velocity, state);
+        hash_f32(self.duration, state);
+    }
+}
+
+impl Lerper for Spring {
+    fn calculate(&self, t: f32) -> f32 {
+        if t >= 1.0 {
+            1.0
+        } else {
+            self.position(t * self.duration)
+        }
+    }
+}
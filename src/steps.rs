@@ -0,0 +1,123 @@
+use crate::Lerper;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// Where the jumps of a [`Steps`][0] lerper happen, matching CSS's
+/// `<step-position>`.
+///
+/// [0]: struct.Steps.html
+pub enum StepPosition {
+    /// The first jump happens at the start, like CSS's `jump-start`, or
+    /// `start`.
+    JumpStart,
+    /// The last jump happens at the end, like CSS's `jump-end`, or `end`.
+    JumpEnd,
+    /// There's no jump at either end, like CSS's `jump-none`.
+    JumpNone,
+    /// There are jumps at both ends, like CSS's `jump-both`.
+    JumpBoth,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Lerper which jumps between a number of equal steps, matching CSS's
+/// `steps(count, position)` timing function.
+///
+/// _t_ is clamped to `[0, 1]`, so the curve holds at either end.
+///
+/// # Usage
+/// ```
+/// use soy::{Lerper, StepPosition, Steps};
+///
+/// let ticks = Steps::new(4, StepPosition::JumpEnd);
+/// assert_eq!(ticks.calculate(0.0), 0.0);
+/// assert_eq!(ticks.calculate(0.3), 0.25);
+/// assert_eq!(ticks.calculate(1.0), 1.0);
+///
+/// let both = Steps::new(3, StepPosition::JumpBoth);
+/// assert_eq!(both.calculate(0.0), 0.25);
+/// assert_eq!(both.calculate(0.9), 0.75);
+/// assert_eq!(both.calculate(1.0), 1.0);
+/// ```
+pub struct Steps {
+    count: u32,
+    position: StepPosition,
+}
+
+impl Steps {
+    /// CSS's `step-start` keyword, `steps(1, jump-start)`.
+    pub const STEP_START: Steps = Steps {
+        count: 1,
+        position: StepPosition::JumpStart,
+    };
+
+    /// CSS's `step-end` keyword, `steps(1, jump-end)`.
+    pub const STEP_END: Steps = Steps {
+        count: 1,
+        position: StepPosition::JumpEnd,
+    };
+
+    /// Create a new steps lerper with the given number of steps.
+    ///
+    /// # Panics
+    /// Panics if `count` is zero, or less than two for
+    /// [`JumpNone`][0], like CSS.
+    ///
+    /// [0]: enum.StepPosition.html#variant.JumpNone
+    pub fn new(count: u32, position: StepPosition) -> Steps {
+        let min = match position {
+            StepPosition::JumpNone => 2,
+            _ => 1,
+        };
+        assert!(count >= min, "steps requires at least {} steps", min);
+
+        Steps { count, position }
+    }
+
+    /// Number of steps.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Where the jumps happen.
+    pub fn position(&self) -> StepPosition {
+        self.position
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Steps {
+    /// Steps are serialized as their count and position, like CSS.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&(self.count, self.position), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Steps {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Steps, D::Error> {
+        let (count, position) =
+            <(u32, StepPosition) as serde::Deserialize>::deserialize(deserializer)?;
+        if count == 0 || (count == 1 && position == StepPosition::JumpNone) {
+            return Err(serde::de::Error::custom("too few steps for the position"));
+        }
+
+        Ok(Steps { count, position })
+    }
+}
+
+impl Lerper for Steps {
+    fn calculate(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        let count = self.count as f32;
+
+        let (offset, jumps) = match self.position {
+            StepPosition::JumpStart => (1.0, count),
+            StepPosition::JumpEnd => (0.0, count),
+            StepPosition::JumpNone => (0.0, count - 1.0),
+            StepPosition::JumpBoth => (1.0, count + 1.0),
+        };
+
+        ((t * count).floor() + offset).min(jumps) / jumps
+    }
+}
@@ -0,0 +1,151 @@
+//! Async integration, enabled with the `async` feature.
+//!
+//! Animations are driven by a stream of frame deltas, in seconds, such as
+//! the frame callbacks of an async UI framework, so any runtime can be used.
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+
+use crate::Lerper;
+
+#[derive(Debug)]
+/// Stream of eased progress, from `0.0` to `1.0`, advanced by each frame
+/// delta of another stream.
+///
+/// The stream ends after yielding the progress at _t = 1_, or when the frames
+/// end.
+///
+/// # Usage
+/// ```
+/// use futures::{executor::block_on, stream, StreamExt};
+/// use soy::stream::TweenStream;
+///
+/// let frames = stream::iter(vec![0.25; 8]);
+/// let values: Vec<f32> = block_on(TweenStream::new(frames, 1.0, soy::Linear).collect());
+///
+/// assert_eq!(values, vec![0.25, 0.5, 0.75, 1.0]);
+/// ```
+pub struct TweenStream<S, L> {
+    frames: S,
+    lerper: L,
+    duration: f32,
+    elapsed: f32,
+    done: bool,
+}
+
+impl<S, L> TweenStream<S, L>
+where
+    S: Stream<Item = f32> + Unpin,
+    L: Lerper + Unpin,
+{
+    /// Create a new stream, tweening over `duration` seconds of `frames`.
+    pub fn new(frames: S, duration: f32, lerper: L) -> TweenStream<S, L> {
+        TweenStream {
+            frames,
+            lerper,
+            duration,
+            elapsed: 0.0,
+            done: false,
+        }
+    }
+
+    /// Time tweened so far, in seconds.
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// Whether the tween has completed.
+    pub fn is_finished(&self) -> bool {
+        self.done
+    }
+
+    /// Future completing, with the final progress, once the tween has
+    /// finished.
+    ///
+    /// # Usage
+    /// ```
+    /// use futures::{executor::block_on, stream};
+    /// use soy::stream::TweenStream;
+    ///
+    /// let frames = stream::iter(vec![0.1; 20]);
+    /// let last = block_on(TweenStream::new(frames, 1.0, soy::EASE_OUT).finished());
+    ///
+    /// assert_eq!(last, 1.0);
+    /// ```
+    pub fn finished(self) -> Finished<S, L> {
+        Finished {
+            stream: self,
+            last: 0.0,
+        }
+    }
+}
+
+impl<S, L> Stream for TweenStream<S, L>
+where
+    S: Stream<Item = f32> + Unpin,
+    L: Lerper + Unpin,
+{
+    type Item = f32;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<f32>> {
+        let this = self.get_mut();
+
+        if this.done {
+            return Poll::Ready(None);
+        }
+
+        match Pin::new(&mut this.frames).poll_next(cx) {
+            Poll::Ready(Some(dt)) => {
+                this.elapsed += dt;
+
+                let t = if this.duration > 0.0 {
+                    (this.elapsed / this.duration).min(1.0)
+                } else {
+                    1.0
+                };
+                this.done = t >= 1.0;
+
+                Poll::Ready(Some(this.lerper.calculate(t)))
+            }
+            Poll::Ready(None) => {
+                this.done = true;
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[derive(Debug)]
+/// Future completing once a [`TweenStream`][0] has finished.
+///
+/// This is created using [`TweenStream::finished`][1].
+///
+/// [0]: struct.TweenStream.html
+/// [1]: struct.TweenStream.html#method.finished
+pub struct Finished<S, L> {
+    stream: TweenStream<S, L>,
+    last: f32,
+}
+
+impl<S, L> Future for Finished<S, L>
+where
+    S: Stream<Item = f32> + Unpin,
+    L: Lerper + Unpin,
+{
+    type Output = f32;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<f32> {
+        let this = self.get_mut();
+
+        loop {
+            match Pin::new(&mut this.stream).poll_next(cx) {
+                Poll::Ready(Some(value)) => this.last = value,
+                Poll::Ready(None) => return Poll::Ready(this.last),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
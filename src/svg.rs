@@ -0,0 +1,508 @@
+use core::fmt;
+use core::ops::{Add, Mul, Sub};
+
+use crate::spline::BezierSegment;
+use crate::Lerper;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Point(f32, f32);
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point(self.0 - other.0, self.1 - other.1)
+    }
+}
+
+impl Mul<f32> for Point {
+    type Output = Point;
+
+    fn mul(self, scale: f32) -> Point {
+        Point(self.0 * scale, self.1 * scale)
+    }
+}
+
+type Segment = BezierSegment<Point>;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Error parsing an SVG path string.
+pub enum SvgPathError {
+    /// Path data must start with a move command.
+    MissingMove,
+    /// A command was missing one of its arguments, at the given byte offset.
+    MissingNumber(usize),
+    /// Unknown character, at the given byte offset.
+    UnexpectedCharacter(char, usize),
+    /// The command is valid SVG, but isn't supported. Only elliptical arcs,
+    /// `A`, are unsupported.
+    UnsupportedCommand(char),
+}
+
+impl fmt::Display for SvgPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SvgPathError::MissingMove => write!(f, "path data must start with a move command"),
+            SvgPathError::MissingNumber(at) => write!(f, "missing number at offset {}", at),
+            SvgPathError::UnexpectedCharacter(c, at) => {
+                write!(f, "unexpected character `{}` at offset {}", c, at)
+            }
+            SvgPathError::UnsupportedCommand(c) => write!(f, "unsupported path command `{}`", c),
+        }
+    }
+}
+
+impl std::error::Error for SvgPathError {}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Subpath {
+    start: Point,
+    segments: Vec<Segment>,
+    closed: bool,
+}
+
+impl Subpath {
+    fn end(&self) -> Point {
+        self.segments.last().map_or(self.start, |s| s.to)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// SVG path, normalized to absolute cubic bezier segments.
+///
+/// Every command except elliptical arcs is supported, and formatting the
+/// path with `Display` gives equivalent path data using only `M`, `C` and
+/// `Z`.
+///
+/// # Usage
+/// ```
+/// let path = soy::SvgPath::parse("M0 0 h10 v10 z").unwrap();
+/// assert_eq!(path.segment_count(), 3);
+/// ```
+pub struct SvgPath {
+    subpaths: Vec<Subpath>,
+}
+
+impl SvgPath {
+    /// Parse SVG path data, such as the `d` attribute of a `<path>`.
+    pub fn parse(data: &str) -> Result<SvgPath, SvgPathError> {
+        Parser::new(data).parse()
+    }
+
+    /// Total number of cubic segments in the path.
+    pub fn segment_count(&self) -> usize {
+        self.subpaths.iter().map(|s| s.segments.len()).sum()
+    }
+}
+
+impl fmt::Display for SvgPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, subpath) in self.subpaths.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "M{} {}", subpath.start.0, subpath.start.1)?;
+
+            for s in &subpath.segments {
+                write!(
+                    f,
+                    " C{} {} {} {} {} {}",
+                    s.c1.0, s.c1.1, s.c2.0, s.c2.1, s.to.0, s.to.1
+                )?;
+            }
+            if subpath.closed {
+                write!(f, " Z")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+struct Parser<'a> {
+    data: &'a str,
+    position: usize,
+    subpaths: Vec<Subpath>,
+    current: Point,
+    /// Control point reflected by a following `S` or `T` command.
+    last_control: Option<(char, Point)>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(data: &'a str) -> Parser<'a> {
+        Parser {
+            data,
+            position: 0,
+            subpaths: Vec::new(),
+            current: Point(0.0, 0.0),
+            last_control: None,
+        }
+    }
+
+    fn parse(mut self) -> Result<SvgPath, SvgPathError> {
+        let mut command = None;
+
+        loop {
+            self.skip_separators();
+            let next = match self.data[self.position..].chars().next() {
+                Some(next) => next,
+                None => break,
+            };
+
+            if next.is_ascii_alphabetic() {
+                if !"MLHVCSQTAZ".contains(next.to_ascii_uppercase()) {
+                    return Err(SvgPathError::UnexpectedCharacter(next, self.position));
+                }
+                self.position += 1;
+                command = Some(next);
+
+                if next == 'z' || next == 'Z' {
+                    self.close()?;
+                    continue;
+                }
+            }
+
+            match command {
+                Some(c) => {
+                    self.command(c)?;
+
+                    // Extra coordinates after a move are implicit lines.
+                    command = match c {
+                        'M' => Some('L'),
+                        'm' => Some('l'),
+                        c => Some(c),
+                    };
+                }
+                None if next.is_ascii_digit() || next == '-' || next == '.' => {
+                    return Err(SvgPathError::MissingMove)
+                }
+                None => return Err(SvgPathError::UnexpectedCharacter(next, self.position)),
+            }
+        }
+
+        Ok(SvgPath {
+            subpaths: self.subpaths,
+        })
+    }
+
+    fn command(&mut self, command: char) -> Result<(), SvgPathError> {
+        let relative = command.is_ascii_lowercase();
+        let origin = if relative {
+            self.current
+        } else {
+            Point(0.0, 0.0)
+        };
+        let current = self.current;
+
+        if command != 'M' && command != 'm' && self.subpaths.is_empty() {
+            return Err(SvgPathError::MissingMove);
+        }
+
+        match command.to_ascii_uppercase() {
+            'M' => {
+                let point = origin + self.point()?;
+                self.subpaths.push(Subpath {
+                    start: point,
+                    segments: Vec::new(),
+                    closed: false,
+                });
+                self.current = point;
+                self.last_control = None;
+            }
+            'L' => {
+                let to = origin + self.point()?;
+                self.line_to(to);
+            }
+            'H' => {
+                let x = self.number()? + if relative { current.0 } else { 0.0 };
+                self.line_to(Point(x, current.1));
+            }
+            'V' => {
+                let y = self.number()? + if relative { current.1 } else { 0.0 };
+                self.line_to(Point(current.0, y));
+            }
+            'C' => {
+                let c1 = origin + self.point()?;
+                let c2 = origin + self.point()?;
+                let to = origin + self.point()?;
+                self.cubic_to(c1, c2, to, 'C');
+            }
+            'S' => {
+                let c1 = self.reflected('C');
+                let c2 = origin + self.point()?;
+                let to = origin + self.point()?;
+                self.cubic_to(c1, c2, to, 'C');
+            }
+            'Q' => {
+                let control = origin + self.point()?;
+                let to = origin + self.point()?;
+                self.quadratic_to(control, to);
+            }
+            'T' => {
+                let control = self.reflected('Q');
+                let to = origin + self.point()?;
+                self.quadratic_to(control, to);
+            }
+            _ => return Err(SvgPathError::UnsupportedCommand(command)),
+        }
+
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<(), SvgPathError> {
+        let (start, end) = match self.subpaths.last() {
+            Some(subpath) => (subpath.start, subpath.end()),
+            None => return Err(SvgPathError::MissingMove),
+        };
+
+        // Close with an explicit line, so every segment can be morphed.
+        if start != end {
+            self.line_to(start);
+        }
+
+        let subpath = self.subpaths.last_mut().expect("subpath exists");
+        subpath.closed = true;
+        self.current = start;
+        self.last_control = None;
+
+        Ok(())
+    }
+
+    fn reflected(&self, kind: char) -> Point {
+        match self.last_control {
+            Some((last, control)) if last == kind => self.current * 2.0 - control,
+            _ => self.current,
+        }
+    }
+
+    fn line_to(&mut self, to: Point) {
+        let from = self.current;
+        let c1 = from + (to - from) * (1.0 / 3.0);
+        let c2 = from + (to - from) * (2.0 / 3.0);
+
+        self.cubic_to(c1, c2, to, 'L');
+    }
+
+    fn quadratic_to(&mut self, control: Point, to: Point) {
+        let from = self.current;
+        let c1 = from + (control - from) * (2.0 / 3.0);
+        let c2 = to + (control - to) * (2.0 / 3.0);
+
+        self.cubic_to(c1, c2, to, 'Q');
+        self.last_control = Some(('Q', control));
+    }
+
+    fn cubic_to(&mut self, c1: Point, c2: Point, to: Point, kind: char) {
+        let from = self.current;
+        let subpath = self.subpaths.last_mut().expect("subpath exists");
+
+        subpath.segments.push(Segment { from, c1, c2, to });
+        self.current = to;
+        self.last_control = Some((kind, c2));
+    }
+
+    fn point(&mut self) -> Result<Point, SvgPathError> {
+        Ok(Point(self.number()?, self.number()?))
+    }
+
+    fn number(&mut self) -> Result<f32, SvgPathError> {
+        self.skip_separators();
+
+        let bytes = self.data.as_bytes();
+        let start = self.position;
+        let mut end = start;
+        let mut seen_dot = false;
+
+        if end < bytes.len() && (bytes[end] == b'-' || bytes[end] == b'+') {
+            end += 1;
+        }
+        while end < bytes.len() {
+            match bytes[end] {
+                b'0'..=b'9' => end += 1,
+                b'.' if !seen_dot => {
+                    seen_dot = true;
+                    end += 1;
+                }
+                b'e' | b'E' => {
+                    end += 1;
+                    if end < bytes.len() && (bytes[end] == b'-' || bytes[end] == b'+') {
+                        end += 1;
+                    }
+                    while end < bytes.len() && bytes[end].is_ascii_digit() {
+                        end += 1;
+                    }
+                    break;
+                }
+                _ => break,
+            }
+        }
+
+        match self.data[start..end].parse() {
+            Ok(number) => {
+                self.position = end;
+                Ok(number)
+            }
+            Err(_) => Err(SvgPathError::MissingNumber(start)),
+        }
+    }
+
+    fn skip_separators(&mut self) {
+        let rest = &self.data[self.position..];
+        let trimmed = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+        self.position += rest.len() - trimmed.len();
+    }
+}
+
+#[derive(Debug)]
+/// Morph between two SVG paths, such as icons, eased by any lerper.
+///
+/// The paths are normalized to cubic segments, and the path with fewer
+/// segments has its longest segments split until both paths match, like web
+/// animation libraries do. If one path has fewer subpaths, the extra
+/// subpaths grow from the end of its last subpath.
+///
+/// # Usage
+/// ```
+/// use soy::{PathMorph, SvgPath};
+///
+/// let triangle = "M0 0 L10 0 L10 10 Z";
+/// let square = "M0 0 H10 V10 H0 Z";
+/// let morph = PathMorph::new(triangle, square).unwrap();
+///
+/// // The triangle's longest edge was split to match the square.
+/// assert_eq!(morph.sample(soy::EASE_IN_OUT, 0.0).segment_count(), 4);
+/// assert_eq!(morph.sample(soy::EASE_IN_OUT, 1.0), SvgPath::parse(square).unwrap());
+///
+/// let d = morph.sample(soy::EASE_IN_OUT, 0.5).to_string();
+/// assert!(d.starts_with("M0 0 C"));
+/// ```
+pub struct PathMorph {
+    from: SvgPath,
+    to: SvgPath,
+}
+
+impl PathMorph {
+    /// Create a new morph between two paths of SVG path data.
+    pub fn new(from: &str, to: &str) -> Result<PathMorph, SvgPathError> {
+        let (mut from, mut to) = (SvgPath::parse(from)?, SvgPath::parse(to)?);
+        normalize(&mut from, &mut to);
+
+        Ok(PathMorph { from, to })
+    }
+
+    /// Calculate the path at `t`, eased with the given lerper. _t_ is
+    /// clamped to `[0, 1]`.
+    pub fn sample<L: Lerper>(&self, lerper: L, t: f32) -> SvgPath {
+        let t = lerper.calculate(t.clamp(0.0, 1.0));
+        let mix = |a: Point, b: Point| a + (b - a) * t;
+
+        let subpaths = self
+            .from
+            .subpaths
+            .iter()
+            .zip(&self.to.subpaths)
+            .map(|(a, b)| Subpath {
+                start: mix(a.start, b.start),
+                segments: a
+                    .segments
+                    .iter()
+                    .zip(&b.segments)
+                    .map(|(a, b)| Segment {
+                        from: mix(a.from, b.from),
+                        c1: mix(a.c1, b.c1),
+                        c2: mix(a.c2, b.c2),
+                        to: mix(a.to, b.to),
+                    })
+                    .collect(),
+                closed: if t < 0.5 { a.closed } else { b.closed },
+            })
+            .collect();
+
+        SvgPath { subpaths }
+    }
+}
+
+/// Give two paths the same number of subpaths, and each pair of subpaths the
+/// same number of segments.
+fn normalize(a: &mut SvgPath, b: &mut SvgPath) {
+    pad_subpaths(a, b.subpaths.len());
+    pad_subpaths(b, a.subpaths.len());
+
+    for (a, b) in a.subpaths.iter_mut().zip(&mut b.subpaths) {
+        let count = a.segments.len().max(b.segments.len()).max(1);
+        split_segments(a, count);
+        split_segments(b, count);
+    }
+}
+
+fn pad_subpaths(path: &mut SvgPath, count: usize) {
+    let point = path.subpaths.last().map_or(Point(0.0, 0.0), Subpath::end);
+
+    while path.subpaths.len() < count {
+        path.subpaths.push(Subpath {
+            start: point,
+            segments: Vec::new(),
+            closed: false,
+        });
+    }
+}
+
+/// Split the longest segments of `subpath` until it has `count` segments.
+fn split_segments(subpath: &mut Subpath, count: usize) {
+    if subpath.segments.is_empty() {
+        let start = subpath.start;
+        subpath.segments.push(Segment {
+            from: start,
+            c1: start,
+            c2: start,
+            to: start,
+        });
+    }
+
+    let chord = |s: &Segment| {
+        let Point(x, y) = s.to - s.from;
+        x * x + y * y
+    };
+
+    while subpath.segments.len() < count {
+        let longest = (0..subpath.segments.len())
+            .max_by(|&i, &j| chord(&subpath.segments[i]).total_cmp(&chord(&subpath.segments[j])))
+            .expect("subpath has segments");
+
+        let (first, second) = split(&subpath.segments[longest]);
+        subpath.segments[longest] = first;
+        subpath.segments.insert(longest + 1, second);
+    }
+}
+
+/// Split a segment in half, with de Casteljau's algorithm.
+fn split(s: &Segment) -> (Segment, Segment) {
+    let mid = |a: Point, b: Point| (a + b) * 0.5;
+
+    let (ab, bc, cd) = (mid(s.from, s.c1), mid(s.c1, s.c2), mid(s.c2, s.to));
+    let (abc, bcd) = (mid(ab, bc), mid(bc, cd));
+    let center = mid(abc, bcd);
+
+    (
+        Segment {
+            from: s.from,
+            c1: ab,
+            c2: abc,
+            to: center,
+        },
+        Segment {
+            from: center,
+            c1: bcd,
+            c2: cd,
+            to: s.to,
+        },
+    )
+}
@@ -0,0 +1,54 @@
+#[cfg(feature = "unicode-segmentation")]
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::Lerper;
+
+/// Reveal the start of `text` at `t`, for typewriter effects.
+///
+/// The eased progression decides the fraction of `text` shown, counted in
+/// characters. With the `unicode-segmentation` feature, text is counted in
+/// grapheme clusters instead, so combined characters, such as emoji with
+/// skin tones, appear all at once.
+///
+/// # Usage
+/// ```
+/// let text = "Hello, world!";
+///
+/// assert_eq!(soy::reveal(text, soy::Linear, 0.0), "");
+/// assert_eq!(soy::reveal(text, soy::Linear, 0.4), "Hello");
+/// assert_eq!(soy::reveal(text, soy::Linear, 1.0), text);
+/// ```
+pub fn reveal<L: Lerper>(text: &str, lerper: L, t: f32) -> &str {
+    let count = reveal_count(text, lerper, t);
+    if count == 0 {
+        return "";
+    }
+
+    match ends(text).nth(count - 1) {
+        Some(end) => &text[..end],
+        None => text,
+    }
+}
+
+/// Number of characters, or grapheme clusters with the `unicode-segmentation`
+/// feature, of `text` shown at `t` by [`reveal`][0].
+///
+/// [0]: fn.reveal.html
+pub fn reveal_count<L: Lerper>(text: &str, lerper: L, t: f32) -> usize {
+    let total = ends(text).count();
+    let progress = lerper.calculate(t).clamp(0.0, 1.0);
+
+    ((progress * total as f32).floor() as usize).min(total)
+}
+
+/// Byte offset of the end of each unit of `text`.
+#[cfg(feature = "unicode-segmentation")]
+fn ends(text: &str) -> impl Iterator<Item = usize> + '_ {
+    text.grapheme_indices(true).map(|(i, g)| i + g.len())
+}
+
+/// Byte offset of the end of each unit of `text`.
+#[cfg(not(feature = "unicode-segmentation"))]
+fn ends(text: &str) -> impl Iterator<Item = usize> + '_ {
+    text.char_indices().map(|(i, c)| i + c.len_utf8())
+}
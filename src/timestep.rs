@@ -0,0 +1,101 @@
+use core::ops::{Add, Mul, Sub};
+
+use crate::{lerp, Linear};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Accumulator turning variable frame times into fixed simulation steps.
+///
+/// Each frame, [`advance`][0] returns how many fixed steps to simulate, and
+/// [`alpha`][1] how far the frame is between the last two simulated states,
+/// for rendering with [`lerp_states`][2].
+///
+/// # Usage
+/// ```
+/// let mut timestep = soy::FixedTimestep::new(0.01);
+/// let (mut previous, mut current) = (0.0, 0.0);
+///
+/// for _ in 0..timestep.advance(0.025) {
+///     previous = current;
+///     current += 1.0;
+/// }
+///
+/// assert_eq!(current, 2.0);
+/// assert!((timestep.alpha() - 0.5).abs() < 1.0e-4);
+/// assert!((soy::lerp_states(previous, current, timestep.alpha()) - 1.5).abs() < 1.0e-4);
+/// ```
+///
+/// [0]: #method.advance
+/// [1]: #method.alpha
+/// [2]: fn.lerp_states.html
+pub struct FixedTimestep {
+    /// Duration of each simulation step, in seconds.
+    pub step: f32,
+    /// Most steps taken in a single frame.
+    pub max_steps: usize,
+    accumulator: f32,
+}
+
+impl FixedTimestep {
+    /// Default most steps taken in a single frame.
+    pub const MAX_STEPS: usize = 8;
+
+    /// Create a new accumulator, taking steps of `step` seconds.
+    ///
+    /// # Panics
+    /// Panics if `step` isn't positive.
+    pub fn new(step: f32) -> FixedTimestep {
+        assert!(step > 0.0, "a fixed timestep requires a positive step");
+
+        FixedTimestep {
+            step,
+            max_steps: FixedTimestep::MAX_STEPS,
+            accumulator: 0.0,
+        }
+    }
+
+    /// Set the most steps taken in a single frame.
+    ///
+    /// When a frame takes too long, the remaining time is dropped instead of
+    /// simulating ever more steps to catch up.
+    pub fn with_max_steps(mut self, max_steps: usize) -> FixedTimestep {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// Add a frame of `dt` seconds, returning the number of fixed steps to
+    /// simulate.
+    pub fn advance(&mut self, dt: f32) -> usize {
+        self.accumulator += dt.max(0.0);
+
+        let mut steps = 0;
+        while self.accumulator >= self.step && steps < self.max_steps {
+            self.accumulator -= self.step;
+            steps += 1;
+        }
+
+        if steps == self.max_steps {
+            self.accumulator = self.accumulator.min(self.step);
+        }
+        steps
+    }
+
+    /// How far the current frame is between the previous and current
+    /// simulated states, in `[0, 1]`.
+    pub fn alpha(&self) -> f32 {
+        (self.accumulator / self.step).min(1.0)
+    }
+}
+
+/// Blend the previous and current simulation states by `alpha`, from
+/// [`FixedTimestep::alpha`][0], for smooth rendering between fixed steps.
+///
+/// [0]: struct.FixedTimestep.html#method.alpha
+pub fn lerp_states<D>(previous: D, current: D, alpha: f32) -> D
+where
+    D: Copy,
+    D: Add<Output = D>,
+    D: Sub<Output = D>,
+    D: Mul<f32, Output = D>,
+{
+    lerp(Linear, previous, current, alpha)
+}
@@ -0,0 +1,93 @@
+//! [tokio](https://tokio.rs) integration, enabled with the `tokio` feature.
+use std::time::Duration;
+
+use tokio::time::{self, Instant, Interval, MissedTickBehavior};
+
+use crate::Lerper;
+
+#[derive(Debug)]
+/// Eased progress, from `0.0` to `1.0`, produced on a tokio interval.
+///
+/// Useful for shaping progress reports, rate ramps and load tests. Progress
+/// is measured from the time the interval was created, so delayed ticks are
+/// skipped rather than bunched up.
+///
+/// # Usage
+/// ```
+/// use std::time::Duration;
+///
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// # async fn main() {
+/// let mut ramp = soy::tokio::eased_interval(
+///     Duration::from_secs(1),
+///     Duration::from_millis(250),
+///     soy::Linear,
+/// );
+///
+/// let mut values = Vec::new();
+/// while let Some(progress) = ramp.tick().await {
+///     values.push(progress);
+/// }
+/// assert_eq!(values, vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+/// # }
+/// ```
+pub struct EasedInterval<L> {
+    interval: Interval,
+    lerper: L,
+    start: Instant,
+    duration: Duration,
+    done: bool,
+}
+
+impl<L: Lerper> EasedInterval<L> {
+    /// Wait for the next tick, returning the eased progress, or `None` once
+    /// the duration has passed.
+    ///
+    /// The first tick completes immediately, and the last tick always yields
+    /// the progress at _t = 1_.
+    pub async fn tick(&mut self) -> Option<f32> {
+        if self.done {
+            return None;
+        }
+
+        let now = self.interval.tick().await;
+        let elapsed = now.duration_since(self.start);
+
+        let t = if self.duration.is_zero() {
+            1.0
+        } else {
+            (elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+        };
+        self.done = t >= 1.0;
+
+        Some(self.lerper.calculate(t))
+    }
+
+    /// Whether the duration has passed.
+    pub fn is_finished(&self) -> bool {
+        self.done
+    }
+}
+
+/// Produce eased progress over `duration`, every `period`.
+///
+/// # Panics
+/// Panics if `period` is zero, like [`tokio::time::interval`][0].
+///
+/// [0]: https://docs.rs/tokio/1/tokio/time/fn.interval.html
+pub fn eased_interval<L: Lerper>(
+    duration: Duration,
+    period: Duration,
+    lerper: L,
+) -> EasedInterval<L> {
+    let mut interval = time::interval(period);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+    EasedInterval {
+        interval,
+        lerper,
+        start: Instant::now(),
+        duration,
+        done: false,
+    }
+}
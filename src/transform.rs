@@ -0,0 +1,208 @@
+use core::ops::{Add, Mul, Sub};
+
+use crate::vector::{normalize, Dot};
+
+/// Spherical linear interpolation between two unit quaternions, following
+/// the shortest arc at a constant angular speed.
+///
+/// Quaternions are treated as four-dimensional vectors, so any quaternion
+/// type implementing [`Dot`][0] and the arithmetic operators can be used.
+/// Nearly identical rotations are normalized linearly instead, which avoids
+/// dividing by a vanishing angle.
+///
+/// # Usage
+/// ```
+/// # use core::ops::{Add, Mul};
+/// # #[derive(Debug, Clone, Copy, PartialEq)]
+/// # struct Quat(f32, f32, f32, f32);
+/// # impl Add for Quat { type Output = Quat; fn add(self, o: Quat) -> Quat { Quat(self.0 + o.0, self.1 + o.1, self.2 + o.2, self.3 + o.3) } }
+/// # impl Mul<f32> for Quat { type Output = Quat; fn mul(self, s: f32) -> Quat { Quat(self.0 * s, self.1 * s, self.2 * s, self.3 * s) } }
+/// # impl soy::Dot for Quat { fn dot(self, o: Quat) -> f32 { self.0 * o.0 + self.1 * o.1 + self.2 * o.2 + self.3 * o.3 } }
+/// let identity = Quat(0.0, 0.0, 0.0, 1.0);
+/// let half_turn = Quat(0.0, 0.0, 1.0, 0.0);
+///
+/// // A quarter turn about z.
+/// let quarter = soy::slerp(identity, half_turn, 0.5);
+/// assert!((quarter.2 - core::f32::consts::FRAC_1_SQRT_2).abs() < 1.0e-6);
+/// assert!((quarter.3 - core::f32::consts::FRAC_1_SQRT_2).abs() < 1.0e-6);
+/// ```
+///
+/// [0]: trait.Dot.html
+pub fn slerp<Q>(start: Q, end: Q, t: f32) -> Q
+where
+    Q: Copy + Dot,
+    Q: Add<Output = Q>,
+    Q: Mul<f32, Output = Q>,
+{
+    const LINEAR_THRESHOLD: f32 = 0.9995;
+
+    // `q` and `-q` are the same rotation, so take the shorter way round.
+    let mut cos = start.dot(end);
+    let end = if cos < 0.0 {
+        cos = -cos;
+        end * -1.0
+    } else {
+        end
+    };
+
+    if cos > LINEAR_THRESHOLD {
+        return normalize(start * (1.0 - t) + end * t);
+    }
+
+    let angle = cos.acos();
+    let sin = angle.sin();
+
+    start * (((1.0 - t) * angle).sin() / sin) + end * ((t * angle).sin() / sin)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Translation, rotation and scale, sampled from a [`TransformTrack`][0].
+///
+/// [0]: struct.TransformTrack.html
+pub struct Transform<V, Q> {
+    /// Translation.
+    pub translation: V,
+    /// Rotation, as a unit quaternion.
+    pub rotation: Q,
+    /// Scale.
+    pub scale: V,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Translation, rotation and scale channels of an animated transform, such
+/// as a glTF node's animation.
+///
+/// Each channel is a list of `(time, value)` keys, with its own timing.
+/// Translation and scale are linearly interpolated, and rotation is
+/// [slerped][0]. Each channel holds its first and last values outside of its
+/// keyed range, so a channel with a single key is constant.
+///
+/// # Usage
+/// ```
+/// # use core::ops::{Add, Mul};
+/// # #[derive(Debug, Clone, Copy, PartialEq)]
+/// # struct Quat(f32, f32, f32, f32);
+/// # impl Add for Quat { type Output = Quat; fn add(self, o: Quat) -> Quat { Quat(self.0 + o.0, self.1 + o.1, self.2 + o.2, self.3 + o.3) } }
+/// # impl Mul<f32> for Quat { type Output = Quat; fn mul(self, s: f32) -> Quat { Quat(self.0 * s, self.1 * s, self.2 * s, self.3 * s) } }
+/// # impl soy::Dot for Quat { fn dot(self, o: Quat) -> f32 { self.0 * o.0 + self.1 * o.1 + self.2 * o.2 + self.3 * o.3 } }
+/// let track = soy::TransformTrack::new(
+///     vec![(0.0, 0.0), (2.0, 10.0)],
+///     vec![(0.0, Quat(0.0, 0.0, 0.0, 1.0))],
+///     vec![(0.0, 1.0), (1.0, 2.0)],
+/// );
+///
+/// let transform = track.sample(1.0);
+/// assert_eq!(transform.translation, 5.0);
+/// assert_eq!(transform.rotation, Quat(0.0, 0.0, 0.0, 1.0));
+/// assert_eq!(transform.scale, 2.0);
+/// assert_eq!(track.end_time(), 2.0);
+/// ```
+///
+/// [0]: fn.slerp.html
+pub struct TransformTrack<V, Q> {
+    translation: Vec<(f32, V)>,
+    rotation: Vec<(f32, Q)>,
+    scale: Vec<(f32, V)>,
+}
+
+impl<V, Q> TransformTrack<V, Q>
+where
+    V: Copy,
+    V: Add<Output = V>,
+    V: Sub<Output = V>,
+    V: Mul<f32, Output = V>,
+    Q: Copy + Dot,
+    Q: Add<Output = Q>,
+    Q: Mul<f32, Output = Q>,
+{
+    /// Create a new transform track from its channels' keys.
+    ///
+    /// # Panics
+    /// Panics if any channel is empty, or its keys are not sorted by strictly
+    /// increasing time.
+    pub fn new(
+        translation: Vec<(f32, V)>,
+        rotation: Vec<(f32, Q)>,
+        scale: Vec<(f32, V)>,
+    ) -> TransformTrack<V, Q> {
+        check_channel(&translation);
+        check_channel(&rotation);
+        check_channel(&scale);
+
+        TransformTrack {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Sample every channel at the given time.
+    pub fn sample(&self, time: f32) -> Transform<V, Q> {
+        let lerp = |a: V, b: V, t: f32| a + (b - a) * t;
+
+        Transform {
+            translation: sample_channel(&self.translation, time, lerp),
+            rotation: sample_channel(&self.rotation, time, slerp),
+            scale: sample_channel(&self.scale, time, lerp),
+        }
+    }
+
+    /// Translation keys.
+    pub fn translation(&self) -> &[(f32, V)] {
+        &self.translation
+    }
+
+    /// Rotation keys.
+    pub fn rotation(&self) -> &[(f32, Q)] {
+        &self.rotation
+    }
+
+    /// Scale keys.
+    pub fn scale(&self) -> &[(f32, V)] {
+        &self.scale
+    }
+
+    /// Time of the earliest key in any channel.
+    pub fn start_time(&self) -> f32 {
+        self.translation[0]
+            .0
+            .min(self.rotation[0].0)
+            .min(self.scale[0].0)
+    }
+
+    /// Time of the latest key in any channel.
+    pub fn end_time(&self) -> f32 {
+        end_time(&self.translation)
+            .max(end_time(&self.rotation))
+            .max(end_time(&self.scale))
+    }
+}
+
+fn check_channel<T>(keys: &[(f32, T)]) {
+    assert!(!keys.is_empty(), "a channel requires at least one key");
+    assert!(
+        keys.windows(2).all(|w| w[0].0 < w[1].0),
+        "keys must be sorted by strictly increasing time"
+    );
+}
+
+/// Time of the last key in a channel.
+fn end_time<T>(keys: &[(f32, T)]) -> f32 {
+    keys[keys.len() - 1].0
+}
+
+/// Sample a channel at `time`, interpolating between the neighbouring keys
+/// with `interpolate`.
+fn sample_channel<T, F>(keys: &[(f32, T)], time: f32, interpolate: F) -> T
+where
+    T: Copy,
+    F: Fn(T, T, f32) -> T,
+{
+    let index = keys.partition_point(|k| k.0 <= time);
+
+    match (index.checked_sub(1).map(|i| keys[i]), keys.get(index)) {
+        (Some((from, a)), Some(&(to, b))) => interpolate(a, b, (time - from) / (to - from)),
+        (Some((_, value)), None) | (None, Some(&(_, value))) => value,
+        (None, None) => unreachable!("channels always have a key"),
+    }
+}
@@ -0,0 +1,51 @@
+use core::ops::{Mul, Sub};
+
+/// Trait for values with an inner (dot) product, which gives splines a notion
+/// of distance.
+///
+/// Implement this for your own vector types to use them with spline
+/// features that depend on distances, such as centripetal parameterization.
+pub trait Dot {
+    /// Inner product of two values.
+    fn dot(self, other: Self) -> f32;
+}
+
+impl Dot for f32 {
+    fn dot(self, other: f32) -> f32 {
+        self * other
+    }
+}
+
+/// Euclidean distance between two values.
+pub(crate) fn distance<T>(a: T, b: T) -> f32
+where
+    T: Copy + Dot + Sub<Output = T>,
+{
+    let d = b - a;
+    d.dot(d).sqrt()
+}
+
+/// Trait for two-dimensional vectors, which have a perpendicular.
+pub trait Perp {
+    /// Vector rotated a quarter turn counter-clockwise.
+    fn perp(self) -> Self;
+}
+
+/// Trait for three-dimensional vectors, which have a cross product.
+pub trait Cross {
+    /// Cross product of two vectors.
+    fn cross(self, other: Self) -> Self;
+}
+
+/// Scale `v` to unit length, leaving zero length values unchanged.
+pub(crate) fn normalize<T>(v: T) -> T
+where
+    T: Copy + Dot + Mul<f32, Output = T>,
+{
+    let length = v.dot(v).sqrt();
+    if length > 0.0 {
+        v * (1.0 / length)
+    } else {
+        v
+    }
+}
@@ -0,0 +1,91 @@
+//! JavaScript bindings through [wasm-bindgen](https://rustwasm.github.io),
+//! enabled with the `wasm` feature.
+//!
+//! Build the module as a `cdylib`, then generate the JavaScript glue with
+//! the `wasm-bindgen` CLI:
+//! ```text
+//! cargo rustc --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/soy.wasm
+//! ```
+//!
+//! ```js
+//! import { Easing } from "soy";
+//!
+//! const ease = Easing.keyword("ease-in-out");
+//! const bounce = Easing.cubicBezier(0.3, 0.0, 0.5, 1.5).thenEase(ease.reverse(), 0.5);
+//!
+//! const y = bounce.calculate(0.25);
+//! ```
+use wasm_bindgen::prelude::*;
+
+use crate::registry::builtin;
+use crate::{Bezier, DynLerper, Lerper, Linear, Repeat, Reverse, Then};
+
+#[wasm_bindgen]
+/// Easing function, exported to JavaScript.
+pub struct Easing {
+    lerper: DynLerper,
+}
+
+#[wasm_bindgen]
+impl Easing {
+    /// Linear easing.
+    pub fn linear() -> Easing {
+        Easing::from_lerper(Linear)
+    }
+
+    /// Easing for a CSS keyword, such as `"ease-in-out"`, throwing if the
+    /// keyword is unknown.
+    pub fn keyword(name: &str) -> Result<Easing, JsValue> {
+        match builtin(name) {
+            Some(lerper) => Ok(Easing::from_lerper(lerper)),
+            None => Err(JsValue::from_str(&format!("unknown easing `{}`", name))),
+        }
+    }
+
+    /// Easing for a CSS `cubic-bezier(x1, y1, x2, y2)` timing function.
+    #[wasm_bindgen(js_name = cubicBezier)]
+    pub fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> Easing {
+        Easing::from_lerper(Bezier::new(x1, y1, x2, y2))
+    }
+
+    /// This easing until `split`, followed by `next`.
+    ///
+    /// Exported as `thenEase`, because a JavaScript object with a `then`
+    /// method is treated as a promise when awaited.
+    #[wasm_bindgen(js_name = thenEase)]
+    pub fn then(&self, next: &Easing, split: f32) -> Easing {
+        Easing::from_lerper(Then::new(self.lerper.clone(), next.lerper.clone(), split))
+    }
+
+    /// This easing played `count` times.
+    pub fn repeat(&self, count: u32) -> Easing {
+        Easing::from_lerper(Repeat::new(self.lerper.clone(), count))
+    }
+
+    /// This easing played backwards.
+    pub fn reverse(&self) -> Easing {
+        Easing::from_lerper(Reverse::new(self.lerper.clone()))
+    }
+
+    /// Calculate the eased progression at `t`.
+    pub fn calculate(&self, t: f32) -> f32 {
+        self.lerper.calculate(t)
+    }
+
+    /// Interpolate from `start` to `end`, eased at `t`.
+    pub fn lerp(&self, start: f32, end: f32, t: f32) -> f32 {
+        crate::lerp(&self.lerper, start, end, t)
+    }
+}
+
+impl Easing {
+    fn from_lerper<L>(lerper: L) -> Easing
+    where
+        L: Lerper + Send + Sync + 'static,
+    {
+        Easing {
+            lerper: lerper.into_dyn(),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use core::ops::{Add, Mul, Sub};
+
+use crate::Lerper;
+
+/// Interpolate element-wise between two sequences, stopping at the end of the
+/// shorter one.
+///
+/// The lerper is only calculated once, however long the sequences are. Like
+/// [`lerp`](fn.lerp.html), _t_ is clamped to `[0, 1]`.
+///
+/// # Usage
+/// ```
+/// let from = vec![0.0, 10.0, 20.0];
+/// let to = vec![10.0, 20.0, 40.0];
+///
+/// let blended: Vec<f32> = soy::lerp_zip(soy::Linear, from, to, 0.5).collect();
+/// assert_eq!(blended, vec![5.0, 15.0, 30.0]);
+///
+/// let late: Vec<f32> = soy::lerp_zip(soy::Linear, vec![0.0], vec![10.0], 1.5).collect();
+/// assert_eq!(late, vec![10.0]);
+/// ```
+pub fn lerp_zip<L, I, J, D>(lerper: L, from: I, to: J, t: f32) -> impl Iterator<Item = D>
+where
+    L: Lerper,
+    I: IntoIterator<Item = D>,
+    J: IntoIterator<Item = D>,
+    D: Copy,
+    D: Add<Output = D>,
+    D: Sub<Output = D>,
+    D: Mul<f32, Output = D>,
+{
+    let y = lerper.calculate(t.clamp(0.0, 1.0));
+
+    from.into_iter().zip(to).map(move |(a, b)| a + (b - a) * y)
+}
+
+/// Interpolate element-wise between two equal length slices.
+///
+/// # Panics
+/// Panics if `from` and `to` have different lengths.
+///
+/// # Usage
+/// ```
+/// let pose = soy::lerp_slice(soy::Linear, &[0.0, 1.0], &[2.0, 3.0], 0.25);
+/// assert_eq!(pose, vec![0.5, 1.5]);
+///
+/// let mut buffer = [0.0; 2];
+/// soy::lerp_slice_into(soy::Linear, &[0.0, 1.0], &[2.0, 3.0], 1.0, &mut buffer);
+/// assert_eq!(buffer, [2.0, 3.0]);
+/// ```
+pub fn lerp_slice<L, D>(lerper: L, from: &[D], to: &[D], t: f32) -> Vec<D>
+where
+    L: Lerper,
+    D: Copy,
+    D: Add<Output = D>,
+    D: Sub<Output = D>,
+    D: Mul<f32, Output = D>,
+{
+    assert_eq!(
+        from.len(),
+        to.len(),
+        "element-wise interpolation requires equal length slices"
+    );
+
+    lerp_zip(lerper, from.iter().copied(), to.iter().copied(), t).collect()
+}
+
+/// Interpolate element-wise between two equal length slices into `buffer`,
+/// without allocating.
+///
+/// # Panics
+/// Panics if `from`, `to` and `buffer` don't all have the same length.
+pub fn lerp_slice_into<L, D>(lerper: L, from: &[D], to: &[D], t: f32, buffer: &mut [D])
+where
+    L: Lerper,
+    D: Copy,
+    D: Add<Output = D>,
+    D: Sub<Output = D>,
+    D: Mul<f32, Output = D>,
+{
+    assert!(
+        from.len() == to.len() && to.len() == buffer.len(),
+        "element-wise interpolation requires equal length slices"
+    );
+
+    let values = lerp_zip(lerper, from.iter().copied(), to.iter().copied(), t);
+    for (slot, value) in buffer.iter_mut().zip(values) {
+        *slot = value;
+    }
+}